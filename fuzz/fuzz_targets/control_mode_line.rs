@@ -0,0 +1,16 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use std::io::{BufRead, BufReader};
+use tmux_interface::control_mode::control_mode::{ControlModeLine, ControlModeOutput};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        for line in s.lines() {
+            let _ = line.control_mode_line();
+        }
+    }
+
+    // raw bytes, including invalid UTF-8 and truncated output blocks
+    let mut lines = BufReader::new(data).lines();
+    while let Ok(Some(_)) = ControlModeOutput::read_response(&mut lines) {}
+});
@@ -0,0 +1,17 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use tmux_interface::{Layout, LayoutDirection};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        if let Ok(layout) = s.parse::<Layout>() {
+            let _ = layout.to_string();
+            let cell = &layout.cell;
+            let _ = cell.pane_at(0, 0);
+            if let Some(id) = cell.panes().first().and_then(|pane| pane.id) {
+                let _ = cell.neighbour(id, LayoutDirection::Right);
+                let _ = cell.neighbour(id, LayoutDirection::Down);
+            }
+        }
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use tmux_interface::StyleList;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        if let Ok(style_list) = s.parse::<StyleList>() {
+            let _ = style_list.to_string();
+        }
+    }
+});
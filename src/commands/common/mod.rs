@@ -5,7 +5,7 @@ pub mod client_flags;
 pub mod pane_size;
 #[cfg(feature = "tmux_3_3")]
 pub mod popup_border_lines_type;
-#[cfg(feature = "tmux_3_2")]
+#[cfg(feature = "tmux_3_0")]
 pub mod position;
 #[cfg(feature = "tmux_3_3")]
 pub mod prompt_type;
@@ -27,8 +27,10 @@ pub use self::pane_size::Size;
 #[cfg(feature = "tmux_3_3")]
 pub use self::popup_border_lines_type::PopupBorderLinesType;
 #[cfg(feature = "tmux_3_2")]
+pub use self::position::PositionFormat;
+#[cfg(feature = "tmux_3_0")]
 pub use self::position::PositionX;
-#[cfg(feature = "tmux_3_2")]
+#[cfg(feature = "tmux_3_0")]
 pub use self::position::PositionY;
 #[cfg(feature = "tmux_3_3")]
 pub use self::prompt_type::PromptType;
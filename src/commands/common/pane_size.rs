@@ -17,6 +17,13 @@ pub enum Size {
     Percentage(usize),
 }
 
+#[cfg(feature = "tmux_3_2")]
+impl From<usize> for Size {
+    fn from(size: usize) -> Self {
+        Self::Size(size)
+    }
+}
+
 #[cfg(feature = "tmux_3_2")]
 impl fmt::Display for Size {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
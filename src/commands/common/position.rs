@@ -1,7 +1,5 @@
 use std::fmt;
 
-// TODO: tmux ^3.2 display-menu, display-popup
-
 // XXX: names shorter?
 /// C        Both    The centre of the terminal
 /// R        -x      The right side of the terminal
@@ -10,27 +8,31 @@ use std::fmt;
 /// W        Both    The window position on the status line
 /// S        -y      The line above or below the status line
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-#[cfg(feature = "tmux_3_2")]
+#[cfg(feature = "tmux_3_0")]
 pub enum PositionX {
     ColumnNumber(usize),
+    #[cfg(feature = "tmux_3_2")]
     TerminalCenter,
     TerminalRight,
     PaneBottom,
     MousePosition,
     StatusLineWindowPosition,
+    #[cfg(feature = "tmux_3_2")]
     PositionFormat(PositionFormat),
 }
 
-#[cfg(feature = "tmux_3_2")]
+#[cfg(feature = "tmux_3_0")]
 impl fmt::Display for PositionX {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::ColumnNumber(n) => write!(f, "{}", n),
+            #[cfg(feature = "tmux_3_2")]
             Self::TerminalCenter => write!(f, "C"),
             Self::TerminalRight => write!(f, "R"),
             Self::PaneBottom => write!(f, "P"),
             Self::MousePosition => write!(f, "M"),
             Self::StatusLineWindowPosition => write!(f, "W"),
+            #[cfg(feature = "tmux_3_2")]
             Self::PositionFormat(s) => write!(f, "{}", s),
         }
     }
@@ -43,27 +45,38 @@ impl fmt::Display for PositionX {
 /// W        Both    The window position on the status line
 /// S        -y      The line above or below the status line
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-#[cfg(feature = "tmux_3_2")]
+#[cfg(feature = "tmux_3_0")]
 pub enum PositionY {
     ColumnNumber(usize),
+    #[cfg(feature = "tmux_3_2")]
     TerminalCenter,
     PaneBottom,
     MousePosition,
     StatusLineWindowPosition,
     AboveBelowStatusLine,
+    #[cfg(feature = "tmux_3_2")]
     PositionFormat(PositionFormat),
 }
 
-#[cfg(feature = "tmux_3_2")]
+#[cfg(feature = "tmux_3_0")]
+impl From<usize> for PositionX {
+    fn from(n: usize) -> Self {
+        Self::ColumnNumber(n)
+    }
+}
+
+#[cfg(feature = "tmux_3_0")]
 impl fmt::Display for PositionY {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::ColumnNumber(n) => write!(f, "{}", n),
+            #[cfg(feature = "tmux_3_2")]
             Self::TerminalCenter => write!(f, "C"),
             Self::PaneBottom => write!(f, "P"),
             Self::MousePosition => write!(f, "M"),
             Self::StatusLineWindowPosition => write!(f, "W"),
             Self::AboveBelowStatusLine => write!(f, "S"),
+            #[cfg(feature = "tmux_3_2")]
             Self::PositionFormat(s) => write!(f, "{}", s),
         }
     }
@@ -133,3 +146,10 @@ impl fmt::Display for PositionFormat {
         write!(f, "#{{{}}}", output)
     }
 }
+
+#[cfg(feature = "tmux_3_0")]
+impl From<usize> for PositionY {
+    fn from(n: usize) -> Self {
+        Self::ColumnNumber(n)
+    }
+}
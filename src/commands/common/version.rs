@@ -53,7 +53,6 @@ impl FromStr for Version {
                 }
                 // end of minor part & EOL
                 ('\n', VersionState::Minor) => {
-                    version.minor = buff.parse()?;
                     break;
                 }
                 // end of minor part, begin alpha, beta
@@ -86,7 +85,18 @@ impl FromStr for Version {
                 }
             }
         }
-        Ok(version)
+
+        match state {
+            // end of minor part & EOF (without `\n`)
+            VersionState::Minor if buff.is_empty() => Err(Error::ParseVersion),
+            VersionState::Minor => {
+                version.minor = buff.parse()?;
+                Ok(version)
+            }
+            VersionState::Suffix => Ok(version),
+            // missing or truncated version number
+            _ => Err(Error::ParseVersion),
+        }
     }
 }
 
@@ -43,14 +43,36 @@ fn from_str() {
     }
 }
 
-//#[test]
-//fn from_str_error() {
-//use crate::{Error, Version};
-
-////let err = "tmux a2.8a".parse::<Version>().unwrap_err();
-////assert!(if let Error::ParseInt(_) = err {
-////true
-////} else {
-////false
-////})
-//}
+#[test]
+fn from_str_numbers() {
+    use crate::Version;
+
+    let version: Version = "tmux 3.4".parse().unwrap();
+    assert_eq!((version.major, version.minor), (3, 4));
+
+    let version: Version = "tmux 3.3a\n".parse().unwrap();
+    assert_eq!((version.major, version.minor), (3, 3));
+    assert_eq!(version.suffix, "a");
+
+    let version: Version = "tmux next-3.5".parse().unwrap();
+    assert_eq!((version.major, version.minor), (3, 5));
+    assert_eq!(version.prefix, "next");
+}
+
+#[test]
+fn from_str_error() {
+    use crate::Version;
+
+    let examples = &[
+        "",
+        "tmux",
+        "tmux ",
+        "tmux 3",
+        "tmux 3.",
+        "tmux a2.8a",
+        "tmux 3.x",
+    ];
+    for example in examples {
+        assert!(example.parse::<Version>().is_err(), "{}", example);
+    }
+}
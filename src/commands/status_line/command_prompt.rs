@@ -57,7 +57,7 @@ pub struct CommandPrompt<'a> {
     #[cfg(feature = "tmux_3_3")]
     pub background: bool,
 
-    /// `[-F]` -
+    /// `[-F]` - the template is expanded as a format
     #[cfg(feature = "tmux_3_3")]
     pub expand_as_format: bool,
 
@@ -114,6 +114,20 @@ impl<'a> CommandPrompt<'a> {
         self
     }
 
+    /// `[-b]` - the prompt is shown in the background and the invoking client does not exit until it is dismissed
+    #[cfg(feature = "tmux_3_3")]
+    pub fn background(mut self) -> Self {
+        self.background = true;
+        self
+    }
+
+    /// `[-F]` - the template is expanded as a format
+    #[cfg(feature = "tmux_3_3")]
+    pub fn expand_as_format(mut self) -> Self {
+        self.expand_as_format = true;
+        self
+    }
+
     /// `[-i]` execute the command every time the prompt input changes
     #[cfg(feature = "tmux_2_4")]
     pub fn on_input_change(mut self) -> Self {
@@ -195,6 +209,18 @@ impl<'a> CommandPrompt<'a> {
             cmd.push_flag(_1_KEY);
         }
 
+        // `[-b]` - the prompt is shown in the background and the invoking client does not exit until it is dismissed
+        #[cfg(feature = "tmux_3_3")]
+        if self.background {
+            cmd.push_flag(B_LOWERCASE_KEY);
+        }
+
+        // `[-F]` - the template is expanded as a format
+        #[cfg(feature = "tmux_3_3")]
+        if self.expand_as_format {
+            cmd.push_flag(F_UPPERCASE_KEY);
+        }
+
         // `[-i]` execute the command every time the prompt input changes
         #[cfg(feature = "tmux_2_4")]
         if self.on_input_change {
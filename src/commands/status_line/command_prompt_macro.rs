@@ -55,7 +55,7 @@ macro_rules! command_prompt {
             $cmd.background()
         }) $($tail)*)
     }};
-    // `[-F]` -
+    // `[-F]` - the template is expanded as a format
     (@cmd ($cmd:expr) -F, $($tail:tt)*) => {{
         $crate::command_prompt!(@cmd ({
             $cmd.expand_as_format()
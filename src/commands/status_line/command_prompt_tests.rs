@@ -51,6 +51,10 @@ fn command_prompt() {
     let command_prompt = CommandPrompt::new();
     #[cfg(feature = "tmux_2_4")]
     let command_prompt = command_prompt.one_keypress();
+    #[cfg(feature = "tmux_3_3")]
+    let command_prompt = command_prompt.background();
+    #[cfg(feature = "tmux_3_3")]
+    let command_prompt = command_prompt.expand_as_format();
     #[cfg(feature = "tmux_2_4")]
     let command_prompt = command_prompt.on_input_change();
     #[cfg(feature = "tmux_3_1")]
@@ -78,6 +82,10 @@ fn command_prompt() {
     s.push(cmd);
     #[cfg(feature = "tmux_2_4")]
     s.push("-1");
+    #[cfg(feature = "tmux_3_3")]
+    s.push("-b");
+    #[cfg(feature = "tmux_3_3")]
+    s.push("-F");
     #[cfg(feature = "tmux_2_4")]
     s.push("-i");
     #[cfg(feature = "tmux_3_1")]
@@ -1,9 +1,119 @@
 use crate::commands::constants::*;
-use crate::TmuxCommand;
+use crate::{conf_command, PositionX, PositionY, TmuxCommand};
 use std::borrow::Cow;
 
 pub type Menu<'a> = DisplayMenu<'a>;
 
+const DISABLED_MARKER: char = '-';
+
+/// Item of the [`DisplayMenu`]
+///
+/// Item names are formats, items with names expanded to an empty string are not shown
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum MenuItem<'a> {
+    /// horizontal line (empty name)
+    Separator,
+    Item {
+        name: Cow<'a, str>,
+        /// key shortcut, empty for none
+        key: Cow<'a, str>,
+        command: Cow<'a, str>,
+        /// shown, but can not be chosen (name prefixed with `-`)
+        disabled: bool,
+        /// format, the item is shown only if it is true (`#{?condition,name,}`)
+        condition: Option<Cow<'a, str>>,
+    },
+}
+
+impl<'a> MenuItem<'a> {
+    pub fn new<S: Into<Cow<'a, str>>>(name: S, key: S, command: S) -> Self {
+        Self::Item {
+            name: name.into(),
+            key: key.into(),
+            command: command.into(),
+            disabled: false,
+            condition: None,
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self::Separator
+    }
+
+    /// Item opening the submenu (generated `display-menu` command)
+    pub fn submenu<S: Into<Cow<'a, str>>>(name: S, key: S, menu: DisplayMenu<'a>) -> Self {
+        // item commands are expanded as formats before being run
+        let command = conf_command(&menu.build()).replace('#', "##");
+        Self::new(name.into(), key.into(), Cow::Owned(command))
+    }
+
+    pub fn disabled(mut self) -> Self {
+        if let Self::Item { disabled, .. } = &mut self {
+            *disabled = true;
+        }
+        self
+    }
+
+    /// Show the item only if the format is true (e.g. `#{pane_marked_set}`)
+    pub fn condition<S: Into<Cow<'a, str>>>(mut self, format: S) -> Self {
+        if let Self::Item { condition, .. } = &mut self {
+            *condition = Some(format.into());
+        }
+        self
+    }
+
+    /// `name`, `key` and `command` arguments of the `display-menu`
+    pub fn to_vec(&self) -> Vec<Cow<'a, str>> {
+        match self {
+            Self::Separator => vec![Cow::Borrowed("")],
+            Self::Item {
+                name,
+                key,
+                command,
+                disabled,
+                condition,
+            } => {
+                let mut name = name.clone();
+                if *disabled {
+                    name = Cow::Owned(format!("{}{}", DISABLED_MARKER, name));
+                }
+                if let Some(condition) = condition {
+                    name = Cow::Owned(format!("#{{?{},{},}}", condition, format_escape(&name)));
+                }
+                vec![name, key.clone(), command.clone()]
+            }
+        }
+    }
+}
+
+// escape `,` and `}` outside of nested formats for the conditional format
+fn format_escape(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    let mut depth = 0;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                output.push(c);
+                match chars.next() {
+                    Some('{') => {
+                        depth += 1;
+                        output.push('{');
+                    }
+                    Some(c) => output.push(c),
+                    None => {}
+                }
+                continue;
+            }
+            '}' if depth > 0 => depth -= 1,
+            ',' | '}' if depth == 0 => output.push('#'),
+            _ => {}
+        }
+        output.push(c);
+    }
+    output
+}
+
 /// Structure for displaying a menu on target-client
 ///
 /// # Manual
@@ -40,11 +150,11 @@ pub struct DisplayMenu<'a> {
 
     /// `[-x position]` - x position of the menu
     #[cfg(feature = "tmux_3_0")]
-    pub x: Option<usize>,
+    pub x: Option<PositionX>,
 
     /// `[-y position]` - y position of the menu
     #[cfg(feature = "tmux_3_0")]
-    pub y: Option<usize>,
+    pub y: Option<PositionY>,
 
     /// `name`
     pub name: Option<Cow<'a, str>>,
@@ -54,6 +164,9 @@ pub struct DisplayMenu<'a> {
 
     /// `command`
     pub command: Option<Cow<'a, str>>,
+
+    /// `name key command ...`
+    pub items: Vec<MenuItem<'a>>,
 }
 
 impl<'a> DisplayMenu<'a> {
@@ -91,15 +204,15 @@ impl<'a> DisplayMenu<'a> {
 
     /// `[-x position]` - x position of the menu
     #[cfg(feature = "tmux_3_0")]
-    pub fn x(mut self, x: usize) -> Self {
-        self.x = Some(x);
+    pub fn x<P: Into<PositionX>>(mut self, x: P) -> Self {
+        self.x = Some(x.into());
         self
     }
 
     /// `[-y position]` - y position of the menu
     #[cfg(feature = "tmux_3_0")]
-    pub fn y(mut self, y: usize) -> Self {
-        self.y = Some(y);
+    pub fn y<P: Into<PositionY>>(mut self, y: P) -> Self {
+        self.y = Some(y.into());
         self
     }
 
@@ -120,6 +233,17 @@ impl<'a> DisplayMenu<'a> {
         self
     }
 
+    /// `name key command ...`
+    pub fn item(mut self, item: MenuItem<'a>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// `""`
+    pub fn separator(self) -> Self {
+        self.item(MenuItem::Separator)
+    }
+
     pub fn build(self) -> TmuxCommand<'a> {
         let mut cmd = TmuxCommand::new();
 
@@ -176,6 +300,13 @@ impl<'a> DisplayMenu<'a> {
             cmd.push_param(command);
         }
 
+        // `name key command ...`
+        for item in self.items {
+            for arg in item.to_vec() {
+                cmd.push_param(arg);
+            }
+        }
+
         cmd
     }
 }
@@ -65,3 +65,56 @@ fn display_menu() {
 
     assert_eq!(display_menu, s);
 }
+
+#[cfg(feature = "tmux_3_2")]
+#[test]
+fn display_menu_items() {
+    use crate::{DisplayMenu, MenuItem, PositionX, PositionY};
+    use std::borrow::Cow;
+
+    let submenu = DisplayMenu::new()
+        .title("#S")
+        .item(MenuItem::new("Kill", "k", "kill-session"));
+    let display_menu = DisplayMenu::new()
+        .x(PositionX::MousePosition)
+        .y(PositionY::AboveBelowStatusLine)
+        .item(MenuItem::new("New", "n", "new-window"))
+        .separator()
+        .item(MenuItem::new("Swap", "s", "swap-pane").disabled())
+        .item(
+            MenuItem::new("Join #{pane_id}, {marked}", "", "join-pane")
+                .condition("#{pane_marked_set}")
+                .disabled(),
+        )
+        .item(MenuItem::submenu("Session", "S", submenu));
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let cmd = "display-menu";
+    #[cfg(feature = "cmd_alias")]
+    let cmd = "menu";
+    let submenu = format!("{} -T '##S' Kill k kill-session", cmd);
+
+    let s = vec![
+        cmd,
+        "-x",
+        "M",
+        "-y",
+        "S",
+        "New",
+        "n",
+        "new-window",
+        "",
+        "-Swap",
+        "s",
+        "swap-pane",
+        "#{?#{pane_marked_set},-Join #{pane_id}#, {marked#},}",
+        "",
+        "join-pane",
+        "Session",
+        "S",
+        &submenu,
+    ];
+    let s: Vec<Cow<str>> = s.into_iter().map(|a| a.into()).collect();
+
+    assert_eq!(display_menu.build().to_vec(), s);
+}
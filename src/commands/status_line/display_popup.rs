@@ -2,7 +2,7 @@ use crate::commands::constants::*;
 #[cfg(feature = "tmux_3_3")]
 use crate::commands::PopupBorderLinesType;
 use crate::commands::Size;
-use crate::TmuxCommand;
+use crate::{PositionX, PositionY, TmuxCommand};
 use std::borrow::Cow;
 
 pub type Popup<'a> = DisplayPopup<'a>;
@@ -56,11 +56,10 @@ pub struct DisplayPopup<'a> {
     #[cfg(feature = "tmux_3_2")]
     pub start_directory: Option<Cow<'a, str>>,
 
-    // XXX: struct instead of tuple
     /// `[-e environment]` - takes the form ‘VARIABLE=value’ and sets an environment variable for
     /// the popup; it may be specified multiple times
     #[cfg(feature = "tmux_3_3")]
-    pub environment: Option<(Cow<'a, str>, Cow<'a, str>)>,
+    pub environment: Option<Vec<(Cow<'a, str>, Cow<'a, str>)>>,
 
     /// `[-h height]` - height of the popup
     #[cfg(feature = "tmux_3_2")]
@@ -90,11 +89,11 @@ pub struct DisplayPopup<'a> {
 
     /// `[-x position]` - x position of the popup
     #[cfg(feature = "tmux_3_2")]
-    pub x: Option<Size>,
+    pub x: Option<PositionX>,
 
     /// `[-y position]` - y position of the popup
     #[cfg(feature = "tmux_3_2")]
-    pub y: Option<Size>,
+    pub y: Option<PositionY>,
 
     /// `[shell-command]` - shell-command
     #[cfg(feature = "tmux_3_2")]
@@ -156,19 +155,20 @@ impl<'a> DisplayPopup<'a> {
         self
     }
 
-    // TODO: vec
     /// `[-e environment]` - takes the form ‘VARIABLE=value’ and sets an environment variable for
     /// the popup; it may be specified multiple times
     #[cfg(feature = "tmux_3_3")]
     pub fn environment<S: Into<Cow<'a, str>>>(mut self, variable: S, value: S) -> Self {
-        self.environment = Some((variable.into(), value.into()));
+        self.environment
+            .get_or_insert(Vec::new())
+            .push((variable.into(), value.into()));
         self
     }
 
     /// `[-h height]` - height of the popup
     #[cfg(feature = "tmux_3_2")]
-    pub fn height(mut self, height: Size) -> Self {
-        self.height = Some(height);
+    pub fn height<S: Into<Size>>(mut self, height: S) -> Self {
+        self.height = Some(height.into());
         self
     }
 
@@ -200,24 +200,24 @@ impl<'a> DisplayPopup<'a> {
         self
     }
 
-    /// `[-w width]` - width of the pane
+    /// `[-w width]` - width of the popup
     #[cfg(feature = "tmux_3_2")]
-    pub fn width(mut self, width: Size) -> Self {
-        self.width = Some(width);
+    pub fn width<S: Into<Size>>(mut self, width: S) -> Self {
+        self.width = Some(width.into());
         self
     }
 
-    /// `[-x position]` - x position of the pane
+    /// `[-x position]` - x position of the popup
     #[cfg(feature = "tmux_3_2")]
-    pub fn x(mut self, x: Size) -> Self {
-        self.x = Some(x);
+    pub fn x<P: Into<PositionX>>(mut self, x: P) -> Self {
+        self.x = Some(x.into());
         self
     }
 
-    /// `[-y position]` - y position of the pane
+    /// `[-y position]` - y position of the popup
     #[cfg(feature = "tmux_3_2")]
-    pub fn y(mut self, y: Size) -> Self {
-        self.y = Some(y);
+    pub fn y<P: Into<PositionY>>(mut self, y: P) -> Self {
+        self.y = Some(y.into());
         self
     }
 
@@ -279,10 +279,9 @@ impl<'a> DisplayPopup<'a> {
         // the popup; it may be specified multiple times
         #[cfg(feature = "tmux_3_3")]
         if let Some(environment) = self.environment {
-            cmd.push_option(
-                E_LOWERCASE_KEY,
-                format!("{}={}", environment.0, environment.1),
-            );
+            for variable in environment {
+                cmd.push_option(E_LOWERCASE_KEY, format!("{}={}", variable.0, variable.1));
+            }
         }
 
         // `[-h height]` - height of the popup
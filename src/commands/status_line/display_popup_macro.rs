@@ -198,9 +198,9 @@ fn display_popup() {
     #[cfg(feature = "tmux_3_2")]
     let display_popup = display_popup!((display_popup), -w Size::Size(10));
     #[cfg(feature = "tmux_3_2")]
-    let display_popup = display_popup!((display_popup), -x Size::Size(11));
+    let display_popup = display_popup!((display_popup), -x 11);
     #[cfg(feature = "tmux_3_2")]
-    let display_popup = display_popup!((display_popup), -y Size::Size(12));
+    let display_popup = display_popup!((display_popup), -y 12);
     #[cfg(feature = "tmux_3_2")]
     let display_popup = display_popup!((display_popup), "13");
 
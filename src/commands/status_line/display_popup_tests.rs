@@ -41,7 +41,7 @@ fn display_popup() {
     #[cfg(feature = "tmux_3_2")]
     let display_popup = display_popup.start_directory("2");
     #[cfg(feature = "tmux_3_3")]
-    let display_popup = display_popup.environment("3", "4");
+    let display_popup = display_popup.environment("3", "4").environment("5", "6");
     #[cfg(feature = "tmux_3_2")]
     let display_popup = display_popup.height(Size::Size(5));
     #[cfg(feature = "tmux_3_3")]
@@ -55,9 +55,9 @@ fn display_popup() {
     #[cfg(feature = "tmux_3_2")]
     let display_popup = display_popup.width(Size::Size(10));
     #[cfg(feature = "tmux_3_2")]
-    let display_popup = display_popup.x(Size::Size(11));
+    let display_popup = display_popup.x(11);
     #[cfg(feature = "tmux_3_2")]
-    let display_popup = display_popup.y(Size::Size(12));
+    let display_popup = display_popup.y(12);
     #[cfg(feature = "tmux_3_2")]
     let display_popup = display_popup.shell_command("13");
 
@@ -83,7 +83,7 @@ fn display_popup() {
     #[cfg(feature = "tmux_3_2")]
     s.extend_from_slice(&["-d", "2"]);
     #[cfg(feature = "tmux_3_3")]
-    s.extend_from_slice(&["-e", "3=4"]);
+    s.extend_from_slice(&["-e", "3=4", "-e", "5=6"]);
     #[cfg(feature = "tmux_3_2")]
     s.extend_from_slice(&["-h", "5"]);
     #[cfg(feature = "tmux_3_3")]
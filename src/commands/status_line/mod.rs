@@ -46,7 +46,7 @@ pub use command_prompt::CommandPrompt;
 #[cfg(feature = "tmux_0_9")]
 pub use confirm_before::{Confirm, ConfirmBefore};
 #[cfg(feature = "tmux_3_0")]
-pub use display_menu::{DisplayMenu, Menu};
+pub use display_menu::{DisplayMenu, Menu, MenuItem};
 #[cfg(feature = "tmux_1_0")]
 pub use display_message::{Display, DisplayMessage};
 //#[cfg(feature = "tmux_1_0")]
@@ -1,7 +1,9 @@
 use crate::commands::constants::*;
 use crate::{Error, TmuxCommand, TmuxCommands, TmuxOutput};
 use std::borrow::Cow;
+use std::io::{self, Write};
 use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
 
 /// enum for setting stdin, stdout, stderr, used in [`Tmux`] struct,
 /// wrapper for [`Stdio`][`std::process::Stdio`]
@@ -485,6 +487,32 @@ impl<'a> Tmux<'a> {
         Ok(child)
     }
 
+    /// execute tmux process, write the input to its stdin, wait for output, return output
+    ///
+    /// by default:
+    ///  * stdin  is piped (closed after the input is written)
+    ///  * stdout is piped
+    ///  * stderr is piped
+    pub fn output_with_stdin(mut self, input: &[u8]) -> Result<TmuxOutput, Error> {
+        self.stdin = Some(StdIO::Piped);
+        self.stdout.get_or_insert(StdIO::Piped);
+        self.stderr.get_or_insert(StdIO::Piped);
+        let mut child = self.spawn()?;
+        // written from another thread, the child may fill the stdout pipe before reading stdin
+        let writer = child.stdin.take().map(|mut stdin| {
+            let input = input.to_vec();
+            thread::spawn(move || stdin.write_all(&input))
+        });
+        let output = child.wait_with_output()?;
+        // tmux exiting before reading the input (e.g. command failed) is reported by the output
+        if let Some(Ok(Err(err))) = writer.map(|writer| writer.join()) {
+            if err.kind() != io::ErrorKind::BrokenPipe {
+                return Err(err.into());
+            }
+        }
+        Ok(TmuxOutput(output))
+    }
+
     /// spawn tmux process, return status result of the command execution
     /// by default:
     ///  * stdin  is inherited
@@ -1,3 +1,4 @@
+use crate::Error;
 use std::fmt;
 use std::process::ExitStatus;
 use std::process::Output;
@@ -44,4 +45,15 @@ impl TmuxOutput {
     pub fn code(&self) -> Option<i32> {
         self.0.status.code()
     }
+
+    /// `Err(Error::Tmux)` with the tmux error message (stderr) if the command failed
+    pub fn into_result(self) -> Result<Self, Error> {
+        if self.success() {
+            Ok(self)
+        } else {
+            Err(Error::Tmux(
+                String::from_utf8_lossy(&self.0.stderr).trim().to_string(),
+            ))
+        }
+    }
 }
@@ -0,0 +1,309 @@
+// tmux configuration file lexer (ref: cmd-parse.y -> yylex()), splits the file into commands
+// (arguments with quotes removed, escapes and variables expanded) and `%` directives
+use std::collections::HashMap;
+use std::env;
+
+const SINGLE_QUOTE: char = '\'';
+const DOUBLE_QUOTE: char = '"';
+const BACKSLASH: char = '\\';
+const COMMENT: char = '#';
+const COMMAND_SEPARATOR: char = ';';
+const DIRECTIVE: char = '%';
+const VARIABLE: char = '$';
+const HOME: char = '~';
+const BLOCK_START: char = '{';
+const BLOCK_END: char = '}';
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) enum ConfLine {
+    /// command arguments (including command name)
+    Command(Vec<String>),
+    /// `%if "condition"`
+    If(String),
+    /// `%elif "condition"`
+    Elif(String),
+    /// `%else`
+    Else,
+    /// `%endif`
+    Endif,
+    /// `%hidden NAME=value`
+    Hidden(String),
+}
+
+pub(crate) struct ConfLexer {
+    chars: Vec<char>,
+    pos: usize,
+    /// current line number (starting from 1)
+    line: usize,
+}
+
+impl ConfLexer {
+    pub fn new(s: &str) -> Self {
+        Self {
+            chars: s.chars().collect(),
+            pos: 0,
+            line: 1,
+        }
+    }
+
+    /// Next command or directive and the line number it starts on, variables are expanded
+    /// using given variables (set in the file) or the process environment
+    pub fn next_line(
+        &mut self,
+        vars: &HashMap<String, String>,
+    ) -> Option<(usize, Result<ConfLine, String>)> {
+        loop {
+            self.skip_whitespace();
+            match self.peek()? {
+                '\n' | COMMAND_SEPARATOR => {
+                    self.next_char();
+                }
+                COMMENT => self.skip_comment(),
+                DIRECTIVE => {
+                    let line = self.line;
+                    self.next_char();
+                    return Some((line, self.directive(vars)));
+                }
+                _ => {
+                    let line = self.line;
+                    return Some((line, self.words(vars).map(ConfLine::Command)));
+                }
+            }
+        }
+    }
+
+    fn directive(&mut self, vars: &HashMap<String, String>) -> Result<ConfLine, String> {
+        let mut name = String::new();
+        while let Some(c) = self.peek().filter(char::is_ascii_alphabetic) {
+            name.push(c);
+            self.next_char();
+        }
+        let mut args = self.words(vars)?.into_iter();
+        let mut arg = || {
+            args.next()
+                .ok_or_else(|| format!("missing %{} argument", name))
+        };
+        match name.as_str() {
+            "if" => Ok(ConfLine::If(arg()?)),
+            "elif" => Ok(ConfLine::Elif(arg()?)),
+            "else" => Ok(ConfLine::Else),
+            "endif" => Ok(ConfLine::Endif),
+            "hidden" => Ok(ConfLine::Hidden(arg()?)),
+            _ => Err(format!("unknown directive: %{}", name)),
+        }
+    }
+
+    // words until the end of the line, `;` or comment
+    fn words(&mut self, vars: &HashMap<String, String>) -> Result<Vec<String>, String> {
+        let mut words = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => break,
+                Some('\n') | Some(COMMAND_SEPARATOR) => {
+                    self.next_char();
+                    break;
+                }
+                Some(COMMENT) => self.skip_comment(),
+                Some(BLOCK_START) => {
+                    self.next_char();
+                    words.push(self.block()?);
+                }
+                Some(_) => {
+                    let (word, end) = self.word(vars)?;
+                    words.push(word);
+                    if end {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(words)
+    }
+
+    // single word, quotes removed, returns true if terminated by `;`
+    fn word(&mut self, vars: &HashMap<String, String>) -> Result<(String, bool), String> {
+        let mut word = String::new();
+        if self.peek() == Some(HOME)
+            && matches!(self.peek_at(1), None | Some('/' | ' ' | '\t' | '\n'))
+        {
+            self.next_char();
+            word.push_str(&env::var("HOME").unwrap_or_default());
+        }
+        while let Some(c) = self.peek() {
+            match c {
+                ' ' | '\t' | '\n' => break,
+                COMMAND_SEPARATOR if matches!(self.peek_at(1), None | Some(' ' | '\t' | '\n')) => {
+                    self.next_char();
+                    return Ok((word, true));
+                }
+                BACKSLASH => {
+                    self.next_char();
+                    self.escape(&mut word)?;
+                }
+                SINGLE_QUOTE => {
+                    self.next_char();
+                    loop {
+                        match self.next_char() {
+                            Some(SINGLE_QUOTE) => break,
+                            Some(c) => word.push(c),
+                            None => return Err("unterminated quote".to_string()),
+                        }
+                    }
+                }
+                DOUBLE_QUOTE => {
+                    self.next_char();
+                    loop {
+                        match self.next_char() {
+                            Some(DOUBLE_QUOTE) => break,
+                            Some(BACKSLASH) => self.escape(&mut word)?,
+                            Some(VARIABLE) => self.variable(&mut word, vars),
+                            Some(c) => word.push(c),
+                            None => return Err("unterminated quote".to_string()),
+                        }
+                    }
+                }
+                VARIABLE => {
+                    self.next_char();
+                    self.variable(&mut word, vars);
+                }
+                c => {
+                    self.next_char();
+                    word.push(c);
+                }
+            }
+        }
+        Ok((word, false))
+    }
+
+    // block argument (`{ ... }`) content as is, nested blocks and quotes are skipped
+    fn block(&mut self) -> Result<String, String> {
+        let mut block = String::new();
+        let mut depth = 0;
+        let mut quote = None;
+        loop {
+            let c = self
+                .next_char()
+                .ok_or_else(|| "unterminated block".to_string())?;
+            match (c, quote) {
+                (BACKSLASH, _) => {
+                    block.push(c);
+                    if let Some(c) = self.next_char() {
+                        block.push(c);
+                    }
+                    continue;
+                }
+                (SINGLE_QUOTE | DOUBLE_QUOTE, None) => quote = Some(c),
+                (c, Some(q)) if c == q => quote = None,
+                (BLOCK_START, None) => depth += 1,
+                (BLOCK_END, None) if depth == 0 => break,
+                (BLOCK_END, None) => depth -= 1,
+                _ => {}
+            }
+            block.push(c);
+        }
+        Ok(block.trim().to_string())
+    }
+
+    // escape sequence after backslash (ref: cmd-parse.y -> yylex_token_escape())
+    fn escape(&mut self, word: &mut String) -> Result<(), String> {
+        let c = self
+            .next_char()
+            .ok_or_else(|| "unterminated escape".to_string())?;
+        let c = match c {
+            // line continuation
+            '\n' => return Ok(()),
+            'a' => '\x07',
+            'b' => '\x08',
+            'e' => '\x1b',
+            'f' => '\x0c',
+            's' => ' ',
+            'v' => '\x0b',
+            'r' => '\r',
+            'n' => '\n',
+            't' => '\t',
+            'u' | 'U' => {
+                let len = if c == 'u' { 4 } else { 8 };
+                let hex: String = (0..len).filter_map(|_| self.next_char()).collect();
+                u32::from_str_radix(&hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid \\{} argument", c))?
+            }
+            '0'..='7' => {
+                let mut n = c.to_digit(8).unwrap_or_default();
+                for _ in 0..2 {
+                    let digit = self.next_char().and_then(|c| c.to_digit(8));
+                    n = n * 8 + digit.ok_or_else(|| "invalid octal escape".to_string())?;
+                }
+                char::from_u32(n).ok_or_else(|| "invalid octal escape".to_string())?
+            }
+            c => c,
+        };
+        word.push(c);
+        Ok(())
+    }
+
+    // `$NAME` or `${NAME}` after `$`, not set variable is expanded to empty string
+    fn variable(&mut self, word: &mut String, vars: &HashMap<String, String>) {
+        let braces = self.peek() == Some(BLOCK_START);
+        if braces {
+            self.next_char();
+        }
+        let mut name = String::new();
+        while let Some(c) = self
+            .peek()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        {
+            name.push(c);
+            self.next_char();
+        }
+        if braces && self.peek() == Some(BLOCK_END) {
+            self.next_char();
+        }
+        if name.is_empty() && !braces {
+            word.push(VARIABLE);
+            return;
+        }
+        match vars.get(&name) {
+            Some(value) => word.push_str(value),
+            None => word.push_str(&env::var(&name).unwrap_or_default()),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            match (self.peek(), self.peek_at(1)) {
+                (Some(' ' | '\t' | '\r'), _) => {}
+                (Some(BACKSLASH), Some('\n')) => {
+                    self.next_char();
+                }
+                _ => break,
+            }
+            self.next_char();
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        while !matches!(self.peek(), None | Some('\n')) {
+            self.next_char();
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, n: usize) -> Option<char> {
+        self.chars.get(self.pos + n).copied()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+}
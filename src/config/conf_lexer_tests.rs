@@ -0,0 +1,77 @@
+fn lines(s: &str) -> Vec<(usize, Result<crate::config::conf_lexer::ConfLine, String>)> {
+    use crate::config::conf_lexer::ConfLexer;
+    use std::collections::HashMap;
+
+    let mut vars = HashMap::new();
+    vars.insert("MY_VAR".to_string(), "my value".to_string());
+    let mut lexer = ConfLexer::new(s);
+    let mut lines = Vec::new();
+    while let Some(line) = lexer.next_line(&vars) {
+        lines.push(line);
+    }
+    lines
+}
+
+#[test]
+fn conf_lexer_command() {
+    use crate::config::conf_lexer::ConfLine;
+
+    let command = |args: &[&str]| {
+        Ok(ConfLine::Command(
+            args.iter().map(|s| s.to_string()).collect(),
+        ))
+    };
+
+    let conf = "# comment\n\
+                set -g status-left '#[fg=red] $MY_VAR' # comment\n\
+                \n\
+                set -g @a \"x\\ty $MY_VAR ${MY_VAR}\\101\" ; set -g @b a\\ b\n\
+                set -g @c \\\n    multi\\;\n\
+                bind x { display-message 'a }'; kill-pane }\n";
+    assert_eq!(
+        lines(conf),
+        vec![
+            (
+                2,
+                command(&["set", "-g", "status-left", "#[fg=red] $MY_VAR"])
+            ),
+            (4, command(&["set", "-g", "@a", "x\ty my value my valueA"])),
+            (4, command(&["set", "-g", "@b", "a b"])),
+            (5, command(&["set", "-g", "@c", "multi;"])),
+            (
+                7,
+                command(&["bind", "x", "display-message 'a }'; kill-pane"])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn conf_lexer_directive() {
+    use crate::config::conf_lexer::ConfLine;
+
+    let conf = "%if \"#{>=:#{version},3.2}\"\n\
+                %hidden NAME=value\n\
+                %elif '#{==:a,b}'\n\
+                %else\n\
+                %endif\n";
+    assert_eq!(
+        lines(conf),
+        vec![
+            (1, Ok(ConfLine::If("#{>=:#{version},3.2}".to_string()))),
+            (2, Ok(ConfLine::Hidden("NAME=value".to_string()))),
+            (3, Ok(ConfLine::Elif("#{==:a,b}".to_string()))),
+            (4, Ok(ConfLine::Else)),
+            (5, Ok(ConfLine::Endif)),
+        ]
+    );
+}
+
+#[test]
+fn conf_lexer_error() {
+    assert!(lines("set -g @a 'x").pop().unwrap().1.is_err());
+    assert!(lines("set -g @a \"x").pop().unwrap().1.is_err());
+    assert!(lines("bind x { kill-pane").pop().unwrap().1.is_err());
+    assert!(lines("%iff 1").pop().unwrap().1.is_err());
+    assert!(lines("%if").pop().unwrap().1.is_err());
+}
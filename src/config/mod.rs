@@ -0,0 +1,17 @@
+//! tmux configuration file (`.tmux.conf`) generation and loading
+pub(crate) mod conf_lexer;
+pub mod tmux_conf;
+pub mod tmux_conf_loader;
+
+pub use tmux_conf::{conf_command, conf_quote, TmuxConf, TmuxConfItem};
+pub use tmux_conf_loader::{
+    ConfIssue, ConfIssueKind, ConfLocation, LoadedTmuxConf, TmuxConfLoader,
+};
+
+#[cfg(test)]
+#[path = "."]
+mod config_tests {
+    pub mod conf_lexer_tests;
+    pub mod tmux_conf_loader_tests;
+    pub mod tmux_conf_tests;
+}
@@ -0,0 +1,245 @@
+#[cfg(feature = "tmux_3_1")]
+use crate::PaneOptions;
+#[cfg(feature = "tmux_2_2")]
+use crate::SetHook;
+use crate::{
+    BindKey, Error, OptionScope, OptionsDiff, OptionsValuesTr, ServerOptions, SessionOptions,
+    SetEnvironment, TmuxCommand, TmuxCommands, WindowOptions,
+};
+use std::borrow::Cow;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+const SINGLE_QUOTE: char = '\'';
+const DOUBLE_QUOTE: char = '"';
+const BACKSLASH: char = '\\';
+const SAFE_CHARS: &str = "-_./:=,+@^[]";
+
+/// Line of the [`TmuxConf`]
+#[derive(Clone, Debug)]
+pub enum TmuxConfItem<'a> {
+    /// single command
+    Command(TmuxCommand<'a>),
+    /// commands loaded only by tmux (major, minor) or newer
+    /// (`%if "#{>=:#{version},3.2}"` ... `%endif`)
+    Since((usize, usize), TmuxConf<'a>),
+}
+
+/// tmux configuration file (`.tmux.conf`) generated from typed options, key bindings, hooks,
+/// environment and command aliases
+///
+/// Global options are set (`set-option -g`, `-s`), arguments are quoted for the
+/// configuration file parser, the output can be loaded using `source-file`
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{BindKey, SessionOptions, TmuxConf};
+///
+/// let conf = TmuxConf::new()
+///     .session_options(&SessionOptions {
+///         base_index: Some(1),
+///         status_left: Some("[#S] ".into()),
+///         ..Default::default()
+///     })
+///     .bind_key(BindKey::new().key("|").command("split-window -h"))
+///     .since((3, 2), TmuxConf::new().command_alias("info", "show-messages -JT"));
+///
+/// # #[cfg(feature = "cmd_alias")]
+/// assert_eq!(
+///     conf.to_string(),
+///     "set -g base-index 1\n\
+///      set -g status-left '[#S] '\n\
+///      bind '|' 'split-window -h'\n\
+///      %if \"#{>=:#{version},3.2}\"\n\
+///      set -a -s command-alias 'info=show-messages -JT'\n\
+///      %endif\n"
+/// );
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct TmuxConf<'a> {
+    pub items: Vec<TmuxConfItem<'a>>,
+}
+
+impl<'a> TmuxConf<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Append any command
+    pub fn command<T: Into<TmuxCommand<'a>>>(mut self, cmd: T) -> Self {
+        self.items.push(TmuxConfItem::Command(cmd.into()));
+        self
+    }
+
+    /// Append commands, one per line
+    pub fn commands(mut self, cmds: TmuxCommands<'a>) -> Self {
+        for cmd in cmds.commands {
+            self = self.command(cmd);
+        }
+        self
+    }
+
+    /// Append commands loaded only by tmux (major, minor) or newer
+    pub fn since(mut self, version: (usize, usize), conf: TmuxConf<'a>) -> Self {
+        self.items.push(TmuxConfItem::Since(version, conf));
+        self
+    }
+
+    /// Set server options (`set-option -s`), options not set are omitted
+    pub fn server_options(self, options: &ServerOptions<'a>) -> Self {
+        self.options(OptionScope::Server, options)
+    }
+
+    /// Set global session options (`set-option -g`), options not set are omitted
+    pub fn session_options(self, options: &SessionOptions<'a>) -> Self {
+        self.options(OptionScope::GlobalSession, options)
+    }
+
+    /// Set global window options (`set-option -g -w`), options not set are omitted
+    pub fn window_options(self, options: &WindowOptions<'a>) -> Self {
+        self.options(OptionScope::GlobalWindow, options)
+    }
+
+    /// Set pane options globally, pane options are inherited from the global window options
+    /// (`set-option -g -w`), options not set are omitted
+    #[cfg(feature = "tmux_3_1")]
+    pub fn pane_options(self, options: &PaneOptions<'a>) -> Self {
+        self.options(OptionScope::GlobalWindow, options)
+    }
+
+    fn options<T: Default + OptionsValuesTr>(self, scope: OptionScope<'a>, options: &T) -> Self {
+        let diff = OptionsDiff::diff(scope, &T::default(), options);
+        self.commands(diff.to_commands())
+    }
+
+    pub fn bind_key(self, bind_key: BindKey<'a>) -> Self {
+        self.command(bind_key.build())
+    }
+
+    #[cfg(feature = "tmux_2_2")]
+    pub fn set_hook(self, set_hook: SetHook<'a>) -> Self {
+        self.command(set_hook.build())
+    }
+
+    pub fn set_environment(self, set_environment: SetEnvironment<'a>) -> Self {
+        self.command(set_environment.build())
+    }
+
+    /// Append command alias (`set-option -as command-alias name=command`), tmux splits the
+    /// appended value on `,`
+    #[cfg(feature = "tmux_2_4")]
+    pub fn command_alias<S: Into<Cow<'a, str>>>(self, name: S, command: S) -> Self {
+        let alias = format!("{}={}", name.into(), command.into());
+        let cmd = OptionScope::Server.append_option(Cow::from("command-alias"), Cow::from(alias));
+        self.command(cmd)
+    }
+
+    /// Write the configuration file
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for TmuxConf<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for item in &self.items {
+            match item {
+                TmuxConfItem::Command(cmd) => fmt_command(f, cmd)?,
+                TmuxConfItem::Since((major, minor), conf) => {
+                    writeln!(f, "%if \"#{{>=:#{{version}},{}.{}}}\"", major, minor)?;
+                    write!(f, "{}", conf)?;
+                    writeln!(f, "%endif")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// command line, commands with subcommands are written one per line
+fn fmt_command(f: &mut fmt::Formatter, cmd: &TmuxCommand) -> fmt::Result {
+    match &cmd.subcommands {
+        Some(cmds) => {
+            for cmd in &cmds.commands {
+                fmt_command(f, cmd)?;
+            }
+            Ok(())
+        }
+        None => writeln!(f, "{}", conf_command(cmd)),
+    }
+}
+
+/// Command line for the tmux command parser (e.g. menu item command, key binding), arguments
+/// are quoted, subcommands are separated by ` ; `
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{conf_command, SetOption, TmuxCommand, TmuxCommands, WaitFor};
+///
+/// let cmds = TmuxCommands::new()
+///     .cmd(SetOption::new().server().option("@a").value("a b"))
+///     .cmd(WaitFor::new().woken().channel("a"));
+///
+/// # #[cfg(feature = "cmd_alias")]
+/// assert_eq!(
+///     conf_command(&TmuxCommand::with_cmds(cmds)),
+///     "set -s @a 'a b' ; wait -S a"
+/// );
+/// ```
+pub fn conf_command(cmd: &TmuxCommand) -> String {
+    match &cmd.subcommands {
+        Some(cmds) => {
+            let cmds: Vec<String> = cmds.commands.iter().map(conf_command).collect();
+            cmds.join(" ; ")
+        }
+        None => {
+            let args = cmd.to_vec();
+            let args: Vec<Cow<str>> = args.iter().map(|arg| conf_quote(arg)).collect();
+            args.join(" ")
+        }
+    }
+}
+
+/// Quote the argument for the configuration file parser, single quotes are used if
+/// possible, double quotes (escaped `\`, `"`, `$`, control characters) otherwise
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::conf_quote;
+///
+/// assert_eq!(conf_quote("C-b"), "C-b");
+/// assert_eq!(conf_quote("#[fg=red] $HOME"), "'#[fg=red] $HOME'");
+/// assert_eq!(conf_quote("it's\n"), "\"it's\\n\"");
+/// ```
+pub fn conf_quote(arg: &str) -> Cow<'_, str> {
+    let safe = |c: char| c.is_ascii_alphanumeric() || SAFE_CHARS.contains(c);
+    if !arg.is_empty() && arg.chars().all(safe) {
+        return Cow::Borrowed(arg);
+    }
+    if !arg.contains(SINGLE_QUOTE) {
+        return Cow::Owned(format!("{}{}{}", SINGLE_QUOTE, arg, SINGLE_QUOTE));
+    }
+
+    let mut s = String::with_capacity(arg.len() + 2);
+    s.push(DOUBLE_QUOTE);
+    for c in arg.chars() {
+        match c {
+            BACKSLASH | DOUBLE_QUOTE | '$' => {
+                s.push(BACKSLASH);
+                s.push(c);
+            }
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if c.is_ascii_control() => s.push_str(&format!("\\{:03o}", c as u32)),
+            c => s.push(c),
+        }
+    }
+    s.push(DOUBLE_QUOTE);
+    Cow::Owned(s)
+}
@@ -0,0 +1,703 @@
+use super::conf_lexer::{ConfLexer, ConfLine};
+use crate::options::options_registry::split_index;
+#[cfg(feature = "tmux_3_1")]
+use crate::PaneOptions;
+use crate::{
+    ArrayOption, Error, OptionInfo, OptionKind, OptionType, OptionsRegistry, OptionsValuesTr,
+    ServerOptions, SessionOptions, Version, WindowOptions,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const USER_OPTION_MARKER: char = '@';
+const ASSIGNMENT: char = '=';
+const GLOB_CHARS: &[char] = &['*', '?', '['];
+const FLAG_ON: &[&str] = &["on", "yes", "1"];
+const FLAG_OFF: &[&str] = &["off", "no", "0"];
+const MOUSE: &str = "mouse";
+const MOUSE_OPTIONS: &[&str] = &[
+    "mode-mouse",
+    "mouse-resize-pane",
+    "mouse-select-pane",
+    "mouse-select-window",
+];
+const STYLE_SUFFIXES: &[&str] = &["-fg", "-bg", "-attr"];
+const STYLE_SUFFIX: &str = "-style";
+// nested `source-file` limit
+const MAX_DEPTH: usize = 50;
+
+/// File and line of the configuration file command
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ConfLocation {
+    pub file: PathBuf,
+    /// line number, starting from 1
+    pub line: usize,
+}
+
+impl fmt::Display for ConfLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file.display(), self.line)
+    }
+}
+
+/// Problem found in the configuration file, see [`TmuxConfLoader`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ConfIssueKind {
+    /// syntax error, not readable included file, condition which can not be evaluated
+    Parse(String),
+    /// option not known to any tmux version
+    UnknownOption(String),
+    /// option name and value not accepted by the option type
+    InvalidValue(String, String),
+    /// option removed before the chosen tmux version, replacement option if known
+    Deprecated(String, Option<&'static str>),
+    /// option added after the chosen tmux version
+    Unsupported(String),
+}
+
+impl fmt::Display for ConfIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parse(msg) => write!(f, "{}", msg),
+            Self::UnknownOption(name) => write!(f, "unknown option: {}", name),
+            Self::InvalidValue(name, value) => {
+                write!(f, "invalid value for option {}: {:?}", name, value)
+            }
+            Self::Deprecated(name, Some(replacement)) => {
+                write!(f, "deprecated option: {} (use {})", name, replacement)
+            }
+            Self::Deprecated(name, None) => write!(f, "deprecated option: {}", name),
+            Self::Unsupported(name) => write!(f, "option not supported by tmux version: {}", name),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ConfIssue {
+    pub location: ConfLocation,
+    pub kind: ConfIssueKind,
+}
+
+impl fmt::Display for ConfIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.kind)
+    }
+}
+
+/// Options set by the configuration file and found problems, see [`TmuxConfLoader`]
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct LoadedTmuxConf<'a> {
+    pub server_options: ServerOptions<'a>,
+    pub session_options: SessionOptions<'a>,
+    pub window_options: WindowOptions<'a>,
+    #[cfg(feature = "tmux_3_1")]
+    pub pane_options: PaneOptions<'a>,
+    pub issues: Vec<ConfIssue>,
+}
+
+/// tmux configuration file (`.tmux.conf`) loader and linter
+///
+/// `set-option` and `set-window-option` commands are mapped onto the options structures
+/// (values are parsed by the option field types, e.g. [`Switch`](crate::Switch),
+/// [`StatusKeys`](crate::StatusKeys)), other commands are skipped. Included files
+/// (`source-file`, relative to the including file), `%if` blocks (`#{version}` and
+/// comparisons only, evaluated for the chosen tmux version) and variables (`NAME=value`,
+/// `%hidden NAME=value`) are processed
+///
+/// Target (`-t`) and global (`-g`) flags are ignored, options are considered global
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{ConfIssueKind, Switch, TmuxConfLoader, Version};
+///
+/// let version = Version {
+///     major: 3,
+///     minor: 3,
+///     ..Default::default()
+/// };
+/// let conf = "set -g mouse on\n\
+///             set -g status-keys nano\n\
+///             set -g mode-mouse on\n";
+///
+/// let loaded = TmuxConfLoader::new(version).parse(conf, "tmux.conf");
+/// assert_eq!(loaded.session_options.mouse, Some(Switch::On));
+/// assert_eq!(
+///     loaded.issues[0].to_string(),
+///     "tmux.conf:2: invalid value for option status-keys: \"nano\""
+/// );
+/// assert_eq!(
+///     loaded.issues[1].kind,
+///     ConfIssueKind::Deprecated("mode-mouse".to_string(), Some("mouse"))
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct TmuxConfLoader {
+    /// tmux version options and `%if` conditions are checked against
+    pub version: Version,
+}
+
+impl TmuxConfLoader {
+    pub fn new(version: Version) -> Self {
+        Self { version }
+    }
+
+    /// Load the configuration file, not readable included files are reported as issues
+    pub fn load<'a, P: AsRef<Path>>(&self, path: P) -> Result<LoadedTmuxConf<'a>, Error> {
+        let path = path.as_ref();
+        let s = fs::read_to_string(path)?;
+        Ok(self.parse(&s, path))
+    }
+
+    /// Parse the configuration file content, the path is used for issue locations and
+    /// included files
+    pub fn parse<'a, P: AsRef<Path>>(&self, s: &str, path: P) -> LoadedTmuxConf<'a> {
+        let mut state = LoadState::default();
+        self.parse_file(&mut state, s, path.as_ref(), 0);
+        LoadedTmuxConf {
+            server_options: state.server.parse(),
+            session_options: state.session.parse(),
+            window_options: state.window.parse(),
+            #[cfg(feature = "tmux_3_1")]
+            pane_options: state.pane.parse(),
+            issues: state.issues,
+        }
+    }
+
+    fn parse_file(&self, state: &mut LoadState, s: &str, path: &Path, depth: usize) {
+        let mut lexer = ConfLexer::new(s);
+        // `%if` blocks, (active, any branch taken)
+        let mut conditions: Vec<(bool, bool)> = Vec::new();
+        let mut last_line = 0;
+
+        while let Some((line, conf_line)) = lexer.next_line(&state.vars) {
+            last_line = line;
+            let location = ConfLocation {
+                file: path.to_path_buf(),
+                line,
+            };
+            let conf_line = match conf_line {
+                Ok(conf_line) => conf_line,
+                Err(msg) => {
+                    state.issue(location, ConfIssueKind::Parse(msg));
+                    return;
+                }
+            };
+            let active = conditions.iter().all(|(active, _)| *active);
+            let parent_active = || {
+                let parents = &conditions[..conditions.len().saturating_sub(1)];
+                parents.iter().all(|(active, _)| *active)
+            };
+
+            match conf_line {
+                ConfLine::If(condition) => {
+                    let value = active && self.condition(state, &location, &condition);
+                    conditions.push((value, value));
+                }
+                ConfLine::Elif(condition) => {
+                    let parent_active = parent_active();
+                    match conditions.last_mut() {
+                        Some((active, taken)) => {
+                            *active = parent_active
+                                && !*taken
+                                && self.condition(state, &location, &condition);
+                            *taken |= *active;
+                        }
+                        None => state.issue(location, parse_issue("%elif without %if")),
+                    }
+                }
+                ConfLine::Else => {
+                    let parent_active = parent_active();
+                    match conditions.last_mut() {
+                        Some((active, taken)) => {
+                            *active = parent_active && !*taken;
+                            *taken = true;
+                        }
+                        None => state.issue(location, parse_issue("%else without %if")),
+                    }
+                }
+                ConfLine::Endif => {
+                    if conditions.pop().is_none() {
+                        state.issue(location, parse_issue("%endif without %if"));
+                    }
+                }
+                _ if !active => {}
+                ConfLine::Hidden(assignment) => match assignment.split_once(ASSIGNMENT) {
+                    Some((name, value)) => {
+                        state.vars.insert(name.to_string(), value.to_string());
+                    }
+                    None => state.issue(location, parse_issue("invalid %hidden assignment")),
+                },
+                ConfLine::Command(args) => self.command(state, location, args, path, depth),
+            }
+        }
+
+        if !conditions.is_empty() {
+            let location = ConfLocation {
+                file: path.to_path_buf(),
+                line: last_line,
+            };
+            state.issue(location, parse_issue("missing %endif"));
+        }
+    }
+
+    fn command(
+        &self,
+        state: &mut LoadState,
+        location: ConfLocation,
+        args: Vec<String>,
+        path: &Path,
+        depth: usize,
+    ) {
+        let (name, args) = match args.split_first() {
+            Some((name, args)) => (name.as_str(), args),
+            None => return,
+        };
+        match name {
+            "set-option" | "set" => self.set_option(state, location, args, false),
+            "set-window-option" | "setw" => self.set_option(state, location, args, true),
+            "source-file" | "source" => self.source_file(state, location, args, path, depth),
+            // environment variable assignment
+            _ if args.is_empty() && is_assignment(name) => {
+                if let Some((name, value)) = name.split_once(ASSIGNMENT) {
+                    state.vars.insert(name.to_string(), value.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn set_option(
+        &self,
+        state: &mut LoadState,
+        location: ConfLocation,
+        args: &[String],
+        window: bool,
+    ) {
+        let (flags, args) = match parse_flags(args, "aFgopqsuUw", "t") {
+            Ok(parsed) => parsed,
+            Err(msg) => return state.issue(location, ConfIssueKind::Parse(msg)),
+        };
+        let (name, value) = match args {
+            [name] => (name.as_str(), None),
+            [name, value] => (name.as_str(), Some(value.clone())),
+            [] => return state.issue(location, parse_issue("missing option name")),
+            _ => return state.issue(location, parse_issue("too many arguments")),
+        };
+        let flag = |c| flags.contains(c);
+
+        // user options, scope given by flags
+        if name.starts_with(USER_OPTION_MARKER) {
+            let kind = match () {
+                _ if flag('s') => OptionKind::Server,
+                _ if flag('p') => OptionKind::Pane,
+                _ if window || flag('w') => OptionKind::Window,
+                _ => OptionKind::Session,
+            };
+            let values = state.values(kind);
+            return match (flag('u') || flag('U'), value) {
+                (true, _) => values.unset(name),
+                (false, Some(value)) => values.set(name, value, flag('a'), flag('o'), false),
+                (false, None) => state.issue(location, parse_issue("missing option value")),
+            };
+        }
+
+        let (option_name, index) = split_index(name);
+        let info = match self.option_info(state, &location, option_name, flag('q')) {
+            Some(info) => info,
+            None => return,
+        };
+        if index.is_some() && !info.array {
+            return state.issue(location, ConfIssueKind::UnknownOption(name.to_string()));
+        }
+
+        let values = state.values(info.kind);
+        if flag('u') || flag('U') {
+            return values.unset(name);
+        }
+        let value = match (value, info.value_type) {
+            (Some(value), OptionType::Flag) => normalize_flag(&value).unwrap_or(value),
+            (Some(value), _) => value,
+            // toggle
+            (None, OptionType::Flag) => {
+                let current = values.get(name).or_else(|| info.default.first().copied());
+                match current {
+                    Some(current) if FLAG_ON.contains(&current) => FLAG_OFF[0].to_string(),
+                    _ => FLAG_ON[0].to_string(),
+                }
+            }
+            (None, OptionType::Choice(_)) => return,
+            (None, _) => return state.issue(location, parse_issue("missing option value")),
+        };
+        // format values (`-F`) are expanded by tmux, not checked
+        if !flag('F') && !is_valid_value(info, name, &value) {
+            let kind = ConfIssueKind::InvalidValue(name.to_string(), value);
+            return state.issue(location, kind);
+        }
+        state.values(info.kind).set(
+            name,
+            value,
+            flag('a'),
+            flag('o'),
+            info.array && index.is_none(),
+        );
+    }
+
+    // option supported by the chosen version, unknown, deprecated and unsupported options are
+    // reported (unknown options only without `-q`)
+    fn option_info(
+        &self,
+        state: &mut LoadState,
+        location: &ConfLocation,
+        name: &str,
+        quiet: bool,
+    ) -> Option<&'static OptionInfo> {
+        if let Some(info) = OptionsRegistry::get(name, &self.version) {
+            return Some(info);
+        }
+        let version = (self.version.major, self.version.minor);
+        let added: Vec<(usize, usize)> =
+            OptionsRegistry::find(name).map(|info| info.added).collect();
+        let kind = match added.iter().min() {
+            None if quiet => return None,
+            None => ConfIssueKind::UnknownOption(name.to_string()),
+            Some(added) if *added <= version => {
+                ConfIssueKind::Deprecated(name.to_string(), self.replacement(name))
+            }
+            Some(_) => ConfIssueKind::Unsupported(name.to_string()),
+        };
+        state.issue(location.clone(), kind);
+        None
+    }
+
+    // option replacing the deprecated option in the chosen version
+    fn replacement(&self, name: &str) -> Option<&'static str> {
+        let replacement = if MOUSE_OPTIONS.contains(&name) {
+            MOUSE.to_string()
+        } else {
+            let base = STYLE_SUFFIXES
+                .iter()
+                .find_map(|suffix| name.strip_suffix(suffix))?;
+            format!("{}{}", base, STYLE_SUFFIX)
+        };
+        OptionsRegistry::get(&replacement, &self.version).map(|info| info.name)
+    }
+
+    fn source_file(
+        &self,
+        state: &mut LoadState,
+        location: ConfLocation,
+        args: &[String],
+        path: &Path,
+        depth: usize,
+    ) {
+        let (flags, files) = match parse_flags(args, "Fnqv", "t") {
+            Ok(parsed) => parsed,
+            Err(msg) => return state.issue(location, ConfIssueKind::Parse(msg)),
+        };
+        if depth >= MAX_DEPTH {
+            return state.issue(location, parse_issue("too many nested files"));
+        }
+        for file in files {
+            if file.contains(GLOB_CHARS) {
+                let msg = format!("glob patterns are not supported: {}", file);
+                state.issue(location.clone(), ConfIssueKind::Parse(msg));
+                continue;
+            }
+            let file = match path.parent() {
+                Some(dir) => dir.join(file),
+                None => PathBuf::from(file),
+            };
+            match fs::read_to_string(&file) {
+                Ok(s) => self.parse_file(state, &s, &file, depth + 1),
+                Err(_) if flags.contains('q') => {}
+                Err(err) => {
+                    let msg = format!("{}: {}", file.display(), err);
+                    state.issue(location.clone(), ConfIssueKind::Parse(msg));
+                }
+            }
+        }
+    }
+
+    // `%if` condition value, not supported formats are reported and evaluated as false
+    fn condition(&self, state: &mut LoadState, location: &ConfLocation, condition: &str) -> bool {
+        let version = format!(
+            "{}.{}{}",
+            self.version.major, self.version.minor, self.version.suffix
+        );
+        match expand_format(condition, &version) {
+            Some(value) => !value.is_empty() && value != "0",
+            None => {
+                let msg = format!("unsupported condition: {}", condition);
+                state.issue(location.clone(), ConfIssueKind::Parse(msg));
+                false
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct LoadState {
+    vars: HashMap<String, String>,
+    server: OptionValues,
+    session: OptionValues,
+    window: OptionValues,
+    #[cfg(feature = "tmux_3_1")]
+    pane: OptionValues,
+    issues: Vec<ConfIssue>,
+}
+
+impl LoadState {
+    fn values(&mut self, kind: OptionKind) -> &mut OptionValues {
+        match kind {
+            OptionKind::Server => &mut self.server,
+            OptionKind::Session => &mut self.session,
+            OptionKind::Window => &mut self.window,
+            #[cfg(feature = "tmux_3_1")]
+            OptionKind::Pane => &mut self.pane,
+            #[cfg(not(feature = "tmux_3_1"))]
+            OptionKind::Pane => &mut self.window,
+        }
+    }
+
+    fn issue(&mut self, location: ConfLocation, kind: ConfIssueKind) {
+        self.issues.push(ConfIssue { location, kind });
+    }
+}
+
+// option values set so far, array options by index
+#[derive(Default)]
+struct OptionValues {
+    values: BTreeMap<String, String>,
+    arrays: BTreeMap<String, ArrayOption<String>>,
+}
+
+impl OptionValues {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    fn set(&mut self, name: &str, value: String, append: bool, only_unset: bool, array: bool) {
+        let (name, index) = split_index(name);
+        match index.and_then(|index| index.parse().ok()) {
+            Some(index) => {
+                let array = self.arrays.entry(name.to_string()).or_default();
+                if !(only_unset && array.get(index).is_some()) {
+                    array.insert(index, value);
+                }
+            }
+            None if array => {
+                let array = self.arrays.entry(name.to_string()).or_default();
+                match append {
+                    true => array.push(value),
+                    false if only_unset && !array.is_empty() => {}
+                    false => *array = ArrayOption::from(vec![value]),
+                }
+            }
+            None => match self.values.get_mut(name) {
+                Some(_) if only_unset => {}
+                Some(current) if append => current.push_str(&value),
+                _ => {
+                    self.values.insert(name.to_string(), value);
+                }
+            },
+        }
+    }
+
+    fn unset(&mut self, name: &str) {
+        let (name, index) = split_index(name);
+        match index.and_then(|index| index.parse().ok()) {
+            Some(index) => {
+                if let Some(array) = self.arrays.get_mut(name) {
+                    array.remove(index);
+                }
+            }
+            None => {
+                self.values.remove(name);
+                self.arrays.remove(name);
+            }
+        }
+    }
+
+    // options structure fields set by the values (values may contain new lines or be empty),
+    // array items are renumbered
+    fn parse<T: OptionsValuesTr + Default>(&self) -> T {
+        let mut options = T::default();
+        for (name, value) in &self.values {
+            options.set_value(name, None, Some(value));
+        }
+        for (name, array) in &self.arrays {
+            for (i, (_, value)) in array.iter().enumerate() {
+                options.set_value(name, Some(i), Some(value));
+            }
+        }
+        options
+    }
+}
+
+// value is parsed by the options structure field type, options not known by the structure
+// (not enabled by features) are checked by the registry
+fn is_valid_value(info: &OptionInfo, name: &str, value: &str) -> bool {
+    match info.value_type {
+        OptionType::Flag | OptionType::Number | OptionType::Choice(_) => {}
+        _ => return true,
+    }
+    let parsed = |value: &str| is_parsed(info.kind, name, value);
+    if parsed(value) {
+        return true;
+    }
+    match info.default.first() {
+        Some(default) if parsed(default) => false,
+        _ => info.validate_value(Some(value)).is_ok(),
+    }
+}
+
+// option value sets a field of the options structure
+fn is_parsed(kind: OptionKind, name: &str, value: &str) -> bool {
+    fn parsed<T: OptionsValuesTr + Default + PartialEq>(name: &str, value: &str) -> bool {
+        let mut options = T::default();
+        options.set_value(name, None, Some(value));
+        options != T::default()
+    }
+    match kind {
+        OptionKind::Server => parsed::<ServerOptions>(name, value),
+        OptionKind::Session => parsed::<SessionOptions>(name, value),
+        OptionKind::Window => parsed::<WindowOptions>(name, value),
+        #[cfg(feature = "tmux_3_1")]
+        OptionKind::Pane => parsed::<PaneOptions>(name, value),
+        #[cfg(not(feature = "tmux_3_1"))]
+        OptionKind::Pane => parsed::<WindowOptions>(name, value),
+    }
+}
+
+// `on` or `off` for flag option value accepted by tmux
+fn normalize_flag(value: &str) -> Option<String> {
+    let value = value.to_lowercase();
+    match value.as_str() {
+        v if FLAG_ON.contains(&v) => Some(FLAG_ON[0].to_string()),
+        v if FLAG_OFF.contains(&v) => Some(FLAG_OFF[0].to_string()),
+        _ => None,
+    }
+}
+
+// `NAME=value` environment variable assignment
+fn is_assignment(s: &str) -> bool {
+    match s.split_once(ASSIGNMENT) {
+        Some((name, _)) => {
+            !name.is_empty()
+                && !name.starts_with(|c: char| c.is_ascii_digit())
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        None => false,
+    }
+}
+
+// getopt like flags, returns flags and remaining arguments
+fn parse_flags<'s>(
+    args: &'s [String],
+    flags: &str,
+    flags_with_arg: &str,
+) -> Result<(String, &'s [String]), String> {
+    let mut parsed = String::new();
+    let mut i = 0;
+    while let Some(arg) = args.get(i) {
+        if arg == "--" {
+            i += 1;
+            break;
+        }
+        let chars = match arg.strip_prefix('-') {
+            Some(chars) if !chars.is_empty() => chars,
+            _ => break,
+        };
+        i += 1;
+        for (j, c) in chars.char_indices() {
+            if flags_with_arg.contains(c) {
+                // argument in the same or the next word
+                if j + c.len_utf8() == chars.len() {
+                    i += 1;
+                }
+                break;
+            } else if flags.contains(c) {
+                parsed.push(c);
+            } else {
+                return Err(format!("unknown flag: -{}", c));
+            }
+        }
+    }
+    Ok((parsed, args.get(i..).unwrap_or_default()))
+}
+
+fn parse_issue(msg: &str) -> ConfIssueKind {
+    ConfIssueKind::Parse(msg.to_string())
+}
+
+// expand supported formats: `#{version}`, comparisons (`#{==:a,b}`, `!=`, `<`, `>`, `<=`,
+// `>=`) and `##`, `None` for other formats
+fn expand_format(s: &str, version: &str) -> Option<String> {
+    let mut output = String::new();
+    let mut rest = s;
+    while let Some(i) = rest.find('#') {
+        output.push_str(&rest[..i]);
+        rest = &rest[i + 1..];
+        if let Some(s) = rest.strip_prefix('#') {
+            output.push('#');
+            rest = s;
+            continue;
+        }
+        let inner = rest.strip_prefix('{')?;
+        let end = matching_brace(inner)?;
+        output.push_str(&expand_variable(&inner[..end], version)?);
+        rest = &inner[end + 1..];
+    }
+    output.push_str(rest);
+    Some(output)
+}
+
+fn expand_variable(s: &str, version: &str) -> Option<String> {
+    if s == "version" {
+        return Some(version.to_string());
+    }
+    let (op, args) = s.split_once(':')?;
+    let comma = top_level_comma(args)?;
+    let left = expand_format(&args[..comma], version)?;
+    let right = expand_format(&args[comma + 1..], version)?;
+    let value = match op {
+        "==" => left == right,
+        "!=" => left != right,
+        "<" => left < right,
+        ">" => left > right,
+        "<=" => left <= right,
+        ">=" => left >= right,
+        _ => return None,
+    };
+    Some(if value { "1" } else { "0" }.to_string())
+}
+
+// index of the `}` closing the format (nested formats skipped)
+fn matching_brace(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(i),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn top_level_comma(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
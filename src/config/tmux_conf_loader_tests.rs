@@ -0,0 +1,252 @@
+fn version(major: usize, minor: usize) -> crate::Version {
+    crate::Version {
+        major,
+        minor,
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "tmux_2_4")]
+#[test]
+fn tmux_conf_loader_options() {
+    use crate::{StatusKeys, Switch, TmuxConfLoader};
+    use std::borrow::Cow;
+
+    let conf = "set -s escape-time 10\n\
+                set -g mouse yes\n\
+                set -g status-left '[#S] '\n\
+                set -ga status-left '#I'\n\
+                set -go base-index 1\n\
+                set -go base-index 2\n\
+                setw -g mode-keys vi\n\
+                set -gw @my_option 'a b'\n\
+                set -s command-alias[0] a=b\n\
+                set -as command-alias c=d\n\
+                set -s command-alias[5] e=f\n\
+                set -su command-alias[0]\n";
+    let loaded = TmuxConfLoader::new(version(3, 3)).parse(conf, "tmux.conf");
+
+    assert_eq!(loaded.issues, vec![]);
+    assert_eq!(loaded.server_options.escape_time, Some(10));
+    assert_eq!(
+        loaded.server_options.command_alias,
+        Some(vec![Cow::from("c=d"), Cow::from("e=f")])
+    );
+    assert_eq!(loaded.session_options.mouse, Some(Switch::On));
+    assert_eq!(
+        loaded.session_options.status_left,
+        Some(Cow::from("[#S] #I"))
+    );
+    assert_eq!(loaded.session_options.base_index, Some(1));
+    assert_eq!(loaded.window_options.mode_keys, Some(StatusKeys::Vi));
+    assert_eq!(
+        loaded.window_options.user_options.get("my_option"),
+        Some(&Some(Cow::from("a b")))
+    );
+}
+
+#[test]
+fn tmux_conf_loader_values() {
+    use crate::{ConfIssueKind, TmuxConfLoader};
+    use std::borrow::Cow;
+
+    let conf = "set -g status-left \"a\\nb\"\n\
+                set -g status-right ''\n\
+                set -g base-index x\n\
+                set -g history-limit 10\n";
+    let loaded = TmuxConfLoader::new(version(3, 3)).parse(conf, "tmux.conf");
+
+    assert_eq!(
+        loaded.issues[0].kind,
+        ConfIssueKind::InvalidValue("base-index".to_string(), "x".to_string())
+    );
+    assert_eq!(loaded.session_options.status_left, Some(Cow::from("a\nb")));
+    assert_eq!(loaded.session_options.status_right, Some(Cow::from("")));
+    assert_eq!(loaded.session_options.base_index, None);
+    assert_eq!(loaded.session_options.history_limit, Some(10));
+}
+
+#[cfg(feature = "tmux_2_9")]
+#[test]
+fn tmux_conf_loader_round_trip() {
+    use crate::{SessionOptions, TmuxConf, TmuxConfLoader};
+
+    let session_options = SessionOptions {
+        status_left: Some("a\nit's b".into()),
+        status_right: Some("c\nd".into()),
+        status_format: Some(vec!["#[align=left]#S".into(), "#{pane_title}".into()]),
+        ..Default::default()
+    };
+    let conf = TmuxConf::new()
+        .session_options(&session_options)
+        .to_string();
+    let loaded = TmuxConfLoader::new(version(3, 3)).parse(&conf, "tmux.conf");
+
+    assert_eq!(loaded.issues, vec![]);
+    assert_eq!(loaded.session_options, session_options);
+}
+
+#[test]
+fn tmux_conf_loader_issues() {
+    use crate::{ConfIssueKind, ConfLocation, TmuxConfLoader};
+    use std::path::PathBuf;
+
+    let conf = "set -g status-keys nano\n\
+                set -g base-index x\n\
+                set -g no-such-option on\n\
+                set -gq no-such-option on\n\
+                set -g status-bg red\n\
+                set -g mode-mouse on\n\
+                set -g status-utf8 on\n\
+                set -g status-left[1] x\n\
+                set -g -Z mouse on\n\
+                set -g status-left 'x\n";
+    let loaded = TmuxConfLoader::new(version(3, 3)).parse(conf, "tmux.conf");
+    let kinds: Vec<(usize, ConfIssueKind)> = loaded
+        .issues
+        .iter()
+        .map(|issue| (issue.location.line, issue.kind.clone()))
+        .collect();
+
+    let name = |s: &str| s.to_string();
+    assert_eq!(
+        kinds,
+        vec![
+            (
+                1,
+                ConfIssueKind::InvalidValue(name("status-keys"), name("nano"))
+            ),
+            (
+                2,
+                ConfIssueKind::InvalidValue(name("base-index"), name("x"))
+            ),
+            (3, ConfIssueKind::UnknownOption(name("no-such-option"))),
+            (
+                5,
+                ConfIssueKind::Deprecated(name("status-bg"), Some("status-style"))
+            ),
+            (
+                6,
+                ConfIssueKind::Deprecated(name("mode-mouse"), Some("mouse"))
+            ),
+            (7, ConfIssueKind::Deprecated(name("status-utf8"), None)),
+            (8, ConfIssueKind::UnknownOption(name("status-left[1]"))),
+            (9, ConfIssueKind::Parse(name("unknown flag: -Z"))),
+            (10, ConfIssueKind::Parse(name("unterminated quote"))),
+        ]
+    );
+    assert_eq!(
+        loaded.issues[0].location,
+        ConfLocation {
+            file: PathBuf::from("tmux.conf"),
+            line: 1
+        }
+    );
+
+    let loaded = TmuxConfLoader::new(version(1, 8)).parse("set -g mouse on\n", "tmux.conf");
+    assert_eq!(
+        loaded.issues[0].to_string(),
+        "tmux.conf:1: option not supported by tmux version: mouse"
+    );
+    let loaded = TmuxConfLoader::new(version(2, 9)).parse("set -g status-bg red", "tmux.conf");
+    assert_eq!(
+        loaded.issues[0].kind,
+        ConfIssueKind::Deprecated(name("status-bg"), Some("status-style"))
+    );
+}
+
+#[test]
+fn tmux_conf_loader_if() {
+    use crate::{ConfIssueKind, TmuxConfLoader};
+    use std::borrow::Cow;
+
+    let conf = "%if \"#{>=:#{version},3.2}\"\n\
+                set -g @a new\n\
+                %if '#{==:#{version},3.2}'\n\
+                set -g @b 3.2\n\
+                %else\n\
+                set -g @b other\n\
+                %endif\n\
+                %elif \"#{>=:#{version},2.9}\"\n\
+                set -g @a old\n\
+                %else\n\
+                set -g @a older\n\
+                %endif\n";
+    let user_option = |major, minor, name: &str| {
+        let loaded = TmuxConfLoader::new(version(major, minor)).parse(conf, "tmux.conf");
+        assert_eq!(loaded.issues, vec![]);
+        loaded
+            .session_options
+            .user_options
+            .get(name)
+            .cloned()
+            .flatten()
+    };
+    assert_eq!(user_option(3, 3, "a"), Some(Cow::from("new")));
+    assert_eq!(user_option(3, 3, "b"), Some(Cow::from("other")));
+    assert_eq!(user_option(3, 2, "b"), Some(Cow::from("3.2")));
+    assert_eq!(user_option(3, 0, "a"), Some(Cow::from("old")));
+    assert_eq!(user_option(3, 0, "b"), None);
+    assert_eq!(user_option(2, 8, "a"), Some(Cow::from("older")));
+
+    let loaded = TmuxConfLoader::new(version(3, 3)).parse(
+        "%if \"#{session_name}\"\nset -g @a a\n%endif\n%if 1\n",
+        "tmux.conf",
+    );
+    let kinds: Vec<ConfIssueKind> = loaded.issues.into_iter().map(|issue| issue.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ConfIssueKind::Parse("unsupported condition: #{session_name}".to_string()),
+            ConfIssueKind::Parse("missing %endif".to_string()),
+        ]
+    );
+    assert!(loaded.session_options.user_options.is_empty());
+}
+
+#[test]
+fn tmux_conf_loader_source_file() {
+    use crate::{ConfIssueKind, ConfLocation, TmuxConfLoader};
+    use std::borrow::Cow;
+    use std::fs;
+
+    let dir = std::env::temp_dir().join(format!("tmux_conf_loader_test_{}", std::process::id()));
+    fs::create_dir_all(dir.join("conf.d")).unwrap();
+    fs::write(
+        dir.join("tmux.conf"),
+        "%hidden THEME=dark\n\
+         source-file conf.d/theme.conf\n\
+         source-file -q conf.d/missing.conf\n\
+         source-file conf.d/missing.conf\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("conf.d/theme.conf"),
+        "set -g @theme $THEME\n\
+         set -g status-keys nano\n",
+    )
+    .unwrap();
+
+    let loaded = TmuxConfLoader::new(version(3, 3)).load(dir.join("tmux.conf"));
+    let missing = TmuxConfLoader::new(version(3, 3)).load(dir.join("missing.conf"));
+    fs::remove_dir_all(&dir).unwrap();
+    let loaded = loaded.unwrap();
+
+    assert!(missing.is_err());
+    assert_eq!(
+        loaded.session_options.user_options.get("theme"),
+        Some(&Some(Cow::from("dark")))
+    );
+    assert_eq!(loaded.issues.len(), 2);
+    assert_eq!(
+        loaded.issues[0].location,
+        ConfLocation {
+            file: dir.join("conf.d/theme.conf"),
+            line: 2
+        }
+    );
+    assert_eq!(loaded.issues[1].location.line, 4);
+    assert!(
+        matches!(&loaded.issues[1].kind, ConfIssueKind::Parse(msg) if msg.contains("missing.conf"))
+    );
+}
@@ -0,0 +1,99 @@
+#[test]
+fn conf_quote() {
+    use crate::conf_quote;
+
+    assert_eq!(conf_quote("status-left"), "status-left");
+    assert_eq!(conf_quote("command-alias[1]"), "command-alias[1]");
+    assert_eq!(conf_quote(""), "''");
+    assert_eq!(conf_quote("a b"), "'a b'");
+    assert_eq!(conf_quote(";"), "';'");
+    assert_eq!(conf_quote("#{pane_id}"), "'#{pane_id}'");
+    assert_eq!(conf_quote("\"x\"\n"), "'\"x\"\n'");
+    assert_eq!(
+        conf_quote("it's \"$HOME\" \\\t\x1b"),
+        "\"it's \\\"\\$HOME\\\" \\\\\\t\\033\""
+    );
+}
+
+#[test]
+fn tmux_conf() {
+    use crate::{SetOption, TmuxCommand, TmuxCommands, TmuxConf, WindowOptions};
+
+    let conf = TmuxConf::new()
+        .window_options(&WindowOptions {
+            mode_keys: Some(crate::StatusKeys::Vi),
+            ..Default::default()
+        })
+        .since(
+            (3, 2),
+            TmuxConf::new().commands(
+                TmuxCommands::new()
+                    .cmd(SetOption::new().global().option("@a").value("it's"))
+                    .cmd(SetOption::new().global().option("@b").value("")),
+            ),
+        )
+        .command(TmuxCommand::with_cmds(
+            TmuxCommands::new().cmd(SetOption::new().server().option("@c").value("c")),
+        ));
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let cmd = "set-option";
+    #[cfg(feature = "cmd_alias")]
+    let cmd = "set";
+    let expected = format!(
+        "{cmd} -g -w mode-keys vi\n\
+         %if \"#{{>=:#{{version}},3.2}}\"\n\
+         {cmd} -g @a \"it's\"\n\
+         {cmd} -g @b ''\n\
+         %endif\n\
+         {cmd} -s @c c\n",
+        cmd = cmd
+    );
+    assert_eq!(conf.to_string(), expected);
+}
+
+#[cfg(feature = "tmux_2_9")]
+#[test]
+fn tmux_conf_array_option() {
+    use crate::{SessionOptions, TmuxConf};
+
+    let conf = TmuxConf::new().session_options(&SessionOptions {
+        status_format: Some(vec!["#[align=left]#S".into(), "#{pane_title}".into()]),
+        update_environment: Some(vec!["DISPLAY".into()]),
+        ..Default::default()
+    });
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let cmd = "set-option";
+    #[cfg(feature = "cmd_alias")]
+    let cmd = "set";
+    let expected = format!(
+        "{cmd} -g status-format[0] '#[align=left]#S'\n\
+         {cmd} -g status-format[1] '#{{pane_title}}'\n\
+         {cmd} -g update-environment[0] DISPLAY\n",
+        cmd = cmd
+    );
+    assert_eq!(conf.to_string(), expected);
+}
+
+#[test]
+fn tmux_conf_new_line() {
+    use crate::{SessionOptions, TmuxConf};
+
+    let conf = TmuxConf::new().session_options(&SessionOptions {
+        status_left: Some("a\nb".into()),
+        status_right: Some("it's\nc".into()),
+        ..Default::default()
+    });
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let cmd = "set-option";
+    #[cfg(feature = "cmd_alias")]
+    let cmd = "set";
+    let expected = format!(
+        "{cmd} -g status-left 'a\nb'\n\
+         {cmd} -g status-right \"it's\\nc\"\n",
+        cmd = cmd
+    );
+    assert_eq!(conf.to_string(), expected);
+}
@@ -215,18 +215,26 @@ impl<B: BufRead> ControlModeOutput<B> {
         writeln!(stdin, "{}", cmd.into())?;
 
         // receive response
-        match lines.next() {
-            Some(line) => match line {
-                Response::OutputBlock(data) => Ok(data),
-                _ => Err(Error::Tmux(String::from("error response"))),
-            },
+        match ControlModeOutput::read_response(&mut lines.0)? {
+            Some(Response::OutputBlock(data)) => Ok(data),
+            Some(_) => Err(Error::Tmux(String::from("error response"))),
             None => Err(Error::Tmux(String::from("none"))),
         }
     }
 
-    // TODO: remove unwrap
     // TODO: rename
+    /// Read next response, returns `None` if output ended or the line can not be parsed
     pub fn check_main(lines: &mut Lines<B>) -> Option<Response> {
+        ControlModeOutput::read_response(lines).ok().flatten()
+    }
+
+    /// Read next response, merging output block parts (`%begin ... data ... %end/%error`)
+    ///
+    /// # Errors
+    ///
+    /// I/O errors of the underlying reader and line parse errors are returned instead of
+    /// ending the output
+    pub fn read_response(lines: &mut Lines<B>) -> Result<Option<Response>, Error> {
         let mut _time: usize = 0;
         let mut _num: usize = 0;
         let mut _flags: usize = 0;
@@ -235,46 +243,42 @@ impl<B: BufRead> ControlModeOutput<B> {
         // checking in loop, because 3 parts block may be returned, which must be merged
         // (`%begin ...  data .. %end/%error`)
         for line in lines {
-            let output = line.unwrap().control_mode_line();
-            if let Ok(output) = output {
-                // check if output is part of output block?
-                match output {
-                    // if output block detected combine it from parts (`%begin ... data ... %end/%error`)
-                    // continue loop waiting for data and end/error
-                    Response::OutputBlockBegin { time, num, flags } => {
-                        _time = time;
-                        _num = num;
-                        _flags = flags;
-                    }
-                    // end of output block (ended with success), break loop, got whole block
-                    Response::OutputBlockEnd { time, num, flags } => {
-                        // XXX: check t, n
-                        output_block.time = time;
-                        output_block.num = num;
-                        output_block.flags = flags;
-                        output_block.success = true;
-                        return Some(Response::OutputBlock(output_block));
-                    }
-                    // end of output block (ended with an error), break loop, got whole block
-                    Response::OutputBlockError { time, num, flags } => {
-                        // XXX: check t, n
-                        output_block.time = time;
-                        output_block.num = num;
-                        output_block.flags = flags;
-                        output_block.success = false;
-                        return Some(Response::OutputBlock(output_block));
-                    }
-                    // data inside of output block
-                    Response::OutputBlockData(data) => output_block.data = Some(data),
-                    // TODO: only as single line? output check, \n ?
-                    // notification, break loop, got whole data
-                    other => return Some(other),
+            let output = line?.control_mode_line()?;
+            // check if output is part of output block?
+            match output {
+                // if output block detected combine it from parts (`%begin ... data ... %end/%error`)
+                // continue loop waiting for data and end/error
+                Response::OutputBlockBegin { time, num, flags } => {
+                    _time = time;
+                    _num = num;
+                    _flags = flags;
                 }
-            } else {
-                return None;
+                // end of output block (ended with success), break loop, got whole block
+                Response::OutputBlockEnd { time, num, flags } => {
+                    // XXX: check t, n
+                    output_block.time = time;
+                    output_block.num = num;
+                    output_block.flags = flags;
+                    output_block.success = true;
+                    return Ok(Some(Response::OutputBlock(output_block)));
+                }
+                // end of output block (ended with an error), break loop, got whole block
+                Response::OutputBlockError { time, num, flags } => {
+                    // XXX: check t, n
+                    output_block.time = time;
+                    output_block.num = num;
+                    output_block.flags = flags;
+                    output_block.success = false;
+                    return Ok(Some(Response::OutputBlock(output_block)));
+                }
+                // data inside of output block
+                Response::OutputBlockData(data) => output_block.data = Some(data),
+                // TODO: only as single line? output check, \n ?
+                // notification, break loop, got whole data
+                other => return Ok(Some(other)),
             }
         }
-        None
+        Ok(None)
     }
 }
 
@@ -319,3 +319,31 @@ fn for_loop() {
 //// send commands if needed using like socket
 //// listen like socket for notificeations
 //}
+
+// malformed and truncated lines return errors instead of panicking
+#[test]
+fn read_response_error() {
+    use crate::control_mode::control_mode::{ControlModeLine, ControlModeOutput};
+    use crate::Error;
+    use std::io::{BufRead, BufReader};
+
+    assert!("%begin".control_mode_line().is_err());
+    assert!("%begin 1618081916 x 0".control_mode_line().is_err());
+    assert!("%session-changed".control_mode_line().is_err());
+    #[cfg(feature = "tmux_3_2")]
+    assert!("%extended-output %1".control_mode_line().is_err());
+
+    // invalid UTF-8 data line
+    let s: &[u8] = b"%begin 1618081916 17688 0\n\xff\xfe\n%end 1618081916 17688 0\n";
+    let mut lines = BufReader::new(s).lines();
+    let err = ControlModeOutput::read_response(&mut lines).unwrap_err();
+    assert!(matches!(err, Error::IO(_)));
+
+    let mut lines = BufReader::new(s).lines();
+    assert!(ControlModeOutput::check_main(&mut lines).is_none());
+
+    let mut lines = BufReader::new("".as_bytes()).lines();
+    assert!(ControlModeOutput::read_response(&mut lines)
+        .unwrap()
+        .is_none());
+}
@@ -25,9 +25,43 @@ pub enum Error {
     ParseTerminalFeatures,
     ParseModeMouse,
     ParseDetachOnDestroy,
+    ParseLayout,
+    ParseColour,
+    ParseRange,
+    ParseStyle,
+    ParseSnapshot,
+    ParseTarget,
+    ParseArrayOption,
+    ParseUserOption,
+
+    /// Option is not supported by the tmux version or has invalid value
+    InvalidOption(String),
+
+    /// Layout can not be built (pane not found or not enough space)
+    Layout,
+
+    /// Workspace definition can not be loaded or applied (unsupported format, unknown ids)
+    Workspace,
+    /// Snapshot can not be recorded or restored (values missing in the tmux output)
+    Snapshot,
+    /// Workspace TOML definition can not be parsed
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+    /// Workspace YAML definition can not be parsed
+    #[cfg(feature = "yaml")]
+    Yaml(serde_yaml::Error),
+    /// User option JSON value can not be encoded or decoded
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// Expected pattern is not a valid regular expression
+    #[cfg(feature = "regex")]
+    Regex(regex::Error),
 
     /// Tmux error message
     Tmux(String),
+    /// Interactive prompt was not answered, command was not completed or condition was not met in
+    /// time
+    Timeout,
     /// IO error
     IO(std::io::Error),
 
@@ -49,6 +83,14 @@ impl std::error::Error for Error {
             Self::IO(ref err) => Some(err),
             Self::ParseInt(ref err) => Some(err),
             Self::Parse(ref err) => Some(err),
+            #[cfg(feature = "toml")]
+            Self::Toml(ref err) => Some(err),
+            #[cfg(feature = "yaml")]
+            Self::Yaml(ref err) => Some(err),
+            #[cfg(feature = "json")]
+            Self::Json(ref err) => Some(err),
+            #[cfg(feature = "regex")]
+            Self::Regex(ref err) => Some(err),
             _ => None,
         }
     }
@@ -58,9 +100,19 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Tmux(ref msg) => write!(f, "{}", msg),
+            Self::InvalidOption(ref msg) => write!(f, "{}", msg),
+            Self::Timeout => write!(f, "timed out"),
             Self::IO(ref err) => err.fmt(f),
             Self::ParseInt(ref err) => err.fmt(f),
             Self::Parse(ref err) => err.fmt(f),
+            #[cfg(feature = "toml")]
+            Self::Toml(ref err) => err.fmt(f),
+            #[cfg(feature = "yaml")]
+            Self::Yaml(ref err) => err.fmt(f),
+            #[cfg(feature = "json")]
+            Self::Json(ref err) => err.fmt(f),
+            #[cfg(feature = "regex")]
+            Self::Regex(ref err) => err.fmt(f),
             _ => "".fmt(f),
         }
     }
@@ -83,3 +135,31 @@ impl From<std::string::ParseError> for Error {
         Self::Parse(err)
     }
 }
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for Error {
+    fn from(err: toml::de::Error) -> Self {
+        Self::Toml(err)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl From<serde_yaml::Error> for Error {
+    fn from(err: serde_yaml::Error) -> Self {
+        Self::Yaml(err)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<regex::Error> for Error {
+    fn from(err: regex::Error) -> Self {
+        Self::Regex(err)
+    }
+}
@@ -0,0 +1,10 @@
+//! Expect-style automation of programs running in panes
+pub mod pane_expect;
+
+pub use pane_expect::{ExpectMatch, PaneExpect};
+
+#[cfg(test)]
+#[path = "."]
+mod expect_tests {
+    pub mod pane_expect_tests;
+}
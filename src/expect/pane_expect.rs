@@ -0,0 +1,322 @@
+use crate::{
+    CapturePane, DisplayMessage, Error, SendKeys, Tmux, TmuxCommand, TmuxCommands, TmuxOutput,
+};
+use regex::Regex;
+use std::borrow::Cow;
+use std::slice;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// pane capture polling interval
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const PANE_SIZE: &str = "#{history_size} #{pane_height}";
+const ENTER_KEY: &str = "Enter";
+const LINE_SEPARATOR: char = '\n';
+const SIZE_SEPARATOR: char = ' ';
+
+/// Text matched in the pane output
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ExpectMatch {
+    /// Output between the previous match (or start) and this match
+    pub before: String,
+    /// Matched text (`0`) and capture groups of the regular expression, `None` if the group
+    /// did not participate in the match
+    pub captures: Vec<Option<String>>,
+}
+
+impl ExpectMatch {
+    /// Matched text
+    pub fn as_str(&self) -> &str {
+        self.get(0).unwrap_or_default()
+    }
+
+    /// Capture group (`0` is the matched text)
+    pub fn get(&self, i: usize) -> Option<&str> {
+        self.captures.get(i).and_then(|capture| capture.as_deref())
+    }
+}
+
+/// Expect-style automation of programs running in the pane (ssh prompts, REPLs, installers)
+///
+/// The pane content is polled (`capture-pane -p -J`) from the first row not consumed by the
+/// previous match, history rows included (`#{history_size}`), so output scrolled off the screen
+/// between polls is matched too, wrapped lines are joined. The first poll starts at the top of
+/// the visible screen.
+///
+/// Rows are counted from the oldest history row, output may be skipped once the history is
+/// full (`history-limit`) or cleared (`clear-history`). Programs redrawing the screen (full
+/// screen applications) should be matched after [`skip()`](PaneExpect::skip).
+///
+/// # Examples
+///
+/// ```no_run
+/// use regex::Regex;
+/// use std::time::Duration;
+/// use tmux_interface::PaneExpect;
+///
+/// let timeout = Duration::from_secs(10);
+/// let mut pane = PaneExpect::new("%1");
+/// pane.send_line("ssh example.com").unwrap();
+/// let prompts = [Regex::new("(?m)password: $").unwrap(), Regex::new(r"(?m)\$ $").unwrap()];
+/// let (i, _) = pane.expect_any(&prompts, timeout).unwrap();
+/// if i == 0 {
+///     pane.send_line("secret").unwrap();
+///     pane.expect(&prompts[1], timeout).unwrap();
+/// }
+/// pane.send_line("uname -r").unwrap();
+/// let kernel = Regex::new(r"(\d+)\.(\d+)\.\d+").unwrap();
+/// let found = pane.expect(&kernel, timeout).unwrap();
+/// let major = found.get(1).unwrap();
+/// ```
+pub struct PaneExpect<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    pub target_pane: Cow<'a, str>,
+    // first not consumed row (history rows included), `None` before the first poll
+    row: Option<usize>,
+    // consumed bytes of the first not consumed (joined) line
+    offset: usize,
+    // history size of the previous poll
+    history_size: usize,
+}
+
+// pane content from the first not consumed row
+struct Capture {
+    // joined lines
+    lines: Vec<String>,
+    // number of rows of each joined line
+    rows: Vec<usize>,
+}
+
+impl<'a> PaneExpect<'a> {
+    pub fn new<S: Into<Cow<'a, str>>>(target_pane: S) -> Self {
+        Self::with_invoker(target_pane, &|cmd| Tmux::with_command(cmd).output())
+    }
+
+    pub fn with_invoker<S: Into<Cow<'a, str>>>(
+        target_pane: S,
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Self {
+        Self {
+            invoker,
+            target_pane: target_pane.into(),
+            row: None,
+            offset: 0,
+            history_size: 0,
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Send the text to the pane (`send-keys -l`)
+    pub fn send<S: Into<Cow<'a, str>>>(&self, text: S) -> Result<(), Error> {
+        let send_keys = SendKeys::new()
+            .disable_lookup()
+            .target_pane(self.target_pane.clone())
+            .key(text);
+        (self.invoker)(send_keys.build())?.into_result()?;
+        Ok(())
+    }
+
+    /// Send the text followed by `Enter` to the pane
+    pub fn send_line<S: Into<Cow<'a, str>>>(&self, line: S) -> Result<(), Error> {
+        let cmds = TmuxCommands::new()
+            .cmd(
+                SendKeys::new()
+                    .disable_lookup()
+                    .target_pane(self.target_pane.clone())
+                    .key(line),
+            )
+            .cmd(
+                SendKeys::new()
+                    .target_pane(self.target_pane.clone())
+                    .key(ENTER_KEY),
+            );
+        (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        Ok(())
+    }
+
+    /// Wait for the regular expression in the output, [`Error::Timeout`] if not matched in time
+    pub fn expect(&mut self, regex: &Regex, timeout: Duration) -> Result<ExpectMatch, Error> {
+        self.expect_any(slice::from_ref(regex), timeout)
+            .map(|(_, found)| found)
+    }
+
+    /// Wait for the text in the output, [`Error::Timeout`] if not matched in time
+    pub fn expect_text(&mut self, text: &str, timeout: Duration) -> Result<ExpectMatch, Error> {
+        let regex = Regex::new(&regex::escape(text))?;
+        self.expect(&regex, timeout)
+    }
+
+    /// Wait for any of the regular expressions in the output, index of the first matched in the
+    /// output is returned (the lowest index if matched at the same position)
+    pub fn expect_any(
+        &mut self,
+        regexes: &[Regex],
+        timeout: Duration,
+    ) -> Result<(usize, ExpectMatch), Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let capture = self.capture()?;
+            if let Some(found) = self.find(&capture, regexes) {
+                return Ok(found);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::Timeout);
+            }
+            thread::sleep(POLL_INTERVAL.min(deadline - now));
+        }
+    }
+
+    /// Consume the current pane content, only the following output is expected
+    pub fn skip(&mut self) -> Result<(), Error> {
+        let capture = self.capture()?;
+        let end = text(&capture.lines, self.offset).trim_end().len();
+        self.consume(&capture, end);
+        Ok(())
+    }
+
+    // pane content from the first not consumed row, captured again if the history size changed
+    // since the previous poll (rows scrolled between the polls)
+    fn capture(&mut self) -> Result<Capture, Error> {
+        loop {
+            let start = match self.row {
+                Some(row) => row as isize - self.history_size as isize,
+                None => 0,
+            };
+            let cmds = TmuxCommands::new()
+                .cmd(
+                    DisplayMessage::new()
+                        .print()
+                        .target_pane(self.target_pane.clone())
+                        .message(PANE_SIZE),
+                )
+                .cmd(
+                    CapturePane::new()
+                        .stdout()
+                        .start_line(start.to_string())
+                        .target_pane(self.target_pane.clone()),
+                )
+                .cmd(
+                    CapturePane::new()
+                        .stdout()
+                        .join()
+                        .start_line(start.to_string())
+                        .target_pane(self.target_pane.clone()),
+                );
+            let output = (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+            let output = output.to_string();
+            let mut lines = output.lines();
+
+            let mut size = lines.next().unwrap_or_default().split(SIZE_SEPARATOR);
+            let history_size: usize = size.next().unwrap_or_default().parse()?;
+            let height: isize = size.next().unwrap_or_default().parse()?;
+            if self.row.is_some() && history_size != self.history_size {
+                self.history_size = history_size;
+                continue;
+            }
+            self.history_size = history_size;
+
+            // start row limited by tmux to the pane content (cleared history)
+            let first = start.max(-(history_size as isize)).min(height - 1);
+            if first != start || self.row.is_none() {
+                self.row = Some((history_size as isize + first) as usize);
+                self.offset = 0;
+            }
+            let rows: Vec<&str> = lines.by_ref().take((height - first) as usize).collect();
+            let lines: Vec<String> = lines.map(|line| line.to_string()).collect();
+            let rows = rows_count(&rows, &lines);
+            return Ok(Capture { lines, rows });
+        }
+    }
+
+    // first match in the not consumed output, consumed up to the match end
+    fn find(&mut self, capture: &Capture, regexes: &[Regex]) -> Option<(usize, ExpectMatch)> {
+        let text = text(&capture.lines, self.offset);
+        let (i, captures) = regexes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, regex)| regex.captures(&text).map(|captures| (i, captures)))
+            .min_by_key(|(i, captures)| (captures.get(0).map(|m| m.start()), *i))?;
+        let matched = captures.get(0)?;
+        let found = ExpectMatch {
+            before: text[..matched.start()].to_string(),
+            captures: captures
+                .iter()
+                .map(|capture| capture.map(|capture| capture.as_str().to_string()))
+                .collect(),
+        };
+        let end = matched.end();
+        self.consume(capture, end);
+        Some((i, found))
+    }
+
+    // consume the output up to the byte of the not consumed text
+    fn consume(&mut self, capture: &Capture, end: usize) {
+        let offset = consumed(&capture.lines, self.offset);
+        let mut start = 0;
+        for (i, line) in capture.lines.iter().enumerate() {
+            let skipped = if i == 0 { offset } else { 0 };
+            let len = line.len() - skipped;
+            if end <= start + len {
+                let row = self.row.unwrap_or_default();
+                self.row = Some(row + capture.rows[..i].iter().sum::<usize>());
+                self.offset = skipped + end - start;
+                return;
+            }
+            start += len + 1;
+        }
+    }
+}
+
+// consumed bytes of the first line, whole line if changed since consumed
+fn consumed(lines: &[String], offset: usize) -> usize {
+    match lines.first() {
+        Some(line) if line.is_char_boundary(offset) => offset,
+        Some(line) => line.len(),
+        None => 0,
+    }
+}
+
+// not consumed text, lines separated by `\n`
+fn text(lines: &[String], offset: usize) -> String {
+    let mut text = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        match i {
+            0 => text.push_str(&line[consumed(lines, offset)..]),
+            _ => {
+                text.push(LINE_SEPARATOR);
+                text.push_str(line);
+            }
+        }
+    }
+    text
+}
+
+// rows of each joined line, wrapped rows are matched by the count of non blank characters
+fn rows_count(rows: &[&str], lines: &[String]) -> Vec<usize> {
+    let mut rows = rows.iter();
+    lines
+        .iter()
+        .map(|line| {
+            let len = non_blank(line);
+            let (mut count, mut taken) = (0, 0);
+            while count == 0 || taken < len {
+                match rows.next() {
+                    Some(row) => {
+                        taken += non_blank(row);
+                        count += 1;
+                    }
+                    None => break,
+                }
+            }
+            count
+        })
+        .collect()
+}
+
+fn non_blank(s: &str) -> usize {
+    s.chars().filter(|c| !c.is_whitespace()).count()
+}
@@ -0,0 +1,70 @@
+#[cfg(unix)]
+#[test]
+fn pane_expect() {
+    use crate::{mock_output, Error, ExpectMatch, MockInvoker, PaneExpect};
+    use regex::Regex;
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    // history size and pane height, rows and joined lines
+    let outputs = RefCell::new(vec![
+        "0 3\nab\ncd\n\nabcd\n\n",
+        // rows scrolled since the previous poll, captured again
+        "2 3\n\n",
+        "2 3\nab\ncd\nef\ngh\n\nabcd\nefgh\n\n",
+        "2 3\nef\ngh\n\nefgh\n\n",
+    ]);
+    let mock = MockInvoker::new();
+    let invoker = mock.invoker(|cmd| {
+        let mut outputs = outputs.borrow_mut();
+        match (cmd.starts_with("send"), outputs.len()) {
+            (true, _) => mock_output(0, ""),
+            (false, 1) => mock_output(0, outputs[0]),
+            (false, _) => mock_output(0, outputs.remove(0)),
+        }
+    });
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let (display, capture, send) = ("display-message", "capture-pane", "send-keys");
+    #[cfg(feature = "cmd_alias")]
+    let (display, capture, send) = ("display", "capturep", "send");
+
+    let timeout = Duration::from_secs(1);
+    let mut pane = PaneExpect::with_invoker("%1", &invoker);
+    pane.send_line("make").unwrap();
+    let found = pane.expect_text("c", timeout).unwrap();
+    assert_eq!(found.before, "ab");
+    assert_eq!(found.as_str(), "c");
+    assert_eq!(
+        mock.cmds.borrow()[1],
+        format!(
+            "{} -p -t %1 #{{history_size}} #{{pane_height}} ; {} -p -S 0 -t %1 ; {} -p -J -S 0 -t %1",
+            display, capture, capture
+        )
+    );
+
+    // first not consumed row is in the history
+    let regexes = [Regex::new("x").unwrap(), Regex::new(r"(e)(x)?f").unwrap()];
+    let (i, found) = pane.expect_any(&regexes, timeout).unwrap();
+    assert_eq!(i, 1);
+    assert_eq!(
+        found,
+        ExpectMatch {
+            before: "d\n".to_string(),
+            captures: vec![Some("ef".to_string()), Some("e".to_string()), None],
+        }
+    );
+    assert!(mock.cmds.borrow()[2].contains(" -S 0 "));
+    assert!(mock.cmds.borrow()[3].contains(" -S -2 "));
+
+    // consumed up to the second row of the history
+    assert!(matches!(
+        pane.expect_text("e", Duration::from_millis(10)),
+        Err(Error::Timeout)
+    ));
+    assert!(mock.cmds.borrow()[4].contains(" -S 0 "));
+    assert_eq!(
+        mock.cmds.borrow()[0],
+        format!("{} -l -t %1 make ; {} -t %1 Enter", send, send)
+    );
+}
@@ -38,7 +38,8 @@ impl<'a> FormatsOutput<'a> {
     // TODO: check vec same size, return type?
     // XXX: mb from_string for default format too?
     pub fn from_string_ext(s: &str, format: &'a mut FormatsOutput<'a>) {
-        let v: Vec<&str> = s.split(format.separator).collect();
+        // the last variable gets the rest of the string (may contain separator)
+        let v: Vec<&str> = s.splitn(format.variables.len(), format.separator).collect();
         for (i, variable) in v.iter().enumerate() {
             VariableOutput::from_string_ext(variable, &mut format.variables[i]);
         }
@@ -758,14 +759,14 @@ impl<'a> FormatsOutput<'a> {
 
     /// `pane_start_command` - Command pane started with
     #[cfg(feature = "tmux_1_6")]
-    pub fn pane_start_command(&mut self, v: &'a mut Option<usize>) -> &mut Self {
+    pub fn pane_start_command(&mut self, v: &'a mut Option<String>) -> &mut Self {
         self.push(VariableOutput::PaneStartCommand(v));
         self
     }
 
     /// `pane_start_path` - Path pane started with
     #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_0")))]
-    pub fn pane_start_path(&mut self, v: &'a mut Option<usize>) -> &mut Self {
+    pub fn pane_start_path(&mut self, v: &'a mut Option<String>) -> &mut Self {
         self.push(VariableOutput::PaneStartPath(v));
         self
     }
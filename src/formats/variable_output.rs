@@ -327,10 +327,10 @@ pub enum VariableOutput<'a> {
     PaneSearchString(&'a mut Option<usize>),
     /// `pane_start_command` - Command pane started with
     #[cfg(feature = "tmux_1_6")]
-    PaneStartCommand(&'a mut Option<usize>),
+    PaneStartCommand(&'a mut Option<String>),
     /// `pane_start_path` - Path pane started with
     #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_0")))]
-    PaneStartPath(&'a mut Option<usize>),
+    PaneStartPath(&'a mut Option<String>),
     /// `pane_synchronized` - 1 if pane is synchronized
     #[cfg(feature = "tmux_1_9")]
     PaneSynchronized(&'a mut Option<bool>),
@@ -947,10 +947,10 @@ impl<'a> VariableOutput<'a> {
             Self::PaneSearchString(v) => **v = s.parse::<usize>().ok(),
             // pane_start_command - Command pane started with
             #[cfg(feature = "tmux_1_6")]
-            Self::PaneStartCommand(v) => **v = s.parse::<usize>().ok(),
+            Self::PaneStartCommand(v) => **v = Self::parse_option_string(s),
             // pane_start_path - Path pane started with
             #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_0")))]
-            Self::PaneStartPath(v) => **v = Self::parse_option_usize(s),
+            Self::PaneStartPath(v) => **v = Self::parse_option_string(s),
             // pane_synchronized - 1 if pane is synchronized
             #[cfg(feature = "tmux_1_9")]
             Self::PaneSynchronized(v) => **v = Self::parse_option_bool(s),
@@ -0,0 +1,208 @@
+use crate::sync::channel::channel_name;
+use crate::{
+    conf_command, DisplayMenu, Error, MenuItem, SetOption, ShowOptions, Tmux, TmuxCommand,
+    TmuxCommands, TmuxOutput, WaitFor,
+};
+#[cfg(feature = "tmux_3_2")]
+use crate::{IfShell, RunShell};
+use std::borrow::Cow;
+
+const CHANNEL_PREFIX: &str = "tmux_interface_menu";
+pub(crate) const USER_OPTION_MARKER: char = '@';
+/// id prefix of items with callbacks
+const CALLBACK_PREFIX: &str = "callback-";
+/// server user option suffix, set while [`MenuChannel::display`] waits for the channel
+#[cfg(feature = "tmux_3_2")]
+const WAITING_SUFFIX: &str = "_waiting";
+/// seconds to wait for the chosen item command after the menu was closed
+#[cfg(feature = "tmux_3_2")]
+const DISPLAY_TIMEOUT: usize = 1;
+
+/// Menu items reporting the user choice to the Rust program
+///
+/// The item command stores the item id in the server user option (`set-option -s @channel id`)
+/// and signals the `wait-for` channel of the same name. The choice is taken after the menu
+/// was closed ([`display()`](MenuChannel::display)) or by waiting for the channel
+/// ([`recv()`](MenuChannel::recv), e.g. menus bound to keys), callbacks of the chosen items
+/// are called
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{Menu, MenuChannel, MenuItem};
+///
+/// let mut renamed = false;
+/// let mut channel = MenuChannel::new();
+/// let rename = channel.callback_item("Rename", "r", || renamed = true);
+/// let menu = Menu::new()
+///     .title("Session")
+///     .item(rename)
+///     .item(channel.item("Kill", "k", "kill"))
+///     .separator()
+///     .item(MenuItem::new("Detach", "d", "detach-client"));
+///
+/// let id = channel.display(menu).unwrap();
+/// ```
+pub struct MenuChannel<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// `wait-for` channel and server user option name (without `@`)
+    pub name: String,
+    /// seconds [`display()`](MenuChannel::display) waits for the chosen item command after the
+    /// menu was closed (the menu is closed without choice if the channel is not signaled)
+    #[cfg(feature = "tmux_3_2")]
+    pub timeout: usize,
+    callbacks: Vec<Box<dyn FnMut() + 'a>>,
+}
+
+impl<'a> Default for MenuChannel<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            name: channel_name(CHANNEL_PREFIX),
+            #[cfg(feature = "tmux_3_2")]
+            timeout: DISPLAY_TIMEOUT,
+            callbacks: Vec::new(),
+        }
+    }
+}
+
+impl<'a> MenuChannel<'a> {
+    /// Channel with unique name
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Item reporting the id when chosen
+    pub fn item<S: Into<Cow<'a, str>>>(&self, name: S, key: S, id: &str) -> MenuItem<'a> {
+        let option = format!("{}{}", USER_OPTION_MARKER, self.name);
+        let cmds = TmuxCommands::new()
+            .cmd(
+                SetOption::new()
+                    .server()
+                    .option(option)
+                    .value(id.to_string()),
+            )
+            .cmd(WaitFor::new().woken().channel(self.name.clone()));
+        // item commands are expanded as formats before being run
+        let command = conf_command(&TmuxCommand::with_cmds(cmds)).replace('#', "##");
+        MenuItem::new(name.into(), key.into(), Cow::Owned(command))
+    }
+
+    /// Item calling the callback when chosen (by [`display()`](MenuChannel::display) or
+    /// [`recv()`](MenuChannel::recv))
+    pub fn callback_item<S: Into<Cow<'a, str>>, F: FnMut() + 'a>(
+        &mut self,
+        name: S,
+        key: S,
+        callback: F,
+    ) -> MenuItem<'a> {
+        let id = format!("{}{}", CALLBACK_PREFIX, self.callbacks.len());
+        self.callbacks.push(Box::new(callback));
+        self.item(name, key, &id)
+    }
+
+    /// Display the menu and return the id of the chosen item, `None` if the menu was closed
+    /// without choice
+    ///
+    /// `display-menu` returns after the menu was closed, the client must be attached (see
+    /// [`DisplayMenu::target_client`]). The chosen item command is run after that, it is
+    /// waited for up to [`timeout`](MenuChannel::timeout) seconds (tmux ^3.2)
+    pub fn display(&mut self, menu: DisplayMenu<'a>) -> Result<Option<String>, Error> {
+        (self.invoker)(menu.build())?.into_result()?;
+        #[cfg(feature = "tmux_3_2")]
+        self.wait()?;
+        let id = self.take()?;
+        if let Some(id) = &id {
+            self.callback(id);
+        }
+        Ok(id)
+    }
+
+    /// Wait until an item is chosen, return its id
+    pub fn recv(&mut self) -> Result<String, Error> {
+        loop {
+            let wait_for = WaitFor::new().channel(self.name.clone());
+            (self.invoker)(wait_for.build())?.into_result()?;
+            // channel may be signaled without id (already taken by `display()`)
+            if let Some(id) = self.take()? {
+                self.callback(&id);
+                return Ok(id);
+            }
+        }
+    }
+
+    // wait for the channel, signaled by the chosen item command or by the delayed command after
+    // the timeout. The delayed command signals the channel only while waiting (waiting option
+    // is set), so no pending signal is left for the next wait
+    #[cfg(feature = "tmux_3_2")]
+    fn wait(&self) -> Result<(), Error> {
+        let waiting = format!("{}{}{}", USER_OPTION_MARKER, self.name, WAITING_SUFFIX);
+        let signal = WaitFor::new().woken().channel(self.name.clone()).build();
+        let timeout = IfShell::new()
+            .not_execute()
+            .shell_command(format!("#{{{}}}", waiting))
+            .command(conf_command(&signal))
+            .build();
+        // `run-shell` command is expanded as a format before being delayed
+        let timeout = conf_command(&timeout).replace('#', "##");
+        let cmds = TmuxCommands::new()
+            .cmd(SetOption::new().server().option(waiting.clone()).value("1"))
+            .cmd(
+                RunShell::new()
+                    .background()
+                    .tmux_command()
+                    .delay(self.timeout)
+                    .shell_command(timeout),
+            )
+            .cmd(WaitFor::new().channel(self.name.clone()))
+            .cmd(SetOption::new().server().unset().option(waiting));
+        (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        Ok(())
+    }
+
+    // chosen item id, option is unset
+    fn take(&self) -> Result<Option<String>, Error> {
+        take(self.invoker, &self.name)
+    }
+
+    fn callback(&mut self, id: &str) {
+        let index = id
+            .strip_prefix(CALLBACK_PREFIX)
+            .and_then(|index| index.parse::<usize>().ok());
+        if let Some(callback) = index.and_then(|index| self.callbacks.get_mut(index)) {
+            callback();
+        }
+    }
+}
+
+// value of the server user option, option is unset
+pub(crate) fn take<'a>(
+    invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    name: &str,
+) -> Result<Option<String>, Error> {
+    let option = format!("{}{}", USER_OPTION_MARKER, name);
+    let cmds = TmuxCommands::new()
+        .cmd(
+            ShowOptions::new()
+                .server()
+                .quiet()
+                .value()
+                .option(option.clone()),
+        )
+        .cmd(SetOption::new().server().unset().option(option));
+    let output = invoker(TmuxCommand::with_cmds(cmds))?.into_result()?;
+    let value = output.to_string();
+    let value = value.strip_suffix('\n').unwrap_or(&value);
+    Ok(Some(value.to_string()).filter(|value| !value.is_empty()))
+}
@@ -0,0 +1,65 @@
+#[cfg(unix)]
+#[test]
+fn menu_channel() {
+    use crate::{mock_output, Menu, MenuChannel, MockInvoker};
+    use std::cell::{Cell, RefCell};
+
+    let mock = MockInvoker::new();
+    let chosen = RefCell::new(String::from("callback-1\n"));
+    let invoker = mock.invoker(|cmd| match cmd.contains("show") {
+        true => mock_output(0, chosen.replace(String::new())),
+        false => mock_output(0, ""),
+    });
+    let first = Cell::new(0);
+    let second = Cell::new(0);
+    let mut channel = MenuChannel::with_invoker(&invoker);
+    channel.name = "menu".to_string();
+    let item0 = channel.callback_item("First", "f", || first.set(first.get() + 1));
+    let item1 = channel.callback_item("Second", "s", || second.set(second.get() + 1));
+    let item2 = channel.item("Third #1", "t", "third #1");
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let (set, show, wait) = ("set-option", "show-options", "wait-for");
+    #[cfg(feature = "cmd_alias")]
+    let (set, show, wait) = ("set", "show", "wait");
+    assert_eq!(
+        item2.to_vec()[2],
+        format!("{} -s @menu 'third ##1' ; {} -S menu", set, wait)
+    );
+
+    let menu = Menu::new().item(item0).item(item1).item(item2);
+    assert_eq!(
+        channel.display(menu).unwrap(),
+        Some("callback-1".to_string())
+    );
+    assert_eq!((first.get(), second.get()), (0, 1));
+    // chosen item command is waited for
+    #[cfg(feature = "tmux_3_2")]
+    {
+        #[cfg(not(feature = "cmd_alias"))]
+        let (run, if_) = ("run-shell", "if-shell");
+        #[cfg(feature = "cmd_alias")]
+        let (run, if_) = ("run", "if");
+        assert_eq!(
+            mock.cmds.borrow()[1],
+            format!(
+                "{set} -s @menu_waiting 1 ; {run} -b -C -d 1 {if_} -F '##{{@menu_waiting}}' '{wait} -S menu' ; \
+                 {wait} menu ; {set} -s -u @menu_waiting",
+                set = set,
+                run = run,
+                if_ = if_,
+                wait = wait
+            )
+        );
+    }
+    let n = mock.cmds.borrow().len();
+    assert_eq!(
+        mock.cmds.borrow()[n - 1],
+        format!("{} -q -s -v @menu ; {} -s -u @menu", show, set)
+    );
+
+    *chosen.borrow_mut() = "third #1\n".to_string();
+    assert_eq!(channel.recv().unwrap(), "third #1");
+    assert_eq!(mock.cmds.borrow()[n], format!("{} menu", wait));
+    assert_eq!((first.get(), second.get()), (0, 1));
+}
@@ -0,0 +1,19 @@
+//! Interactive menus, prompts and popups returning the user choice to the Rust program
+pub mod menu_channel;
+#[cfg(all(unix, feature = "tmux_3_2"))]
+pub mod popup_runner;
+pub mod prompt_channel;
+
+pub use menu_channel::MenuChannel;
+#[cfg(all(unix, feature = "tmux_3_2"))]
+pub use popup_runner::{PopupOutcome, PopupRunner};
+pub use prompt_channel::PromptChannel;
+
+#[cfg(test)]
+#[path = "."]
+mod interactive_tests {
+    pub mod menu_channel_tests;
+    #[cfg(all(unix, feature = "tmux_3_2"))]
+    pub mod popup_runner_tests;
+    pub mod prompt_channel_tests;
+}
@@ -0,0 +1,202 @@
+use crate::interactive::menu_channel::{take, USER_OPTION_MARKER};
+use crate::sync::channel::channel_name;
+use crate::{DisplayPopup, Error, SetOption, Tmux, TmuxCommand, TmuxOutput, WaitFor};
+use std::borrow::Cow;
+use std::env;
+use std::fs::{self, DirBuilder, OpenOptions};
+use std::io::Write;
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+use std::path::{Path, PathBuf};
+
+const CHANNEL_PREFIX: &str = "tmux_interface_popup";
+const SCRIPT_EXTENSION: &str = "sh";
+const OUTPUT_EXTENSION: &str = "out";
+const SHELL: &str = "sh";
+// temporary directory is accessible by the owner only, files are not replaced by other users
+const DIR_MODE: u32 = 0o700;
+const FILE_MODE: u32 = 0o600;
+const SINGLE_QUOTE: char = '\'';
+
+/// Exit status and captured output of the command run in the popup
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct PopupOutcome {
+    /// Exit code of the shell command, `None` if the popup was closed before the command exited
+    pub code: Option<i32>,
+    /// Standard output of the shell command, if captured
+    /// ([`capture_output()`](PopupRunner::capture_output))
+    pub output: Option<String>,
+}
+
+impl PopupOutcome {
+    pub fn success(&self) -> bool {
+        self.code == Some(0)
+    }
+}
+
+/// Run shell commands in popups, return their exit status and output to the Rust program
+///
+/// The shell command is run by the wrapper script (temporary file in the newly created private
+/// directory, run by `sh`), the script stores the exit status in the server user option (`tmux set-option -s @channel "$?"`) and
+/// signals the `wait-for` channel of the same name. `display-popup` returns after the popup was
+/// closed, the status is taken then.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{DisplayPopup, PopupRunner, Size};
+///
+/// let outcome = PopupRunner::new()
+///     .run_in_popup("/dev/pts/1", "make test")
+///     .unwrap();
+/// assert!(outcome.success());
+///
+/// let popup = DisplayPopup::new()
+///     .target_client("/dev/pts/1")
+///     .close_on_exit()
+///     .width(Size::Percentage(80))
+///     .height(20)
+///     .environment("FZF_DEFAULT_OPTS", "--reverse");
+/// let outcome = PopupRunner::new()
+///     .capture_output()
+///     .run(popup, "ls | fzf")
+///     .unwrap();
+/// let chosen = outcome.output.unwrap();
+/// ```
+pub struct PopupRunner<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// `wait-for` channel and server user option name (without `@`), temporary directory and
+    /// files name
+    pub name: String,
+    /// Standard output of the shell command is redirected to the temporary file and returned
+    pub capture_output: bool,
+}
+
+impl<'a> Default for PopupRunner<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            name: channel_name(CHANNEL_PREFIX),
+            capture_output: false,
+        }
+    }
+}
+
+impl<'a> PopupRunner<'a> {
+    /// Runner with unique channel name
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Capture the standard output of the shell command, it is not displayed in the popup
+    pub fn capture_output(mut self) -> Self {
+        self.capture_output = true;
+        self
+    }
+
+    /// Run the shell command in the popup on the client, the popup is closed when the command
+    /// exits
+    pub fn run_in_popup<S: Into<Cow<'a, str>>>(
+        &self,
+        target_client: S,
+        shell_command: &str,
+    ) -> Result<PopupOutcome, Error> {
+        let popup = DisplayPopup::new()
+            .target_client(target_client)
+            .close_on_exit();
+        self.run(popup, shell_command)
+    }
+
+    /// Run the shell command in the popup (size, position, environment...), the shell command
+    /// of the popup is replaced by the wrapper script
+    pub fn run(&self, popup: DisplayPopup<'a>, shell_command: &str) -> Result<PopupOutcome, Error> {
+        // fails if the directory exists, the files in it are not created by the other users
+        let dir = self.dir();
+        DirBuilder::new().mode(DIR_MODE).create(&dir)?;
+        let result = self.run_in_dir(popup, shell_command, &dir);
+        fs::remove_dir_all(&dir)?;
+        result
+    }
+
+    fn run_in_dir(
+        &self,
+        popup: DisplayPopup<'a>,
+        shell_command: &str,
+        dir: &Path,
+    ) -> Result<PopupOutcome, Error> {
+        let script = self.path(dir, SCRIPT_EXTENSION);
+        let output = self.path(dir, OUTPUT_EXTENSION);
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(FILE_MODE)
+            .open(&script)?
+            .write_all(self.script(shell_command, &output).as_bytes())?;
+
+        let popup = popup.shell_command(format!("{} {}", SHELL, shell_quote(&script)));
+        self.outcome(popup, &output)
+    }
+
+    fn outcome(&self, popup: DisplayPopup<'a>, output: &Path) -> Result<PopupOutcome, Error> {
+        let status = (self.invoker)(popup.build())?;
+        // popup exits with the shell command status, error only if message is printed
+        if !status.0.stderr.is_empty() {
+            status.into_result()?;
+        }
+
+        let code = match take(self.invoker, &self.name)? {
+            Some(code) => {
+                // signaled channel is waited for (returns immediately) to be removed
+                let wait_for = WaitFor::new().channel(self.name.clone());
+                (self.invoker)(wait_for.build())?.into_result()?;
+                Some(code.parse()?)
+            }
+            None => None,
+        };
+        let output = match self.capture_output && output.exists() {
+            true => Some(String::from_utf8_lossy(&fs::read(output)?).to_string()),
+            false => None,
+        };
+        Ok(PopupOutcome { code, output })
+    }
+
+    // wrapper script, shell command is run in the subshell
+    fn script(&self, shell_command: &str, output: &Path) -> String {
+        let option = format!("{}{}", USER_OPTION_MARKER, self.name);
+        let set_option = SetOption::new().server().option(option).build();
+        let wait_for = WaitFor::new().woken().channel(self.name.clone()).build();
+        let redirect = match self.capture_output {
+            true => format!(" > {}", shell_quote(output)),
+            false => String::new(),
+        };
+        format!(
+            "(\n{}\n){}\ntmux {} \"$?\"\ntmux {}\n",
+            shell_command, redirect, set_option, wait_for
+        )
+    }
+
+    fn dir(&self) -> PathBuf {
+        env::temp_dir().join(&self.name)
+    }
+
+    fn path(&self, dir: &Path, extension: &str) -> PathBuf {
+        dir.join(format!("{}.{}", self.name, extension))
+    }
+}
+
+// single quoted shell argument
+fn shell_quote(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    let escaped = path.replace(SINGLE_QUOTE, "'\\''");
+    format!("{}{}{}", SINGLE_QUOTE, escaped, SINGLE_QUOTE)
+}
@@ -0,0 +1,77 @@
+#[cfg(unix)]
+#[test]
+fn popup_runner() {
+    use crate::{mock_output, DisplayPopup, MockInvoker, PopupOutcome, PopupRunner};
+    use std::cell::RefCell;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    let name = format!("popup_runner_test_{}", std::process::id());
+    let dir = std::env::temp_dir().join(&name);
+    let script = dir.join(format!("{}.sh", name));
+    let output = dir.join(format!("{}.out", name));
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let (set, wait, popup) = ("set-option", "wait-for", "display-popup");
+    #[cfg(feature = "cmd_alias")]
+    let (set, wait, popup) = ("set", "wait", "popup");
+
+    let mock = MockInvoker::new();
+    let scripts = RefCell::new(Vec::new());
+    let invoker = mock.invoker(|cmd| {
+        if cmd.starts_with("show") {
+            return mock_output(0, "3\n");
+        }
+        if !cmd.starts_with(&format!("{} ", popup)) {
+            return mock_output(0, "");
+        }
+        // command run in the popup, popup exits with the shell command status
+        let mode = fs::metadata(&script).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        scripts
+            .borrow_mut()
+            .push(fs::read_to_string(&script).unwrap());
+        fs::write(&output, "chosen\n").unwrap();
+        mock_output(3, "")
+    });
+
+    let mut runner = PopupRunner::with_invoker(&invoker);
+    runner.name = name.clone();
+    let outcome = runner.run_in_popup("client", "exit 3").unwrap();
+    assert_eq!(
+        outcome,
+        PopupOutcome {
+            code: Some(3),
+            output: None
+        }
+    );
+    assert_eq!(
+        mock.cmds.borrow()[0],
+        format!("{} -E -c client sh '{}'", popup, script.to_string_lossy())
+    );
+    assert_eq!(mock.cmds.borrow()[2], format!("{} {}", wait, name));
+    assert_eq!(
+        scripts.borrow()[0],
+        format!(
+            "(\nexit 3\n)\ntmux {} -s @{} \"$?\"\ntmux {} -S {}\n",
+            set, name, wait, name
+        )
+    );
+    assert!(!dir.exists());
+
+    let runner = runner.capture_output();
+    let popup = DisplayPopup::new().target_client("client").close_on_exit();
+    let outcome = runner.run(popup, "ls | fzf").unwrap();
+    assert_eq!(outcome.output, Some("chosen\n".to_string()));
+    assert!(scripts.borrow()[1].starts_with(&format!(
+        "(\nls | fzf\n) > '{}'\n",
+        output.to_string_lossy()
+    )));
+    assert!(!dir.exists());
+
+    // existing directory (created by the other user) is not used
+    fs::create_dir(&dir).unwrap();
+    assert!(runner.run_in_popup("client", "exit 3").is_err());
+    assert_eq!(scripts.borrow().len(), 2);
+    fs::remove_dir(&dir).unwrap();
+}
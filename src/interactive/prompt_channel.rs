@@ -0,0 +1,245 @@
+use crate::interactive::menu_channel::{take, USER_OPTION_MARKER};
+use crate::sync::channel::channel_name;
+use crate::{
+    conf_command, CommandPrompt, ConfirmBefore, Error, SetOption, Tmux, TmuxCommand, TmuxOutput,
+    WaitFor,
+};
+#[cfg(feature = "tmux_2_6")]
+use crate::{ChooseTree, DisplayMessage};
+use std::borrow::Cow;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const CHANNEL_PREFIX: &str = "tmux_interface_prompt";
+/// template placeholder replaced by the answer, `"`, `\`, `$`, `;` and `~` are escaped
+const ANSWER_PLACEHOLDER: &str = "%%%";
+/// answer stored when confirmed
+const CONFIRMED: &str = "y";
+/// answer polling interval (timeouts, `choose-tree`)
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// Maximum time waiting for the answer to the prompt shown in the background without
+/// [`timeout`](PromptChannel::timeout) (tmux <3.3), cancelled prompts are not reported
+pub const PROMPT_TIMEOUT: Duration = Duration::from_secs(300);
+#[cfg(feature = "tmux_2_6")]
+const PANE_IN_MODE: &str = "#{pane_in_mode}";
+
+/// Prompts returning the user answer to the Rust program
+///
+/// The prompt template stores the answer in the server user option
+/// (`set-option -s @channel "%%%"`, `%%%` is replaced by the answer escaped for the command
+/// parser) and signals the `wait-for` channel of the same name.
+///
+/// tmux ^3.3 `command-prompt` and `confirm-before` return after the prompt was answered or
+/// cancelled, the answer is taken then. Otherwise (older tmux, prompt shown in the background
+/// because of the [`timeout`](PromptChannel::timeout)) the answer is polled, cancelled prompts
+/// are reported only by [`Error::Timeout`] (after [`PROMPT_TIMEOUT`] if no timeout is set).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use tmux_interface::PromptChannel;
+///
+/// let prompts = PromptChannel::new();
+/// if let Some(name) = prompts.prompt("/dev/pts/1", "Rename to:").unwrap() {
+///     println!("renamed to {}", name);
+/// }
+///
+/// let prompts = PromptChannel::new().timeout(Duration::from_secs(30));
+/// let confirmed = prompts.confirm("/dev/pts/1", "Kill session? (y/n)").unwrap();
+/// ```
+pub struct PromptChannel<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// `wait-for` channel and server user option name (without `@`)
+    pub name: String,
+    /// Maximum time waiting for the answer, `None` waits until answered or cancelled (tmux ^3.3),
+    /// at most [`PROMPT_TIMEOUT`] on older tmux
+    pub timeout: Option<Duration>,
+}
+
+impl<'a> Default for PromptChannel<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            name: channel_name(CHANNEL_PREFIX),
+            timeout: None,
+        }
+    }
+}
+
+impl<'a> PromptChannel<'a> {
+    /// Channel with unique name
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Give up waiting for the answer after the timeout ([`Error::Timeout`]), the prompt is
+    /// shown in the background and stays open
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Ask for the text in the client, `None` if the prompt was cancelled
+    pub fn prompt<S: Into<Cow<'a, str>>>(
+        &self,
+        target_client: S,
+        prompt: S,
+    ) -> Result<Option<String>, Error> {
+        let command_prompt = CommandPrompt::new()
+            .target_client(target_client)
+            .prompts(prompt);
+        self.command_prompt(command_prompt)
+    }
+
+    /// Display the command prompt (e.g. with initial input, single key), the template is
+    /// replaced, `None` if the prompt was cancelled
+    pub fn command_prompt(
+        &self,
+        command_prompt: CommandPrompt<'a>,
+    ) -> Result<Option<String>, Error> {
+        let command_prompt = command_prompt.template(self.template(ANSWER_PLACEHOLDER));
+        #[cfg(feature = "tmux_3_3")]
+        let (command_prompt, blocking) = match self.timeout {
+            Some(_) => (command_prompt.background(), false),
+            None => (command_prompt, true),
+        };
+        #[cfg(not(feature = "tmux_3_3"))]
+        let blocking = false;
+
+        (self.invoker)(command_prompt.build())?.into_result()?;
+        self.answer(blocking)
+    }
+
+    /// Ask for the confirmation in the client, `false` if refused or cancelled
+    pub fn confirm<S: Into<Cow<'a, str>>>(
+        &self,
+        target_client: S,
+        prompt: S,
+    ) -> Result<bool, Error> {
+        let confirm_before = ConfirmBefore::new()
+            .target_client(target_client)
+            .prompt(prompt);
+        self.confirm_before(confirm_before)
+    }
+
+    /// Display the confirmation prompt, the command is replaced, `false` if refused or
+    /// cancelled
+    pub fn confirm_before(&self, confirm_before: ConfirmBefore<'a>) -> Result<bool, Error> {
+        let confirm_before = confirm_before.command(self.template(CONFIRMED));
+        #[cfg(feature = "tmux_3_4")]
+        let (confirm_before, blocking) = match self.timeout {
+            Some(_) => (confirm_before.background(), false),
+            None => (confirm_before, true),
+        };
+        #[cfg(all(feature = "tmux_3_3", not(feature = "tmux_3_4")))]
+        let blocking = true;
+        #[cfg(not(feature = "tmux_3_3"))]
+        let blocking = false;
+
+        let output = (self.invoker)(confirm_before.build())?;
+        // refused confirmation exits with 1 without error message (tmux ^3.3)
+        if !output.0.stderr.is_empty() {
+            output.into_result()?;
+        }
+        Ok(self.answer(blocking)?.is_some())
+    }
+
+    /// Display the session, window and pane tree, return the chosen target (e.g. `=0:1.`),
+    /// `None` if the tree mode was left without choice
+    ///
+    /// `choose-tree` returns immediately, the pane mode is polled until the target is chosen
+    #[cfg(feature = "tmux_2_6")]
+    pub fn choose_tree(&self, choose_tree: ChooseTree<'a>) -> Result<Option<String>, Error> {
+        let target_pane = choose_tree.target_pane.clone();
+        let choose_tree = choose_tree.template(self.template(ANSWER_PLACEHOLDER));
+        (self.invoker)(choose_tree.build())?.into_result()?;
+
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            if let Some(answer) = self.received()? {
+                return Ok(Some(answer));
+            }
+            if !self.in_mode(target_pane.clone())? {
+                // target may be chosen in the meantime
+                return self.received();
+            }
+            sleep(deadline)?;
+        }
+    }
+
+    // template storing the value and signaling the channel, the value is double quoted
+    fn template(&self, value: &str) -> String {
+        let option = format!("{}{}", USER_OPTION_MARKER, self.name);
+        let set_option = SetOption::new().server().option(option).build();
+        let wait_for = WaitFor::new().woken().channel(self.name.clone()).build();
+        format!(
+            "{} \"{}\" ; {}",
+            conf_command(&set_option),
+            value,
+            conf_command(&wait_for)
+        )
+    }
+
+    // answer taken after the prompt was closed (blocking) or polled until the timeout, the
+    // cancelled prompt does not signal the channel
+    fn answer(&self, blocking: bool) -> Result<Option<String>, Error> {
+        if blocking {
+            return self.received();
+        }
+        let deadline = Instant::now() + self.timeout.unwrap_or(PROMPT_TIMEOUT);
+        loop {
+            if let Some(answer) = self.received()? {
+                return Ok(Some(answer));
+            }
+            sleep(Some(deadline))?;
+        }
+    }
+
+    // answer if stored, the signaled channel is waited for (returns immediately) to be removed
+    fn received(&self) -> Result<Option<String>, Error> {
+        let answer = take(self.invoker, &self.name)?;
+        if answer.is_some() {
+            let wait_for = WaitFor::new().channel(self.name.clone());
+            (self.invoker)(wait_for.build())?.into_result()?;
+        }
+        Ok(answer)
+    }
+
+    #[cfg(feature = "tmux_2_6")]
+    fn in_mode(&self, target_pane: Option<Cow<'a, str>>) -> Result<bool, Error> {
+        let mut display_message = DisplayMessage::new().print().message(PANE_IN_MODE);
+        if let Some(target_pane) = target_pane {
+            display_message = display_message.target_pane(target_pane);
+        }
+        let output = (self.invoker)(display_message.build())?.into_result()?;
+        Ok(output.to_string().trim() == "1")
+    }
+}
+
+// sleep for the poll interval, error if the deadline passed
+fn sleep(deadline: Option<Instant>) -> Result<(), Error> {
+    let interval = match deadline {
+        Some(deadline) => {
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::Timeout);
+            }
+            POLL_INTERVAL.min(deadline - now)
+        }
+        None => POLL_INTERVAL,
+    };
+    thread::sleep(interval);
+    Ok(())
+}
@@ -0,0 +1,75 @@
+#[cfg(all(unix, feature = "tmux_3_3"))]
+#[test]
+fn prompt_channel() {
+    use crate::{mock_output, Error, MockInvoker, PromptChannel};
+    use std::cell::{Cell, RefCell};
+    use std::time::Duration;
+
+    let mock = MockInvoker::new();
+    let answer = RefCell::new(String::from("a \"b\"\n"));
+    let status = Cell::new(0);
+    let invoker = mock.invoker(|cmd| {
+        if cmd.starts_with("show") {
+            mock_output(0, answer.replace(String::new()))
+        } else if cmd.starts_with("confirm") {
+            mock_output(status.get(), "")
+        } else {
+            mock_output(0, "")
+        }
+    });
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let (set, show, wait, confirm) = ("set-option", "show-options", "wait-for", "confirm-before");
+    #[cfg(feature = "cmd_alias")]
+    let (set, show, wait, confirm) = ("set", "show", "wait", "confirm");
+
+    let mut prompts = PromptChannel::with_invoker(&invoker);
+    prompts.name = "prompt".to_string();
+    assert_eq!(
+        prompts.prompt("client", "Rename to:").unwrap(),
+        Some("a \"b\"".to_string())
+    );
+    assert_eq!(
+        *mock.cmds.borrow(),
+        vec![
+            format!(
+                "command-prompt -p Rename to: -t client {} -s @prompt \"%%%\" ; {} -S prompt",
+                set, wait
+            ),
+            format!("{} -q -s -v @prompt ; {} -s -u @prompt", show, set),
+            format!("{} prompt", wait),
+        ]
+    );
+
+    // cancelled
+    mock.clear();
+    assert_eq!(prompts.prompt("client", "Rename to:").unwrap(), None);
+    assert_eq!(mock.cmds.borrow().len(), 2);
+
+    // refused, exits with 1
+    mock.clear();
+    status.set(1);
+    assert!(!prompts.confirm("client", "Kill?").unwrap());
+    assert_eq!(
+        mock.cmds.borrow()[0],
+        format!(
+            "{} -p Kill? -t client {} -s @prompt \"y\" ; {} -S prompt",
+            confirm, set, wait
+        )
+    );
+    status.set(0);
+    *answer.borrow_mut() = "y\n".to_string();
+    assert!(prompts.confirm("client", "Kill?").unwrap());
+
+    // shown in the background, not answered in time
+    mock.clear();
+    let prompts = prompts.timeout(Duration::from_millis(10));
+    assert!(matches!(
+        prompts.prompt("client", "Rename to:"),
+        Err(Error::Timeout)
+    ));
+    assert!(mock.cmds.borrow()[0].starts_with("command-prompt -b -p"));
+    assert!(mock.cmds.borrow()[1..]
+        .iter()
+        .all(|cmd| cmd.starts_with(show)));
+}
@@ -10,10 +10,11 @@
 //! * 3. [Package Compilation Features](#3-package-compilation-features)
 //!     * 3.1. [Tmux Version](#31-tmux-version)
 //!     * 3.2. [Tmux Command Alias](#32-tmux-command-alias)
-//!     * 3.3. [Repository](#3-3-repository)
-//!         * 3.3.1 [Using Crates Repository](#331-using-crates-repository)
-//!         * 3.3.2 [Using Local Repository](#332-using-local-repository)
-//!         * 3.3.3 [Using Remote Repository](#333-using-remote-repository)
+//!     * 3.3. [Optional Features](#33-optional-features)
+//!     * 3.4. [Repository](#3-4-repository)
+//!         * 3.4.1 [Using Crates Repository](#341-using-crates-repository)
+//!         * 3.4.2 [Using Local Repository](#342-using-local-repository)
+//!         * 3.4.3 [Using Remote Repository](#343-using-remote-repository)
 //! * 4. [Modules Overview](#4-modules-overview)
 //! * 5. [Modules and Levels Hierarchy](#5-modules-and-levels-hierarchy)
 //!
@@ -150,9 +151,23 @@
 //!
 //! `cmd_alias` use alias instead of full tmux command name (e.g. `list-sessions` -> `ls`). Enabled by default.
 //!
-//! ## 3.3. Repository
+//! ## 3.3. Optional Features
 //!
-//! ### 3.3.1. Using Crates Repository
+//! Optional features, disabled by default:
+//!
+//! * `serde` derive `Serialize` and `Deserialize` for spec structures (e.g. `Workspace`),
+//!   variables, options and targets. Layouts, styles, flags, option values and targets are
+//!   serialized in their tmux string form (e.g. `"fg=red,bold"`, `"$1:2.3"`)
+//! * `toml` load workspace definitions from TOML (`Workspace::from_toml`)
+//! * `yaml` load workspace definitions from YAML (`Workspace::from_yaml`)
+//! * `json` store JSON encoded user options values (`UserOptionsCtl::set_json`)
+//! * `regex` expect text or regular expressions in panes (`PaneExpect`)
+//! * `testing` test fixtures running commands on an isolated tmux server (`TestServer`) or an
+//!   in-process fake one (`FakeServer`), unix only
+//!
+//! ## 3.4. Repository
+//!
+//! ### 3.4.1. Using Crates Repository
 //!
 //! ```text
 //! [dependencies]
@@ -161,7 +176,7 @@
 //! }
 //! ```
 //!
-//! ### 3.3.2. Using Local Repository
+//! ### 3.4.2. Using Local Repository
 //!
 //! ```text
 //! [dependencies]
@@ -171,7 +186,7 @@
 //! }
 //! ```
 //!
-//! ### 3.3.3. Using Remote Repository
+//! ### 3.4.3. Using Remote Repository
 //!
 //! ```text
 //! tmux_interface = {
@@ -210,6 +225,15 @@
 //!     * Clock Mode
 //!         * (unimplemented)
 //!
+//! * Config ([`config`])
+//!     * [`TmuxConf`](crate::TmuxConf)
+//!     * [`TmuxConfLoader`](crate::TmuxConfLoader)
+//!     * ...
+//!
+//! * Expect (`expect`, `regex` feature)
+//!     * `PaneExpect`
+//!     * `ExpectMatch`
+//!
 //! * Formats ([`formats`])
 //!     * [`Formats`][crate::formats::Formats]
 //!     * [`FormatsOutput`][crate::formats::FormatsOutput]
@@ -217,19 +241,44 @@
 //!     * [`VariableOutput`][crate::formats::VariableOutput]
 //!     * ...
 //!
+//! * Interactive ([`interactive`])
+//!     * [`MenuChannel`](crate::MenuChannel)
+//!     * [`PromptChannel`](crate::PromptChannel)
+//!     * [`PopupRunner`](crate::PopupRunner)
+//!     * ...
+//!
 //! * Options ([`options`])
 //!
+//! * Pipe ([`pipe`])
+//!     * [`PaneStream`](crate::PaneStream)
+//!     * [`PaneLog`](crate::PaneLog)
+//!
+//! * Snapshot ([`snapshot`])
+//!     * [`Snapshot`](crate::Snapshot)
+//!     * [`SnapshotCtl`](crate::SnapshotCtl)
+//!     * ...
+//!
 //! * Styles ([`styles`])
 //!     * [`StyleList`][crate::styles::StyleList]
 //!     * [`Style`][crate::styles::Style]
+//!     * [`StatusLine`][crate::styles::StatusLine]
 //!     * ...
 //!
+//! * Sync ([`sync`])
+//!     * [`Channel`](crate::Channel)
+//!     * [`ChannelGuard`](crate::ChannelGuard)
+//!     * [`PaneRunner`](crate::PaneRunner)
+//!
 //! * Target ([`target`])
 //!     * [`TargetSession`]
 //!     * [`TargetWindow`]
 //!     * [`TargetPane`]
 //!     * ...
 //!
+//! * Testing ([`testing`])
+//!     * [`TestServer`](crate::TestServer)
+//!     * [`FakeServer`](crate::FakeServer)
+//!
 //! * Variables ([`variables`])
 //!     * [`Sessions`](crate::Sessions)
 //!     * [`Session`](crate::Session)
@@ -239,6 +288,11 @@
 //!     * [`Pane`](crate::Pane)
 //!     * ...
 //!
+//! * Workspace ([`workspace`])
+//!     * [`Workspace`](crate::Workspace)
+//!     * [`WorkspaceCtl`](crate::WorkspaceCtl)
+//!     * ...
+//!
 //! * Error ([`Error`])
 //!
 //! * ...
@@ -486,23 +540,59 @@
 // user app -> library in -> ... -> library out -> user app
 // ```
 
+#[cfg(feature = "serde")]
+#[macro_use]
+mod serde_str;
+
 pub mod commands;
+#[cfg(feature = "tmux_1_2")]
+pub mod config;
 pub mod control_mode;
 pub mod copy_mode;
 pub mod error;
+#[cfg(all(feature = "regex", feature = "tmux_2_9a"))]
+pub mod expect;
 pub mod formats;
+#[cfg(feature = "tmux_3_0")]
+pub mod interactive;
 pub mod options;
+#[cfg(all(unix, feature = "tmux_2_9a"))]
+pub mod pipe;
+#[cfg(feature = "tmux_2_0")]
+pub mod snapshot;
 pub mod styles;
+#[cfg(feature = "tmux_1_8")]
+pub mod sync;
 pub mod target;
+#[cfg(all(unix, any(test, feature = "testing"), feature = "tmux_1_6"))]
+pub mod testing;
 #[cfg(feature = "tmux_1_6")]
 pub mod variables;
+#[cfg(feature = "tmux_1_9")]
+pub mod workspace;
 
 pub use commands::*;
+#[cfg(feature = "tmux_1_2")]
+pub use config::*;
 pub use control_mode::*;
 pub use error::Error;
+#[cfg(all(feature = "regex", feature = "tmux_2_9a"))]
+pub use expect::*;
 pub use formats::*;
+#[cfg(feature = "tmux_3_0")]
+pub use interactive::*;
 pub use options::*;
+#[cfg(all(unix, feature = "tmux_2_9a"))]
+pub use pipe::*;
+#[cfg(feature = "tmux_2_0")]
+pub use snapshot::*;
 pub use styles::*;
+#[cfg(feature = "tmux_1_8")]
+pub use sync::*;
 pub use target::*;
+#[cfg(all(unix, any(test, feature = "testing"), feature = "tmux_1_6"))]
+pub use testing::*;
 #[cfg(feature = "tmux_1_6")]
 pub use variables::*;
+#[cfg(feature = "tmux_1_9")]
+pub use workspace::*;
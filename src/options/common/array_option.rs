@@ -0,0 +1,246 @@
+use crate::options::common::{get_parts, option_value_unescape};
+use crate::Error;
+#[cfg(feature = "tmux_1_7")]
+use crate::{OptionScope, TmuxCommand, TmuxCommands, TmuxOutput};
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Array option items by index (e.g. `command-alias[0]`, `status-format[1]`), indices may be
+/// sparse after single items were set or unset
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::ArrayOption;
+///
+/// let output = "command-alias[0] split-pane=split-window\n\
+///               command-alias[5] \"info=show-messages -JT\"\n";
+/// let mut command_alias: ArrayOption<String> = output.parse().unwrap();
+/// assert_eq!(command_alias.get(5).unwrap(), "info=show-messages -JT");
+/// assert_eq!(command_alias.get(1), None);
+///
+/// command_alias.push("x=y".to_string());
+/// assert_eq!(command_alias.get(6).unwrap(), "x=y");
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ArrayOption<T> {
+    pub items: BTreeMap<usize, T>,
+}
+
+impl<T> Default for ArrayOption<T> {
+    fn default() -> Self {
+        Self {
+            items: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> ArrayOption<T> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(&index)
+    }
+
+    /// Set item at the index, returns the previous item
+    pub fn insert(&mut self, index: usize, item: T) -> Option<T> {
+        self.items.insert(index, item)
+    }
+
+    /// Unset item at the index, returns the removed item
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.items.remove(&index)
+    }
+
+    /// Append item after the last index
+    pub fn push(&mut self, item: T) {
+        let index = self.next_index();
+        self.items.insert(index, item);
+    }
+
+    /// Index after the last item
+    pub fn next_index(&self) -> usize {
+        self.items.keys().next_back().map_or(0, |index| index + 1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Items with indices in ascending index order
+    pub fn iter(&self) -> btree_map::Iter<'_, usize, T> {
+        self.items.iter()
+    }
+
+    /// Items in ascending index order, indices are dropped
+    pub fn into_vec(self) -> Vec<T> {
+        self.items.into_values().collect()
+    }
+}
+
+impl<T> From<Vec<T>> for ArrayOption<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self {
+            items: items.into_iter().enumerate().collect(),
+        }
+    }
+}
+
+/// Parse `show-options` output (`name[index] value` lines), lines without index (empty
+/// array) are skipped
+impl<T: FromStr> FromStr for ArrayOption<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut array = Self::new();
+        for line in s.lines() {
+            if let Some((_, Some(index), value)) = get_parts(line) {
+                let value = option_value_unescape(value.unwrap_or_default());
+                let item = value.parse().map_err(|_| Error::ParseArrayOption)?;
+                array.insert(index, item);
+            }
+        }
+        Ok(array)
+    }
+}
+
+// array option get/set commands for the given scope, used by options ctls
+#[cfg(feature = "tmux_1_7")]
+pub(crate) struct ArrayOptionCtl<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    pub scope: OptionScope<'a>,
+}
+
+#[cfg(feature = "tmux_1_7")]
+impl<'a> ArrayOptionCtl<'a> {
+    pub fn new(
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+        scope: OptionScope<'a>,
+    ) -> Self {
+        Self { invoker, scope }
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<ArrayOption<T>, Error> {
+        let cmd = self.scope.show_options().option(name.to_string()).build();
+        let output = (self.invoker)(cmd)?.into_result()?.to_string();
+        output.parse()
+    }
+
+    pub fn get_item<T: FromStr>(&self, name: &str, index: usize) -> Result<Option<T>, Error> {
+        Ok(self.get(name)?.remove(index))
+    }
+
+    pub fn set_item<T: fmt::Display>(
+        &self,
+        name: &str,
+        index: usize,
+        item: Option<T>,
+    ) -> Result<(), Error> {
+        let cmd = self
+            .scope
+            .set_option(format!("{}[{}]", name, index), item.map(|s| s.to_string()));
+        (self.invoker)(cmd)?.into_result()?;
+        Ok(())
+    }
+
+    pub fn append<T: fmt::Display>(&self, name: &str, item: T) -> Result<(), Error> {
+        let cmd = self.scope.append_option(name.to_string(), item.to_string());
+        (self.invoker)(cmd)?.into_result()?;
+        Ok(())
+    }
+
+    // items not in the given array are unset, single invocation
+    pub fn set<T: fmt::Display>(&self, name: &str, array: &ArrayOption<T>) -> Result<(), Error> {
+        let current: ArrayOption<String> = self.get(name)?;
+        let mut cmds = TmuxCommands::new();
+        for index in current.items.keys() {
+            if array.get(*index).is_none() {
+                cmds.push(self.scope.set_option(format!("{}[{}]", name, index), None));
+            }
+        }
+        for (index, item) in array.iter() {
+            cmds.push(
+                self.scope
+                    .set_option(format!("{}[{}]", name, index), Some(item.to_string())),
+            );
+        }
+        if !cmds.commands.is_empty() {
+            (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        }
+        Ok(())
+    }
+}
+
+/// Array option methods shared by the server, session, window and pane options ctls
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{ArrayOption, ArrayOptionsCtl, ServerOptionsCtl};
+///
+/// let server_ctl = ServerOptionsCtl::default();
+/// server_ctl.set_array_item("command-alias", 10, "x=y").unwrap();
+/// let command_alias: ArrayOption<String> = server_ctl.get_array_option("command-alias").unwrap();
+/// ```
+#[cfg(feature = "tmux_1_7")]
+pub trait ArrayOptionsCtl<'a> {
+    /// Function used for executing the array option get/set commands
+    fn array_invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>;
+
+    /// Options scope of the array option commands (`set-option`/`show-options` flags and target)
+    fn array_scope(&self) -> OptionScope<'a>;
+
+    /// Array option items by index (e.g. `command-alias[3]`), indices may be sparse
+    fn get_array_option<T: FromStr>(&self, name: &str) -> Result<ArrayOption<T>, Error> {
+        ArrayOptionCtl::new(self.array_invoker(), self.array_scope()).get(name)
+    }
+
+    /// Array option item at the index, `None` if not set
+    fn get_array_item<T: FromStr>(&self, name: &str, index: usize) -> Result<Option<T>, Error> {
+        ArrayOptionCtl::new(self.array_invoker(), self.array_scope()).get_item(name, index)
+    }
+
+    /// Set array option item at the index (`name[index] value`)
+    fn set_array_item<T: fmt::Display>(
+        &self,
+        name: &str,
+        index: usize,
+        item: T,
+    ) -> Result<(), Error> {
+        ArrayOptionCtl::new(self.array_invoker(), self.array_scope()).set_item(
+            name,
+            index,
+            Some(item),
+        )
+    }
+
+    /// Unset array option item at the index, other items keep their indices
+    fn unset_array_item(&self, name: &str, index: usize) -> Result<(), Error> {
+        ArrayOptionCtl::new(self.array_invoker(), self.array_scope())
+            .set_item::<String>(name, index, None)
+    }
+
+    /// Append item to the array option (`set-option -a`), tmux uses the first free index and
+    /// splits the item on the option separator (e.g. spaces for `update-environment`)
+    fn append_array_item<T: fmt::Display>(&self, name: &str, item: T) -> Result<(), Error> {
+        ArrayOptionCtl::new(self.array_invoker(), self.array_scope()).append(name, item)
+    }
+
+    /// Replace all array option items, current items not in `array` are unset (single tmux
+    /// invocation)
+    fn set_array_option<T: fmt::Display>(
+        &self,
+        name: &str,
+        array: &ArrayOption<T>,
+    ) -> Result<(), Error> {
+        ArrayOptionCtl::new(self.array_invoker(), self.array_scope()).set(name, array)
+    }
+}
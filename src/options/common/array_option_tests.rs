@@ -0,0 +1,45 @@
+#[test]
+fn array_option() {
+    use crate::{ArrayOption, Error};
+
+    let output = "update-environment[0] DISPLAY\n\
+                  update-environment[2] \"SSH AUTH\"\n\
+                  update-environment[7] XAUTHORITY\n";
+    let mut array: ArrayOption<String> = output.parse().unwrap();
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.get(2).unwrap(), "SSH AUTH");
+    assert_eq!(array.get(1), None);
+    assert_eq!(array.next_index(), 8);
+
+    assert_eq!(array.remove(7).unwrap(), "XAUTHORITY");
+    array.push("WINDOWID".to_string());
+    assert_eq!(array.insert(5, "KRB5CCNAME".to_string()), None);
+    let items: Vec<(usize, &str)> = array.iter().map(|(i, s)| (*i, s.as_str())).collect();
+    assert_eq!(
+        items,
+        vec![
+            (0, "DISPLAY"),
+            (2, "SSH AUTH"),
+            (3, "WINDOWID"),
+            (5, "KRB5CCNAME")
+        ]
+    );
+    assert_eq!(
+        array.into_vec(),
+        vec!["DISPLAY", "SSH AUTH", "WINDOWID", "KRB5CCNAME"]
+    );
+
+    // empty array, name only
+    let array: ArrayOption<String> = "terminal-overrides\n".parse().unwrap();
+    assert!(array.is_empty());
+
+    let array: ArrayOption<usize> = "a[0] 1\na[3] 4\n".parse().unwrap();
+    let mut expected = ArrayOption::from(vec![1, 0, 0, 4]);
+    expected.remove(1);
+    expected.remove(2);
+    assert_eq!(array, expected);
+    assert!(matches!(
+        "a[0] x".parse::<ArrayOption<usize>>(),
+        Err(Error::ParseArrayOption)
+    ));
+}
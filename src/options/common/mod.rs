@@ -1,10 +1,16 @@
+pub mod array_option;
 pub mod constants;
+pub mod option_value;
 pub mod status_keys;
 pub mod switch;
 pub mod terminal_features;
 pub mod user_option;
 
+pub use array_option::ArrayOption;
+#[cfg(feature = "tmux_1_7")]
+pub use array_option::ArrayOptionsCtl;
 pub use constants::*;
+pub use option_value::{option_value_escape, option_value_unescape};
 pub use status_keys::StatusKeys;
 pub use switch::Switch;
 pub use terminal_features::*;
@@ -39,10 +45,51 @@ pub fn option_array_to_string<S: fmt::Display>(
     }
 }
 
+// option value, part of the options structure values (`OptionsValuesTr::values()`)
+pub fn option_value<S: fmt::Display>(
+    v: &mut Vec<(&'static str, Option<usize>, String)>,
+    name: &'static str,
+    value: &Option<S>,
+) {
+    if let Some(data) = value {
+        v.push((name, None, data.to_string()))
+    }
+}
+
+// array option items with their indices
+pub fn option_array_value<S: fmt::Display>(
+    v: &mut Vec<(&'static str, Option<usize>, String)>,
+    name: &'static str,
+    value: &Option<Vec<S>>,
+) {
+    if let Some(data) = value {
+        for (i, item) in data.iter().enumerate() {
+            v.push((name, Some(i), item.to_string()))
+        }
+    }
+}
+
+// `name value` lines, array items on separate lines with the same name
+pub fn option_values_to_string(values: &[(&str, Option<usize>, String)]) -> String {
+    let v: Vec<String> = values
+        .iter()
+        .map(|(name, _, value)| format!("{}{}{}", name, SEPARATOR, value))
+        .collect();
+    v.join("\n")
+}
+
+// set array item `i`, missing items before sparse indices (e.g. only `user-keys[3]` is set)
+// are padded with empty values
 pub fn array_insert(v: &mut Option<Vec<Cow<'_, str>>>, i: Option<usize>, value: Option<String>) {
     if let Some(i) = i {
         match value {
-            Some(data) => v.get_or_insert(Vec::new()).insert(i, data.into()),
+            Some(data) => {
+                let items = v.get_or_insert(Vec::new());
+                if items.len() <= i {
+                    items.resize(i + 1, Cow::Borrowed(""));
+                }
+                items[i] = data.into();
+            }
             None => *v = None,
         }
     }
@@ -75,6 +122,8 @@ pub fn get_parts(s: &str) -> Option<(&str, Option<usize>, Option<&str>)> {
 #[cfg(test)]
 #[path = "."]
 mod common_tests {
+    pub mod array_option_tests;
+    pub mod option_value_tests;
     pub mod status_keys_tests;
     pub mod switch_tests;
 }
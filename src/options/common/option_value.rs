@@ -0,0 +1,91 @@
+use std::borrow::Cow;
+
+const DOUBLE_QUOTE: char = '"';
+const SINGLE_QUOTE: char = '\'';
+const BACKSLASH: char = '\\';
+const COMMAND_SEPARATOR: char = ';';
+
+/// Unquote and unescape option value as printed by `show-options`
+///
+/// tmux quotes values containing spaces or special characters and escapes them
+/// (ref: arguments.c -> args_escape()), e.g. `"#S:#I \"#T\""`, `''`, `\~`
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::option_value_unescape;
+///
+/// assert_eq!(option_value_unescape("\"[#S] \""), "[#S] ");
+/// assert_eq!(option_value_unescape("''"), "");
+/// assert_eq!(option_value_unescape("C-b"), "C-b");
+/// ```
+pub fn option_value_unescape(s: &str) -> String {
+    let s = match (s.chars().next(), s.chars().last()) {
+        (Some(DOUBLE_QUOTE), Some(DOUBLE_QUOTE)) | (Some(SINGLE_QUOTE), Some(SINGLE_QUOTE))
+            if s.len() > 1 =>
+        {
+            &s[1..s.len() - 1]
+        }
+        _ => s,
+    };
+
+    // escaped characters (ref: vis.c -> VIS_CSTYLE, VIS_OCTAL, VIS_DQ)
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != BACKSLASH {
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        match chars.next() {
+            Some('n') => bytes.push(b'\n'),
+            Some('r') => bytes.push(b'\r'),
+            Some('t') => bytes.push(b'\t'),
+            Some('a') => bytes.push(0x07),
+            Some('b') => bytes.push(0x08),
+            Some('v') => bytes.push(0x0b),
+            Some('f') => bytes.push(0x0c),
+            Some('s') => bytes.push(b' '),
+            Some(c @ '0'..='7') => {
+                let mut n = c.to_digit(8).unwrap_or_default();
+                for _ in 0..2 {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(d) => {
+                            n = n * 8 + d;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                bytes.push(n as u8);
+            }
+            Some(c) => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            }
+            None => bytes.push(b'\\'),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Escape option value passed as `set-option` argument
+///
+/// Arguments are not parsed by shell, but tmux treats trailing `;` as command separator
+/// (ref: cmd-parse.c -> cmd_parse_from_arguments()), it is escaped as `\;`
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::option_value_escape;
+///
+/// assert_eq!(option_value_escape("a;b"), "a;b");
+/// assert_eq!(option_value_escape("a;"), "a\\;");
+/// ```
+pub fn option_value_escape(s: &str) -> Cow<'_, str> {
+    match s.strip_suffix(COMMAND_SEPARATOR) {
+        Some(s) => Cow::Owned(format!("{}{}{}", s, BACKSLASH, COMMAND_SEPARATOR)),
+        None => Cow::Borrowed(s),
+    }
+}
@@ -0,0 +1,36 @@
+#[test]
+fn option_value_unescape() {
+    use crate::option_value_unescape;
+
+    assert_eq!(option_value_unescape("C-b"), "C-b");
+    assert_eq!(option_value_unescape(""), "");
+    assert_eq!(option_value_unescape("''"), "");
+    assert_eq!(option_value_unescape("\""), "\"");
+    assert_eq!(option_value_unescape("\\~"), "~");
+    assert_eq!(option_value_unescape("'lock -np'"), "lock -np");
+    assert_eq!(
+        option_value_unescape("\"[#{session_name}] \""),
+        "[#{session_name}] "
+    );
+    assert_eq!(
+        option_value_unescape("\"#S:#I:#W - \\\"#T\\\" #{session_alerts}\""),
+        "#S:#I:#W - \"#T\" #{session_alerts}"
+    );
+    assert_eq!(
+        option_value_unescape("\"!\\\"#\\$%&'()*+,-./:;<=>?@[\\\\]^`{|}~\""),
+        "!\"#$%&'()*+,-./:;<=>?@[\\]^`{|}~"
+    );
+    assert_eq!(option_value_unescape("\"a\\tb\\nc\""), "a\tb\nc");
+    assert_eq!(option_value_unescape("\"\\303\\251 \\033\""), "é \x1b");
+}
+
+#[test]
+fn option_value_escape() {
+    use crate::option_value_escape;
+
+    assert_eq!(option_value_escape(""), "");
+    assert_eq!(option_value_escape("a;b"), "a;b");
+    assert_eq!(option_value_escape(";"), "\\;");
+    assert_eq!(option_value_escape("a\\;"), "a\\\\;");
+    assert_eq!(option_value_escape("\"a\"\n\t"), "\"a\"\n\t");
+}
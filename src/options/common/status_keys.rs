@@ -29,3 +29,6 @@ impl FromStr for StatusKeys {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(StatusKeys);
@@ -29,3 +29,6 @@ impl FromStr for Switch {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(Switch);
@@ -288,3 +288,6 @@ impl<'a> FromStr for TerminalFeatures<'a> {
         Ok(tf)
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(<'a> TerminalFeatures<'a>);
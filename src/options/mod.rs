@@ -144,6 +144,10 @@
 //!
 //! # 2.3. Control
 //!
+//! * [`OptionsCtl`] all scopes at once ([`Options`]), diff and apply ([`OptionsDiff`])
+//! * [`OptionsCtl::resolve`] effective option value and its source level ([`ResolvedOption`])
+//! * [`OptionsRegistry`] known options metadata per tmux version, `set-option` validation
+//! * [`UserOptionsCtl`] typed key-value store in user options of any scope
 //! * [`ServerOptionsCtl`]
 //! * [`SessionOptionsCtl`]
 //!     * [`GlobalSessionOptionsCtl`]
@@ -412,13 +416,36 @@
 //
 pub mod common;
 pub mod get_option_tr;
+pub mod options_values_tr;
 pub mod set_option_tr;
 
 pub use common::*;
 
 pub use get_option_tr::GetOptionTr;
+pub use options_values_tr::OptionsValuesTr;
 pub use set_option_tr::SetOptionTr;
 
+#[cfg(feature = "tmux_1_2")]
+pub mod options_ctl;
+#[cfg(feature = "tmux_1_2")]
+pub mod options_diff;
+pub mod options_registry;
+pub(crate) mod options_table;
+#[cfg(feature = "tmux_1_8")]
+pub mod resolved_option;
+#[cfg(feature = "tmux_1_8")]
+pub mod user_options_ctl;
+
+#[cfg(feature = "tmux_1_2")]
+pub use options_ctl::{GlobalOptions, Options, OptionsCtl};
+#[cfg(feature = "tmux_1_2")]
+pub use options_diff::{OptionChange, OptionScope, OptionsDiff};
+pub use options_registry::{OptionInfo, OptionKind, OptionType, OptionsRegistry};
+#[cfg(feature = "tmux_1_8")]
+pub use resolved_option::ResolvedOption;
+#[cfg(feature = "tmux_1_8")]
+pub use user_options_ctl::UserOptionsCtl;
+
 #[cfg(feature = "tmux_3_1")]
 pub mod pane;
 #[cfg(feature = "tmux_1_2")]
@@ -442,5 +469,16 @@ pub use window::*;
 mod options_tests {
     pub mod get_option_tr_tests;
 
+    #[cfg(feature = "tmux_1_2")]
+    pub mod options_diff_tests;
+
+    pub mod options_registry_tests;
+
+    #[cfg(feature = "tmux_1_8")]
+    pub mod resolved_option_tests;
+
     pub mod set_option_tr_tests;
+
+    #[cfg(feature = "tmux_1_8")]
+    pub mod user_options_ctl_tests;
 }
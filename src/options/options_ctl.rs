@@ -1,9 +1,32 @@
 use crate::{
     Error, GlobalSessionOptionsCtl, GlobalWindowOptionsCtl, LocalSessionOptionsCtl,
-    LocalWindowOptionsCtl, ServerOptions, ServerOptionsCtl, SessionOptions, SessionOptionsCtl,
-    Tmux, TmuxCommand, TmuxOutput, WindowOptions, WindowOptionsCtl,
+    LocalWindowOptionsCtl, OptionScope, OptionsDiff, ServerOptions, ServerOptionsCtl,
+    SessionOptions, SessionOptionsCtl, Tmux, TmuxCommand, TmuxOutput, WindowOptions,
+    WindowOptionsCtl,
 };
+#[cfg(feature = "tmux_1_8")]
+use crate::{OptionKind, OptionsRegistry, ResolvedOption};
+#[cfg(feature = "tmux_3_1")]
+use crate::{PaneOptions, PaneOptionsCtl};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
+/// Get/set options of all scopes (server, session, window, pane) at once
+///
+/// Local session, window and pane options are taken from the given targets, current
+/// session/window/pane is used if target is not set
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::OptionsCtl;
+///
+/// let options_ctl = OptionsCtl::new().target_session("0:");
+/// let mut options = options_ctl.get_all().unwrap();
+/// options.local_session_options.base_index = Some(1);
+/// options_ctl.set_all(options).unwrap();
+/// ```
 pub struct OptionsCtl<'a> {
     // TODO: comment/doc
     //
@@ -12,68 +35,322 @@ pub struct OptionsCtl<'a> {
     // ```
     // let tmux = Tmux::new();
     // ```
-    pub invoker: fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// target session for local session options
+    pub target_session: Option<Cow<'a, str>>,
+    /// target window for local window options
+    pub target_window: Option<Cow<'a, str>>,
+    /// target pane for pane options
+    pub target_pane: Option<Cow<'a, str>>,
 }
 
 impl<'a> Default for OptionsCtl<'a> {
     fn default() -> Self {
         Self {
-            invoker: |cmd| Tmux::with_command(cmd).output(),
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            target_session: None,
+            target_window: None,
+            target_pane: None,
         }
     }
 }
 
 impl<'a> OptionsCtl<'a> {
-    pub fn get_all(&self) -> Options<'a> {
-        let mut options = Options::default();
-        options.server_options = self.get_server_options();
-        options
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    pub fn get_server_options(&self) -> ServerOptions<'a> {
-        let server_options_ctl = ServerOptionsCtl::default();
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Set target session for local session options
+    pub fn target_session<S: Into<Cow<'a, str>>>(mut self, target_session: S) -> Self {
+        self.target_session = Some(target_session.into());
+        self
+    }
 
-        server_options_ctl.get_all().unwrap()
+    /// Set target window for local window options
+    pub fn target_window<S: Into<Cow<'a, str>>>(mut self, target_window: S) -> Self {
+        self.target_window = Some(target_window.into());
+        self
     }
 
-    pub fn get_global_session_options(&self) -> SessionOptions<'a> {
-        let session_options_ctl = GlobalSessionOptionsCtl::default();
+    /// Set target pane for pane options
+    pub fn target_pane<S: Into<Cow<'a, str>>>(mut self, target_pane: S) -> Self {
+        self.target_pane = Some(target_pane.into());
+        self
+    }
+
+    /// Get options of all scopes
+    pub fn get_all(&self) -> Result<Options<'a>, Error> {
+        Ok(Options {
+            server_options: self.get_server_options()?,
+            global_session_options: self.get_global_session_options()?,
+            local_session_options: self.get_local_session_options()?,
+            global_window_options: self.get_global_window_options()?,
+            local_window_options: self.get_local_window_options()?,
+            #[cfg(feature = "tmux_3_1")]
+            pane_options: self.get_pane_options()?,
+        })
+    }
 
-        session_options_ctl.get_all().unwrap()
+    /// Set options of all scopes, options not set (`None`) are unset
+    pub fn set_all(&self, options: Options<'a>) -> Result<(), Error> {
+        self.set_server_options(options.server_options)?;
+        self.set_global_session_options(options.global_session_options)?;
+        self.set_local_session_options(options.local_session_options)?;
+        self.set_global_window_options(options.global_window_options)?;
+        self.set_local_window_options(options.local_window_options)?;
+        #[cfg(feature = "tmux_3_1")]
+        self.set_pane_options(options.pane_options)?;
+        Ok(())
     }
 
-    pub fn get_local_session_options(&self) -> SessionOptions<'a> {
-        let session_options_ctl = LocalSessionOptionsCtl::default();
+    /// Changes needed to get from the current to the `desired` options, only options set
+    /// in `desired` are compared (see [`OptionsDiff::diff`])
+    pub fn diff(&self, desired: &Options<'a>) -> Result<OptionsDiff<'a>, Error> {
+        let current = self.get_all()?;
+        let mut diff = OptionsDiff::new();
+        diff.append(OptionsDiff::diff(
+            OptionScope::Server,
+            &current.server_options,
+            &desired.server_options,
+        ));
+        diff.append(OptionsDiff::diff(
+            OptionScope::GlobalSession,
+            &current.global_session_options,
+            &desired.global_session_options,
+        ));
+        diff.append(OptionsDiff::diff(
+            OptionScope::Session(self.target_session.clone()),
+            &current.local_session_options,
+            &desired.local_session_options,
+        ));
+        diff.append(OptionsDiff::diff(
+            OptionScope::GlobalWindow,
+            &current.global_window_options,
+            &desired.global_window_options,
+        ));
+        diff.append(OptionsDiff::diff(
+            OptionScope::Window(self.target_window.clone()),
+            &current.local_window_options,
+            &desired.local_window_options,
+        ));
+        #[cfg(feature = "tmux_3_1")]
+        diff.append(OptionsDiff::diff(
+            OptionScope::Pane(self.target_pane.clone()),
+            &current.pane_options,
+            &desired.pane_options,
+        ));
+        Ok(diff)
+    }
 
-        session_options_ctl.get_all().unwrap()
+    /// Set changed options only (single tmux invocation), options not set in `desired`
+    /// are left as they are. Returns applied changes
+    pub fn apply(&self, desired: &Options<'a>) -> Result<OptionsDiff<'a>, Error> {
+        let diff = self.diff(desired)?;
+        diff.apply(self.invoker)?;
+        Ok(diff)
     }
 
-    pub fn get_global_window_global_options(&self) -> WindowOptions<'a> {
-        let window_options_ctl = GlobalWindowOptionsCtl::default();
-        window_options_ctl.get_all().unwrap()
+    /// Effective value of the option and the level it is set on, the inheritance chain is
+    /// given by the option kind ([`OptionsRegistry`]): pane -> window -> global window,
+    /// session -> global session or server
+    ///
+    /// User options (`@name`) can be set on any level, use [`OptionsCtl::resolve_kind`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tmux_interface::OptionsCtl;
+    ///
+    /// let options_ctl = OptionsCtl::new().target_pane("%1");
+    /// let resolved = options_ctl.resolve("mode-keys").unwrap();
+    /// // e.g. `mode-keys "vi" (global window)`
+    /// println!("{}", resolved);
+    /// ```
+    #[cfg(feature = "tmux_1_8")]
+    pub fn resolve(&self, name: &str) -> Result<ResolvedOption<'a>, Error> {
+        let option = OptionsRegistry::latest(name)
+            .ok_or_else(|| Error::InvalidOption(format!("unknown option kind: {}", name)))?;
+        self.resolve_kind(option.kind, name)
     }
 
-    pub fn get_local_window_global_options(&self) -> WindowOptions<'a> {
-        let window_options_ctl = LocalWindowOptionsCtl::default();
+    /// Effective value of the option of the given kind and the level it is set on
+    ///
+    /// Window level target is the target window or pane, session level target is the
+    /// target session, window or pane
+    #[cfg(feature = "tmux_1_8")]
+    pub fn resolve_kind(&self, kind: OptionKind, name: &str) -> Result<ResolvedOption<'a>, Error> {
+        let target_window = self
+            .target_window
+            .clone()
+            .or_else(|| self.target_pane.clone());
+        let target_session = self
+            .target_session
+            .clone()
+            .or_else(|| target_window.clone());
+        let chain = match kind {
+            OptionKind::Server => vec![OptionScope::Server],
+            OptionKind::Session => vec![
+                OptionScope::Session(target_session),
+                OptionScope::GlobalSession,
+            ],
+            OptionKind::Window => vec![
+                OptionScope::Window(target_window),
+                OptionScope::GlobalWindow,
+            ],
+            #[cfg(feature = "tmux_3_1")]
+            OptionKind::Pane => vec![
+                OptionScope::Pane(self.target_pane.clone()),
+                OptionScope::Window(target_window),
+                OptionScope::GlobalWindow,
+            ],
+            #[cfg(not(feature = "tmux_3_1"))]
+            OptionKind::Pane => vec![
+                OptionScope::Window(target_window),
+                OptionScope::GlobalWindow,
+            ],
+        };
+        ResolvedOption::resolve(self.invoker, name, chain)
+    }
+
+    pub fn get_server_options(&self) -> Result<ServerOptions<'a>, Error> {
+        self.server_options_ctl().get_all()
+    }
 
-        window_options_ctl.get_all().unwrap()
+    pub fn set_server_options(&self, server_options: ServerOptions<'a>) -> Result<(), Error> {
+        self.server_options_ctl()
+            .set_all(server_options)?
+            .into_result()?;
+        Ok(())
     }
 
-    // pub fn get_pane_options() -> PaneOptions<'a> {}
+    pub fn get_global_session_options(&self) -> Result<SessionOptions<'a>, Error> {
+        self.global_session_options_ctl().get_all()
+    }
+
+    pub fn set_global_session_options(
+        &self,
+        session_options: SessionOptions<'a>,
+    ) -> Result<(), Error> {
+        self.global_session_options_ctl()
+            .set_all(session_options)?
+            .into_result()?;
+        Ok(())
+    }
+
+    pub fn get_local_session_options(&self) -> Result<SessionOptions<'a>, Error> {
+        self.local_session_options_ctl().get_all()
+    }
+
+    pub fn set_local_session_options(
+        &self,
+        session_options: SessionOptions<'a>,
+    ) -> Result<(), Error> {
+        self.local_session_options_ctl()
+            .set_all(session_options)?
+            .into_result()?;
+        Ok(())
+    }
+
+    pub fn get_global_window_options(&self) -> Result<WindowOptions<'a>, Error> {
+        self.global_window_options_ctl().get_all()
+    }
+
+    pub fn set_global_window_options(
+        &self,
+        window_options: WindowOptions<'a>,
+    ) -> Result<(), Error> {
+        self.global_window_options_ctl()
+            .set_all(window_options)?
+            .into_result()?;
+        Ok(())
+    }
+
+    pub fn get_local_window_options(&self) -> Result<WindowOptions<'a>, Error> {
+        self.local_window_options_ctl().get_all()
+    }
+
+    pub fn set_local_window_options(&self, window_options: WindowOptions<'a>) -> Result<(), Error> {
+        self.local_window_options_ctl()
+            .set_all(window_options)?
+            .into_result()?;
+        Ok(())
+    }
+
+    #[cfg(feature = "tmux_3_1")]
+    pub fn get_pane_options(&self) -> Result<PaneOptions<'a>, Error> {
+        self.pane_options_ctl().get_all()
+    }
+
+    #[cfg(feature = "tmux_3_1")]
+    pub fn set_pane_options(&self, pane_options: PaneOptions<'a>) -> Result<(), Error> {
+        self.pane_options_ctl()
+            .set_all(pane_options)?
+            .into_result()?;
+        Ok(())
+    }
+
+    pub fn server_options_ctl(&self) -> ServerOptionsCtl<'a> {
+        ServerOptionsCtl::new(self.invoker)
+    }
+
+    pub fn global_session_options_ctl(&self) -> GlobalSessionOptionsCtl<'a> {
+        GlobalSessionOptionsCtl::new(self.invoker)
+    }
+
+    pub fn local_session_options_ctl(&self) -> LocalSessionOptionsCtl<'a> {
+        LocalSessionOptionsCtl {
+            invoker: self.invoker,
+            target: self.target_session.clone(),
+        }
+    }
+
+    pub fn global_window_options_ctl(&self) -> GlobalWindowOptionsCtl<'a> {
+        GlobalWindowOptionsCtl::new(self.invoker)
+    }
+
+    pub fn local_window_options_ctl(&self) -> LocalWindowOptionsCtl<'a> {
+        LocalWindowOptionsCtl {
+            invoker: self.invoker,
+            target: self.target_window.clone(),
+        }
+    }
+
+    #[cfg(feature = "tmux_3_1")]
+    pub fn pane_options_ctl(&self) -> PaneOptionsCtl<'a> {
+        PaneOptionsCtl {
+            invoker: self.invoker,
+            target: self.target_pane.clone(),
+        }
+    }
 }
 
 // naming convention for moudules objects <Tmux>Options <=> <?>SessionOptions
 // NOTE: separate call only, resulting command can't be merged in one, parsing differentation problems
-#[derive(Default, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Default, Clone, Debug)]
 pub struct Options<'a> {
     pub server_options: ServerOptions<'a>,
     pub global_session_options: SessionOptions<'a>,
     pub local_session_options: SessionOptions<'a>,
     pub global_window_options: WindowOptions<'a>,
     pub local_window_options: WindowOptions<'a>,
-    // pub pane_options: PaneOptions<'a>,
+    #[cfg(feature = "tmux_3_1")]
+    pub pane_options: PaneOptions<'a>,
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Default, Clone, Debug)]
 pub struct GlobalOptions<'a> {
     pub server_options: ServerOptions<'a>,
     pub global_session_options: SessionOptions<'a>,
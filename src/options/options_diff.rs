@@ -0,0 +1,308 @@
+use crate::options::common::option_value_unescape;
+use crate::{
+    Error, OptionsValuesTr, SetOption, ShowOptions, TmuxCommand, TmuxCommands, TmuxOutput,
+};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Options scope, determines `set-option` flags and target
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum OptionScope<'a> {
+    /// `set-option -s`
+    Server,
+    /// `set-option -g`
+    GlobalSession,
+    /// `set-option [-t target]`
+    Session(Option<Cow<'a, str>>),
+    /// `set-option -g -w`
+    GlobalWindow,
+    /// `set-option -w [-t target]`
+    Window(Option<Cow<'a, str>>),
+    /// `set-option -p [-t target]`
+    #[cfg(feature = "tmux_3_1")]
+    Pane(Option<Cow<'a, str>>),
+}
+
+impl<'a> fmt::Display for OptionScope<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (scope, target) = match self {
+            Self::Server => ("server", None),
+            Self::GlobalSession => ("global session", None),
+            Self::Session(target) => ("session", target.as_ref()),
+            Self::GlobalWindow => ("global window", None),
+            Self::Window(target) => ("window", target.as_ref()),
+            #[cfg(feature = "tmux_3_1")]
+            Self::Pane(target) => ("pane", target.as_ref()),
+        };
+        match target {
+            Some(target) => write!(f, "{} {}", scope, target),
+            None => write!(f, "{}", scope),
+        }
+    }
+}
+
+impl<'a> OptionScope<'a> {
+    /// `set-option` command for this scope, `None` value unsets the option (`-u`)
+    pub fn set_option<S: Into<Cow<'a, str>>>(&self, name: S, value: Option<S>) -> TmuxCommand<'a> {
+        let cmd = self.set_options().option(name);
+        let cmd = match value {
+            Some(value) => cmd.value(value),
+            None => cmd.unset(),
+        };
+        cmd.build()
+    }
+
+    /// `set-option -a` command for this scope, value is appended to the string or style
+    /// option, array options get a new item
+    pub fn append_option<S: Into<Cow<'a, str>>>(&self, name: S, value: S) -> TmuxCommand<'a> {
+        self.set_options()
+            .append()
+            .option(name)
+            .value(value)
+            .build()
+    }
+
+    /// `set-option` command builder for this scope (flags and target set)
+    pub fn set_options(&self) -> SetOption<'a> {
+        let cmd = SetOption::new();
+        let (cmd, target) = match self {
+            Self::Server => (cmd.server(), None),
+            Self::GlobalSession => (cmd.global(), None),
+            Self::Session(target) => (cmd, target.clone()),
+            Self::GlobalWindow => (cmd.global().window(), None),
+            Self::Window(target) => (cmd.window(), target.clone()),
+            #[cfg(feature = "tmux_3_1")]
+            Self::Pane(target) => (cmd.pane(), target.clone()),
+        };
+        match target {
+            #[cfg(not(feature = "tmux_3_0"))]
+            Some(target) => cmd.target(target),
+            #[cfg(feature = "tmux_3_0")]
+            Some(target) => cmd.target_pane(target),
+            None => cmd,
+        }
+    }
+
+    /// `show-options` command builder for this scope (flags and target set)
+    pub fn show_options(&self) -> ShowOptions<'a> {
+        let cmd = ShowOptions::new();
+        let (cmd, target) = match self {
+            Self::Server => (cmd.server(), None),
+            Self::GlobalSession => (cmd.global(), None),
+            Self::Session(target) => (cmd, target.clone()),
+            Self::GlobalWindow => (cmd.global().window(), None),
+            Self::Window(target) => (cmd.window(), target.clone()),
+            #[cfg(feature = "tmux_3_1")]
+            Self::Pane(target) => (cmd.pane(), target.clone()),
+        };
+        match target {
+            Some(target) => cmd.target(target),
+            None => cmd,
+        }
+    }
+}
+
+/// Single changed option, part of the [`OptionsDiff`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct OptionChange<'a> {
+    pub scope: OptionScope<'a>,
+    /// option name, array options with index (e.g. `command-alias[1]`)
+    pub name: String,
+    /// current value, `None` if not set
+    pub old: Option<String>,
+    /// desired value, `None` if the option will be unset
+    pub new: Option<String>,
+}
+
+impl<'a> OptionChange<'a> {
+    /// `set-option` (or `set-option -u`) command applying this change
+    pub fn to_command(&self) -> TmuxCommand<'a> {
+        self.scope.set_option(self.name.clone(), self.new.clone())
+    }
+}
+
+impl<'a> fmt::Display for OptionChange<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = |v: &Option<String>| match v {
+            Some(v) => format!("{:?}", v),
+            None => "(unset)".to_string(),
+        };
+        write!(
+            f,
+            "{} {}: {} -> {}",
+            self.scope,
+            self.name,
+            value(&self.old),
+            value(&self.new)
+        )
+    }
+}
+
+/// Difference between current and desired options ([`ServerOptions`](crate::ServerOptions),
+/// [`SessionOptions`](crate::SessionOptions), [`WindowOptions`](crate::WindowOptions),
+/// [`PaneOptions`](crate::PaneOptions))
+///
+/// Current and desired values are normalized the same way, quoted values as returned by the
+/// options ctl getters (`show-options` output, escaped) are unescaped, other values (raw, as set
+/// by user) are compared as they are
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{OptionScope, OptionsDiff, SessionOptions};
+///
+/// let current = SessionOptions {
+///     base_index: Some(0),
+///     history_limit: Some(2000),
+///     status_left: Some("\"[#S] \"".into()),
+///     ..Default::default()
+/// };
+/// let desired = SessionOptions {
+///     base_index: Some(1),
+///     status_left: Some("[#S] ".into()),
+///     ..Default::default()
+/// };
+///
+/// let diff = OptionsDiff::diff(OptionScope::GlobalSession, &current, &desired);
+/// assert_eq!(diff.len(), 1);
+/// assert_eq!(diff.to_string(), "global session base-index: \"0\" -> \"1\"\n");
+/// ```
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct OptionsDiff<'a> {
+    pub changes: Vec<OptionChange<'a>>,
+}
+
+impl<'a> OptionsDiff<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Changes of the options set in `desired` only, other options are not managed and
+    /// left as they are
+    pub fn diff<T: OptionsValuesTr>(scope: OptionScope<'a>, current: &T, desired: &T) -> Self {
+        Self::diff_ext(scope, current, desired, false)
+    }
+
+    /// Changes of all options, options not set in `desired` are unset
+    pub fn diff_all<T: OptionsValuesTr>(scope: OptionScope<'a>, current: &T, desired: &T) -> Self {
+        Self::diff_ext(scope, current, desired, true)
+    }
+
+    fn diff_ext<T: OptionsValuesTr>(
+        scope: OptionScope<'a>,
+        current: &T,
+        desired: &T,
+        all: bool,
+    ) -> Self {
+        let current = options_map(current);
+        let desired = options_map(desired);
+
+        // array options set in `desired` are managed as a whole, other current items are unset
+        let arrays: BTreeSet<&str> = desired
+            .keys()
+            .filter(|(_, index)| index.is_some())
+            .map(|(name, _)| *name)
+            .collect();
+        let mut keys: BTreeSet<&(&str, Option<usize>)> = desired.keys().collect();
+        keys.extend(
+            current
+                .keys()
+                .filter(|(name, index)| all || (index.is_some() && arrays.contains(name))),
+        );
+
+        let mut diff = Self::new();
+        for key in keys {
+            let (old, new) = (current.get(key), desired.get(key));
+            if old != new {
+                let name = match key {
+                    (name, Some(index)) => format!("{}[{}]", name, index),
+                    (name, None) => name.to_string(),
+                };
+                diff.push(scope.clone(), name, old, new);
+            }
+        }
+        diff
+    }
+
+    fn push(
+        &mut self,
+        scope: OptionScope<'a>,
+        name: String,
+        old: Option<&String>,
+        new: Option<&String>,
+    ) {
+        self.changes.push(OptionChange {
+            scope,
+            name,
+            old: old.cloned(),
+            new: new.cloned(),
+        });
+    }
+
+    /// Append changes of another diff (e.g. different scope)
+    pub fn append(&mut self, other: OptionsDiff<'a>) {
+        self.changes.extend(other.changes);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, OptionChange<'a>> {
+        self.changes.iter()
+    }
+
+    /// `set-option` commands for all changes
+    pub fn to_commands(&self) -> TmuxCommands<'a> {
+        let mut cmds = TmuxCommands::new();
+        for change in &self.changes {
+            cmds.push(change.to_command());
+        }
+        cmds
+    }
+
+    /// Apply all changes using a single tmux invocation, nothing is invoked if there are no
+    /// changes
+    pub fn apply(
+        &self,
+        invoker: &dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<(), Error> {
+        if !self.is_empty() {
+            invoker(TmuxCommand::with_cmds(self.to_commands()))?.into_result()?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for OptionsDiff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+// option name and array index to value, quoted values are unescaped (`show-options` output,
+// values containing escaped characters are always quoted)
+fn options_map<T: OptionsValuesTr>(options: &T) -> BTreeMap<(&'static str, Option<usize>), String> {
+    options
+        .values()
+        .into_iter()
+        .map(|(name, index, value)| {
+            let quoted = value.len() > 1
+                && ((value.starts_with('"') && value.ends_with('"'))
+                    || (value.starts_with('\'') && value.ends_with('\'')));
+            let value = match quoted {
+                true => option_value_unescape(&value),
+                false => value,
+            };
+            ((name, index), value)
+        })
+        .collect()
+}
@@ -0,0 +1,170 @@
+#[test]
+fn diff() {
+    use crate::{OptionChange, OptionScope, OptionsDiff, SessionOptions, Switch};
+
+    let current = SessionOptions {
+        base_index: Some(0),
+        history_limit: Some(2000),
+        set_titles: Some(Switch::Off),
+        status_left: Some("\"[#{session_name}] \"".into()),
+        ..Default::default()
+    };
+    let desired = SessionOptions {
+        base_index: Some(1),
+        set_titles: Some(Switch::Off),
+        status_left: Some("[#{session_name}] ".into()),
+        status_right: Some("%H:%M".into()),
+        ..Default::default()
+    };
+
+    let diff = OptionsDiff::diff(OptionScope::GlobalSession, &current, &desired);
+    assert_eq!(
+        diff.changes,
+        vec![
+            OptionChange {
+                scope: OptionScope::GlobalSession,
+                name: "base-index".to_string(),
+                old: Some("0".to_string()),
+                new: Some("1".to_string()),
+            },
+            OptionChange {
+                scope: OptionScope::GlobalSession,
+                name: "status-right".to_string(),
+                old: None,
+                new: Some("%H:%M".to_string()),
+            },
+        ]
+    );
+
+    // not managed options are unset
+    let diff = OptionsDiff::diff_all(OptionScope::Session(Some("0:".into())), &current, &desired);
+    let names: Vec<&str> = diff.iter().map(|change| change.name.as_str()).collect();
+    assert_eq!(names, vec!["base-index", "history-limit", "status-right"]);
+    assert_eq!(diff.changes[1].new, None);
+
+    let diff = OptionsDiff::diff(OptionScope::GlobalSession, &desired, &desired);
+    assert!(diff.is_empty());
+
+    // desired values normalized as the current ones (e.g. returned by the options ctl getters)
+    let diff = OptionsDiff::diff_all(OptionScope::GlobalSession, &current, &current);
+    assert!(diff.is_empty());
+    let desired = SessionOptions {
+        status_left: Some("\"[#S] \"".into()),
+        ..Default::default()
+    };
+    let diff = OptionsDiff::diff(OptionScope::GlobalSession, &current, &desired);
+    assert_eq!(diff.len(), 1);
+    assert_eq!(diff.changes[0].new.as_deref(), Some("[#S] "));
+
+    // raw values are not unescaped
+    let desired = SessionOptions {
+        status_left: Some("#[fg=red] \\ ;".into()),
+        ..Default::default()
+    };
+    let diff = OptionsDiff::diff(OptionScope::GlobalSession, &current, &desired);
+    assert_eq!(diff.changes[0].new.as_deref(), Some("#[fg=red] \\ ;"));
+}
+
+#[cfg(feature = "tmux_2_4")]
+#[test]
+fn diff_array() {
+    use crate::{OptionScope, OptionsDiff, ServerOptions};
+
+    let current = ServerOptions {
+        command_alias: Some(vec!["a=b".into(), "c=d".into(), "e=f".into()]),
+        ..Default::default()
+    };
+    let desired = ServerOptions {
+        command_alias: Some(vec!["a=b".into(), "x=y".into()]),
+        ..Default::default()
+    };
+
+    let diff = OptionsDiff::diff(OptionScope::Server, &current, &desired);
+    assert_eq!(
+        diff.to_string(),
+        "server command-alias[1]: \"c=d\" -> \"x=y\"\n\
+         server command-alias[2]: \"e=f\" -> (unset)\n"
+    );
+}
+
+#[cfg(feature = "tmux_2_9")]
+#[test]
+fn diff_session_array() {
+    use crate::{OptionScope, OptionsDiff, SessionOptions};
+
+    let current = SessionOptions {
+        status_format: Some(vec!["\"a\"".into(), "b".into()]),
+        update_environment: Some(vec!["DISPLAY".into()]),
+        ..Default::default()
+    };
+    let desired = SessionOptions {
+        status_format: Some(vec!["a".into()]),
+        update_environment: Some(vec!["DISPLAY".into(), "SSH_AUTH_SOCK".into()]),
+        ..Default::default()
+    };
+
+    let diff = OptionsDiff::diff(OptionScope::GlobalSession, &current, &desired);
+    assert_eq!(
+        diff.to_string(),
+        "global session status-format[1]: \"b\" -> (unset)\n\
+         global session update-environment[1]: (unset) -> \"SSH_AUTH_SOCK\"\n"
+    );
+}
+
+#[test]
+fn diff_new_line() {
+    use crate::{OptionScope, OptionsDiff, SessionOptions};
+
+    let current = SessionOptions {
+        status_left: Some("\"a\\nb\"".into()),
+        ..Default::default()
+    };
+    let desired = SessionOptions {
+        status_left: Some("a\nb".into()),
+        status_right: Some("c\nd".into()),
+        ..Default::default()
+    };
+
+    let diff = OptionsDiff::diff(OptionScope::GlobalSession, &current, &desired);
+    assert_eq!(diff.len(), 1);
+    assert_eq!(diff.changes[0].name, "status-right");
+    assert_eq!(diff.changes[0].new.as_deref(), Some("c\nd"));
+}
+
+#[test]
+fn to_commands() {
+    use crate::{OptionScope, OptionsDiff, WindowOptions};
+
+    let current = WindowOptions::default();
+    let desired = WindowOptions {
+        main_pane_width: Some(80),
+        ..Default::default()
+    };
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let cmd = "set-option";
+    #[cfg(feature = "cmd_alias")]
+    let cmd = "set";
+
+    let diff = OptionsDiff::diff(OptionScope::GlobalWindow, &current, &desired);
+    assert_eq!(
+        diff.to_commands().to_string(),
+        format!("{} -g -w main-pane-width 80", cmd)
+    );
+
+    let diff = OptionsDiff::diff_all(OptionScope::Window(Some("@1".into())), &desired, &current);
+    #[cfg(feature = "tmux_3_0")]
+    assert_eq!(
+        diff.to_commands().to_string(),
+        format!("{} -u -w -t @1 main-pane-width", cmd)
+    );
+}
+
+#[test]
+fn apply() {
+    use crate::{Error, OptionsDiff, TmuxCommand, TmuxOutput};
+
+    // nothing to apply, nothing invoked
+    let invoker = |_: TmuxCommand| -> Result<TmuxOutput, Error> { unreachable!() };
+    OptionsDiff::new().apply(&invoker).unwrap();
+}
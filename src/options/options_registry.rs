@@ -0,0 +1,216 @@
+use super::options_table::OPTIONS_TABLE;
+use crate::{Error, SetOption, Version};
+use std::fmt;
+
+const USER_OPTION_MARKER: char = '@';
+const ARRAY_INDEX_START: char = '[';
+const ARRAY_INDEX_END: char = ']';
+
+const FLAG_VALUES: &[&str] = &["on", "off", "yes", "no", "1", "0"];
+
+/// Object type the option belongs to (`-s`, ` `, `-w`, `-p` flags)
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum OptionKind {
+    Server,
+    Session,
+    Window,
+    Pane,
+}
+
+impl fmt::Display for OptionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Server => "server",
+            Self::Session => "session",
+            Self::Window => "window",
+            Self::Pane => "pane",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Option value type (ref: options-table.c -> `enum options_table_type`)
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum OptionType {
+    /// any string
+    String,
+    /// non-negative number
+    Number,
+    /// key name (e.g. `C-b`)
+    Key,
+    /// colour (e.g. `red`, `colour100`, `#ff0000`)
+    Colour,
+    /// `on` | `off` (or omitted to toggle)
+    Flag,
+    /// one of the listed values
+    Choice(&'static [&'static str]),
+    /// style (e.g. `bg=green,fg=black`)
+    Style,
+}
+
+/// Option metadata, entry of the [`OptionsRegistry`]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct OptionInfo {
+    /// option name (e.g. `status-left`)
+    pub name: &'static str,
+    pub kind: OptionKind,
+    pub value_type: OptionType,
+    /// array option (`name[0] value`, `name[1] value`, ...)
+    pub array: bool,
+    /// default value (as set by tmux), array options may have multiple or no items
+    pub default: &'static [&'static str],
+    /// tmux version (major, minor) the option was added in
+    pub added: (usize, usize),
+    /// tmux version (major, minor) the option was removed in
+    pub removed: Option<(usize, usize)>,
+}
+
+impl OptionInfo {
+    /// Option is supported by the given tmux version
+    pub fn is_available(&self, version: &Version) -> bool {
+        let version = (version.major, version.minor);
+        self.added <= version && !matches!(self.removed, Some(removed) if removed <= version)
+    }
+
+    /// Check the value for `set-option`, `None` toggles flag or choice option
+    ///
+    /// Only flag, number and choice values are checked, strings, keys, colours and styles may
+    /// contain formats and are accepted as is
+    pub fn validate_value(&self, value: Option<&str>) -> Result<(), Error> {
+        let valid = match (self.value_type, value) {
+            (OptionType::Flag, None) | (OptionType::Choice(_), None) => true,
+            (_, None) => false,
+            (OptionType::Flag, Some(value)) => FLAG_VALUES.contains(&value),
+            (OptionType::Number, Some(value)) => value.parse::<usize>().is_ok(),
+            (OptionType::Choice(choices), Some(value)) => choices.contains(&value),
+            _ => true,
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(Error::InvalidOption(format!(
+                "invalid value for option {}: {}",
+                self.name,
+                value.unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+/// Known tmux options with metadata (scope, value type, default value, supported versions)
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{OptionKind, OptionsRegistry, Version};
+///
+/// let version = Version {
+///     major: 3,
+///     minor: 3,
+///     ..Default::default()
+/// };
+///
+/// let option = OptionsRegistry::get("status-keys", &version).unwrap();
+/// assert_eq!(option.kind, OptionKind::Session);
+/// assert_eq!(option.default, &["emacs"]);
+///
+/// assert!(OptionsRegistry::validate("status-keys", Some("vi"), &version).is_ok());
+/// assert!(OptionsRegistry::validate("status-keys", Some("nano"), &version).is_err());
+/// assert!(OptionsRegistry::validate("status-utf8", Some("on"), &version).is_err());
+/// ```
+pub struct OptionsRegistry;
+
+impl OptionsRegistry {
+    /// All known options, including options removed in later tmux versions
+    pub fn all() -> &'static [OptionInfo] {
+        OPTIONS_TABLE
+    }
+
+    /// All entries with the given name (the same name may be used in different scopes in
+    /// different tmux versions, e.g. `remain-on-exit` window option became pane option)
+    pub fn find(name: &str) -> impl Iterator<Item = &'static OptionInfo> + '_ {
+        OPTIONS_TABLE
+            .iter()
+            .filter(move |option| option.name == name)
+    }
+
+    /// Option with the given name supported by the given tmux version, the array index
+    /// (e.g. `command-alias[1]`) is ignored
+    pub fn get(name: &str, version: &Version) -> Option<&'static OptionInfo> {
+        let (name, _) = split_index(name);
+        Self::find(name).find(|option| option.is_available(version))
+    }
+
+    /// Option with the given name as in the latest tmux version knowing it, the array index
+    /// is ignored
+    pub fn latest(name: &str) -> Option<&'static OptionInfo> {
+        let (name, _) = split_index(name);
+        Self::find(name).max_by_key(|option| option.added)
+    }
+
+    /// Options of the given kind supported by the given tmux version
+    pub fn available(
+        kind: OptionKind,
+        version: &Version,
+    ) -> impl Iterator<Item = &'static OptionInfo> + '_ {
+        OPTIONS_TABLE
+            .iter()
+            .filter(move |option| option.kind == kind && option.is_available(version))
+    }
+
+    /// Check the option name and value for `set-option`, user options (`@name`) are always
+    /// valid
+    pub fn validate(name: &str, value: Option<&str>, version: &Version) -> Result<(), Error> {
+        match Self::validate_name(name, version)? {
+            Some(option) => option.validate_value(value),
+            None => Ok(()),
+        }
+    }
+
+    /// Check the option name (and array index), returns `None` for user options (`@name`)
+    pub fn validate_name(
+        name: &str,
+        version: &Version,
+    ) -> Result<Option<&'static OptionInfo>, Error> {
+        if name.starts_with(USER_OPTION_MARKER) {
+            return Ok(None);
+        }
+        let (option_name, index) = split_index(name);
+        let option = Self::get(option_name, version).ok_or_else(|| {
+            Error::InvalidOption(format!(
+                "unknown option for tmux {}.{}: {}",
+                version.major, version.minor, option_name
+            ))
+        })?;
+        if index.is_some() && !option.array {
+            return Err(Error::InvalidOption(format!(
+                "not an array option: {}",
+                name
+            )));
+        }
+        Ok(Some(option))
+    }
+
+    /// Check the `set-option` command, unset (`-u`) options are checked by name only
+    pub fn validate_set_option(set_option: &SetOption, version: &Version) -> Result<(), Error> {
+        let name = set_option
+            .option
+            .as_deref()
+            .ok_or_else(|| Error::InvalidOption("option name is not set".to_string()))?;
+        #[cfg(feature = "tmux_0_8")]
+        if set_option.unset {
+            return Self::validate_name(name, version).map(|_| ());
+        }
+        Self::validate(name, set_option.value.as_deref(), version)
+    }
+}
+
+// option name and array index (`name[index]`)
+pub(crate) fn split_index(name: &str) -> (&str, Option<&str>) {
+    match name.strip_suffix(ARRAY_INDEX_END) {
+        Some(s) => match s.split_once(ARRAY_INDEX_START) {
+            Some((name, index)) => (name, Some(index)),
+            None => (name, None),
+        },
+        None => (name, None),
+    }
+}
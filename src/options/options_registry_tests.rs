@@ -0,0 +1,78 @@
+#[test]
+fn get() {
+    use crate::{OptionKind, OptionType, OptionsRegistry, Version};
+
+    let version = |major, minor| Version {
+        major,
+        minor,
+        ..Default::default()
+    };
+
+    let option = OptionsRegistry::get("base-index", &version(3, 3)).unwrap();
+    assert_eq!(option.kind, OptionKind::Session);
+    assert_eq!(option.value_type, OptionType::Number);
+    assert_eq!(option.default, &["0"]);
+    assert!(!option.array);
+
+    let option = OptionsRegistry::get("command-alias[1]", &version(3, 3)).unwrap();
+    assert_eq!(option.kind, OptionKind::Server);
+    assert!(option.array);
+    assert_eq!(option.default[1], "splitp=split-window");
+    assert!(OptionsRegistry::get("command-alias", &version(2, 3)).is_none());
+
+    // moved between scopes
+    let option = OptionsRegistry::get("remain-on-exit", &version(2, 9)).unwrap();
+    assert_eq!(option.kind, OptionKind::Window);
+    let option = OptionsRegistry::get("remain-on-exit", &version(3, 0)).unwrap();
+    assert_eq!(option.kind, OptionKind::Pane);
+    assert_eq!(OptionsRegistry::find("remain-on-exit").count(), 2);
+
+    // removed
+    assert!(OptionsRegistry::get("status-utf8", &version(2, 1)).is_some());
+    assert!(OptionsRegistry::get("status-utf8", &version(2, 2)).is_none());
+    assert!(OptionsRegistry::get("unknown-option", &version(3, 3)).is_none());
+
+    assert!(OptionsRegistry::available(OptionKind::Pane, &version(2, 9))
+        .next()
+        .is_none());
+    assert!(
+        OptionsRegistry::available(OptionKind::Server, &version(3, 3))
+            .all(|option| option.kind == OptionKind::Server && option.removed.is_none())
+    );
+}
+
+#[test]
+fn validate() {
+    use crate::{OptionsRegistry, SetOption, Version};
+
+    let version = Version {
+        major: 3,
+        minor: 3,
+        ..Default::default()
+    };
+
+    assert!(OptionsRegistry::validate("mouse", Some("on"), &version).is_ok());
+    assert!(OptionsRegistry::validate("mouse", None, &version).is_ok());
+    assert!(OptionsRegistry::validate("mouse", Some("maybe"), &version).is_err());
+    assert!(OptionsRegistry::validate("history-limit", Some("5000"), &version).is_ok());
+    assert!(OptionsRegistry::validate("history-limit", Some("-1"), &version).is_err());
+    assert!(OptionsRegistry::validate("history-limit", None, &version).is_err());
+    assert!(OptionsRegistry::validate("status", Some("2"), &version).is_ok());
+    assert!(OptionsRegistry::validate("status", Some("6"), &version).is_err());
+    assert!(
+        OptionsRegistry::validate("status-left", Some("#{?pane_in_mode,x,}"), &version).is_ok()
+    );
+    assert!(OptionsRegistry::validate("command-alias[10]", Some("a=b"), &version).is_ok());
+    assert!(OptionsRegistry::validate("base-index[0]", Some("1"), &version).is_err());
+    assert!(OptionsRegistry::validate("@user-option", Some("any"), &version).is_ok());
+    assert!(OptionsRegistry::validate("mode-mouse", Some("on"), &version).is_err());
+
+    let set_option = SetOption::new().option("base-index").value("1");
+    assert!(OptionsRegistry::validate_set_option(&set_option, &version).is_ok());
+    let set_option = SetOption::new().option("base-index").value("one");
+    assert!(OptionsRegistry::validate_set_option(&set_option, &version).is_err());
+    let set_option = SetOption::new().option("base-index").unset();
+    assert!(OptionsRegistry::validate_set_option(&set_option, &version).is_ok());
+    let set_option = SetOption::new().value("1");
+    assert!(OptionsRegistry::validate_set_option(&set_option, &version).is_err());
+}
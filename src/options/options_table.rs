@@ -0,0 +1,1665 @@
+// tmux options table (ref: options-table.c), default values as in the latest tmux version
+// supporting the option
+use super::options_registry::{OptionInfo, OptionKind, OptionType};
+
+const ACTION_CHOICES: &[&str] = &["none", "any", "current", "other"];
+const ACTIVITY_CHOICES: &[&str] = &["off", "on", "both"];
+const CLOCK_MODE_STYLE_CHOICES: &[&str] = &["12", "24"];
+const DESTROY_UNATTACHED_CHOICES: &[&str] = &["off", "on", "keep-last", "keep-group"];
+const DETACH_ON_DESTROY_CHOICES: &[&str] = &["off", "on", "no-detached"];
+const EXTENDED_KEYS_CHOICES: &[&str] = &["off", "on", "always"];
+const KEYS_CHOICES: &[&str] = &["emacs", "vi"];
+const MESSAGE_LINE_CHOICES: &[&str] = &["0", "1", "2", "3", "4"];
+const MODE_MOUSE_CHOICES: &[&str] = &["off", "on", "copy-mode"];
+const PANE_BORDER_STATUS_CHOICES: &[&str] = &["off", "top", "bottom"];
+const REMAIN_ON_EXIT_CHOICES: &[&str] = &["off", "on", "failed"];
+const SET_CLIPBOARD_CHOICES: &[&str] = &["off", "external", "on"];
+const STATUS_CHOICES: &[&str] = &["off", "on", "2", "3", "4", "5"];
+const STATUS_JUSTIFY_CHOICES: &[&str] = &["left", "centre", "right", "absolute-centre"];
+const STATUS_POSITION_CHOICES: &[&str] = &["top", "bottom"];
+const WINDOW_SIZE_CHOICES: &[&str] = &["largest", "smallest", "manual", "latest"];
+
+pub(crate) const OPTIONS_TABLE: &[OptionInfo] = &[
+    // server options
+    OptionInfo {
+        name: "backspace",
+        kind: OptionKind::Server,
+        value_type: OptionType::Key,
+        array: false,
+        default: &["C-?"],
+        added: (3, 1),
+        removed: None,
+    },
+    OptionInfo {
+        name: "buffer-limit",
+        kind: OptionKind::Server,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["50"],
+        added: (1, 5),
+        removed: None,
+    },
+    OptionInfo {
+        name: "command-alias",
+        kind: OptionKind::Server,
+        value_type: OptionType::String,
+        array: true,
+        default: &["split-pane=split-window", "splitp=split-window", "server-info=show-messages -JT", "info=show-messages -JT", "choose-window=choose-tree -w", "choose-session=choose-tree -s"],
+        added: (2, 4),
+        removed: None,
+    },
+    OptionInfo {
+        name: "default-terminal",
+        kind: OptionKind::Server,
+        value_type: OptionType::String,
+        array: false,
+        default: &["screen"],
+        added: (2, 1),
+        removed: None,
+    },
+    OptionInfo {
+        name: "copy-command",
+        kind: OptionKind::Server,
+        value_type: OptionType::String,
+        array: false,
+        default: &[""],
+        added: (3, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "escape-time",
+        kind: OptionKind::Server,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["500"],
+        added: (1, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "editor",
+        kind: OptionKind::Server,
+        value_type: OptionType::String,
+        array: false,
+        default: &["/usr/bin/vi"],
+        added: (3, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "exit-empty",
+        kind: OptionKind::Server,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (2, 7),
+        removed: None,
+    },
+    OptionInfo {
+        name: "exit-unattached",
+        kind: OptionKind::Server,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 4),
+        removed: None,
+    },
+    OptionInfo {
+        name: "extended-keys",
+        kind: OptionKind::Server,
+        value_type: OptionType::Choice(EXTENDED_KEYS_CHOICES),
+        array: false,
+        default: &["off"],
+        added: (3, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "focus-events",
+        kind: OptionKind::Server,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "history-file",
+        kind: OptionKind::Server,
+        value_type: OptionType::String,
+        array: false,
+        default: &[""],
+        added: (2, 1),
+        removed: None,
+    },
+    OptionInfo {
+        name: "message-limit",
+        kind: OptionKind::Server,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["1000"],
+        added: (2, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "prompt-history-limit",
+        kind: OptionKind::Server,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["100"],
+        added: (3, 3),
+        removed: None,
+    },
+    OptionInfo {
+        name: "set-clipboard",
+        kind: OptionKind::Server,
+        value_type: OptionType::Choice(SET_CLIPBOARD_CHOICES),
+        array: false,
+        default: &["external"],
+        added: (1, 5),
+        removed: None,
+    },
+    OptionInfo {
+        name: "terminal-features",
+        kind: OptionKind::Server,
+        value_type: OptionType::String,
+        array: true,
+        default: &["xterm*:clipboard:ccolour:cstyle:focus:title", "screen*:title"],
+        added: (3, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "terminal-overrides",
+        kind: OptionKind::Server,
+        value_type: OptionType::String,
+        array: true,
+        default: &[],
+        added: (2, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "user-keys",
+        kind: OptionKind::Server,
+        value_type: OptionType::String,
+        array: true,
+        default: &[],
+        added: (3, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "quiet",
+        kind: OptionKind::Server,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 2),
+        removed: Some((2, 0)),
+    },
+    OptionInfo {
+        name: "detach-on-destroy",
+        kind: OptionKind::Server,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (1, 3),
+        removed: Some((1, 4)),
+    },
+    // session options
+    OptionInfo {
+        name: "activity-action",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(ACTION_CHOICES),
+        array: false,
+        default: &["other"],
+        added: (2, 6),
+        removed: None,
+    },
+    OptionInfo {
+        name: "assume-paste-time",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["1"],
+        added: (1, 8),
+        removed: None,
+    },
+    OptionInfo {
+        name: "base-index",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["0"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "bell-action",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(ACTION_CHOICES),
+        array: false,
+        default: &["any"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "bell-on-alert",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 5),
+        removed: Some((2, 6)),
+    },
+    OptionInfo {
+        name: "buffer-limit",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["20"],
+        added: (1, 0),
+        removed: Some((1, 4)),
+    },
+    OptionInfo {
+        name: "default-command",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &[""],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "default-shell",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["/bin/bash"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "default-path",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &[""],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "default-terminal",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["screen"],
+        added: (1, 0),
+        removed: Some((2, 1)),
+    },
+    OptionInfo {
+        name: "default-size",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["80x24"],
+        added: (2, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "destroy-unattached",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(DESTROY_UNATTACHED_CHOICES),
+        array: false,
+        default: &["off"],
+        added: (1, 5),
+        removed: None,
+    },
+    OptionInfo {
+        name: "detach-on-destroy",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(DETACH_ON_DESTROY_CHOICES),
+        array: false,
+        default: &["on"],
+        added: (1, 4),
+        removed: None,
+    },
+    OptionInfo {
+        name: "display-panes-active-colour",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["red"],
+        added: (1, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "display-panes-colour",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["blue"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "display-panes-time",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["1000"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "display-time",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["750"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "history-limit",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["2000"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "key-table",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["root"],
+        added: (2, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "lock-after-time",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["0"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "lock-command",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["lock -np"],
+        added: (1, 1),
+        removed: None,
+    },
+    OptionInfo {
+        name: "lock-server",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (1, 1),
+        removed: Some((2, 1)),
+    },
+    OptionInfo {
+        name: "message-attr",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "message-bg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["yellow"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "message-command-attr",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "message-command-bg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["black"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "message-command-fg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["yellow"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "message-fg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["black"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "message-command-style",
+        kind: OptionKind::Session,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["bg=black,fg=yellow"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "message-line",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(MESSAGE_LINE_CHOICES),
+        array: false,
+        default: &["0"],
+        added: (3, 4),
+        removed: None,
+    },
+    OptionInfo {
+        name: "message-limit",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["20"],
+        added: (1, 2),
+        removed: Some((2, 0)),
+    },
+    OptionInfo {
+        name: "message-style",
+        kind: OptionKind::Session,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["bg=yellow,fg=black"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "mouse-resize-pane",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 5),
+        removed: Some((2, 1)),
+    },
+    OptionInfo {
+        name: "mouse-select-pane",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 5),
+        removed: Some((2, 1)),
+    },
+    OptionInfo {
+        name: "mouse-select-window",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 5),
+        removed: Some((2, 1)),
+    },
+    OptionInfo {
+        name: "mouse",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (2, 1),
+        removed: None,
+    },
+    OptionInfo {
+        name: "mouse-utf8",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 5),
+        removed: Some((2, 2)),
+    },
+    OptionInfo {
+        name: "pane-active-border-bg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 2),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "pane-active-border-fg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["green"],
+        added: (1, 2),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "pane-border-bg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 2),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "pane-border-fg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 2),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "pane-active-border-style",
+        kind: OptionKind::Session,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["fg=green"],
+        added: (1, 9),
+        removed: Some((2, 0)),
+    },
+    OptionInfo {
+        name: "pane-border-style",
+        kind: OptionKind::Session,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (1, 9),
+        removed: Some((2, 0)),
+    },
+    OptionInfo {
+        name: "prefix",
+        kind: OptionKind::Session,
+        value_type: OptionType::Key,
+        array: false,
+        default: &["C-b"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "prefix2",
+        kind: OptionKind::Session,
+        value_type: OptionType::Key,
+        array: false,
+        default: &["None"],
+        added: (1, 6),
+        removed: None,
+    },
+    OptionInfo {
+        name: "renumber-windows",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 7),
+        removed: None,
+    },
+    OptionInfo {
+        name: "repeat-time",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["500"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "set-remain-on-exit",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: Some((2, 4)),
+    },
+    OptionInfo {
+        name: "set-titles",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "set-titles-string",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["#S:#I:#W - \"#T\" #{session_alerts}"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "silence-action",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(ACTION_CHOICES),
+        array: false,
+        default: &["other"],
+        added: (2, 6),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(STATUS_CHOICES),
+        array: false,
+        default: &["on"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-attr",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-bg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["green"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-fg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["black"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-format",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: true,
+        default: &["#[align=left range=left #{status-left-style}]#[push-default]#{T;=/#{status-left-length}:status-left}#[pop-default]#[norange default]#[list=on align=#{status-justify}]#[list=left-marker]<#[list=right-marker]>#[list=on]#{W:#[range=window|#{window_index} #{window-status-style}#{?#{&&:#{window_last_flag},#{!=:#{window-status-last-style},default}}, #{window-status-last-style},}#{?#{&&:#{window_bell_flag},#{!=:#{window-status-bell-style},default}}, #{window-status-bell-style},#{?#{&&:#{||:#{window_activity_flag},#{window_silence_flag}},#{!=:#{window-status-activity-style},default}}, #{window-status-activity-style},}}]#[push-default]#{T:window-status-format}#[pop-default]#[norange default]#{?window_end_flag,,#{window-status-separator}},#[range=window|#{window_index} list=focus #{?#{!=:#{window-status-current-style},default},#{window-status-current-style},#{window-status-style}}#{?#{&&:#{window_last_flag},#{!=:#{window-status-last-style},default}}, #{window-status-last-style},}#{?#{&&:#{window_bell_flag},#{!=:#{window-status-bell-style},default}}, #{window-status-bell-style},#{?#{&&:#{||:#{window_activity_flag},#{window_silence_flag}},#{!=:#{window-status-activity-style},default}}, #{window-status-activity-style},}}]#[push-default]#{T:window-status-current-format}#[pop-default]#[norange list=on default]#{?window_end_flag,,#{window-status-separator}}}#[nolist align=right range=right #{status-right-style}]#[push-default]#{T;=/#{status-right-length}:status-right}#[pop-default]#[norange default]", "#[align=centre]#{P:#{?pane_active,#[reverse],}#{pane_index}[#{pane_width}x#{pane_height}]#[default] }"],
+        added: (2, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-interval",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["15"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-justify",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(STATUS_JUSTIFY_CHOICES),
+        array: false,
+        default: &["left"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-keys",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(KEYS_CHOICES),
+        array: false,
+        default: &["emacs"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-left",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["[#{session_name}] "],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-left-attr",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-left-bg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-left-fg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-left-length",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["10"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-left-style",
+        kind: OptionKind::Session,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-position",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(STATUS_POSITION_CHOICES),
+        array: false,
+        default: &["bottom"],
+        added: (1, 7),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-right",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["#{?window_bigger,[#{window_offset_x}#,#{window_offset_y}] ,}\"#{=21:pane_title}\" %H:%M %d-%b-%y"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-right-attr",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-right-bg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-right-fg",
+        kind: OptionKind::Session,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "status-right-length",
+        kind: OptionKind::Session,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["40"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-right-style",
+        kind: OptionKind::Session,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-style",
+        kind: OptionKind::Session,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["bg=green,fg=black"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "status-utf8",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: Some((2, 2)),
+    },
+    OptionInfo {
+        name: "terminal-overrides",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["xterm*:XT:Ms=\\E]52;%p1%s;%p2%s\\007:Cs=\\E]12;%p1%s\\007:Cr=\\E]112\\007:Ss=\\E[%p1%d q:Se=\\E[2 q,screen*:XT"],
+        added: (1, 0),
+        removed: Some((2, 0)),
+    },
+    OptionInfo {
+        name: "update-environment",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: true,
+        default: &["DISPLAY", "KRB5CCNAME", "SSH_ASKPASS", "SSH_AUTH_SOCK", "SSH_AGENT_PID", "SSH_CONNECTION", "WINDOWID", "XAUTHORITY"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "user-keys",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: true,
+        default: &[],
+        added: (2, 6),
+        removed: Some((3, 0)),
+    },
+    OptionInfo {
+        name: "visual-activity",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(ACTIVITY_CHOICES),
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "visual-bell",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(ACTIVITY_CHOICES),
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "visual-content",
+        kind: OptionKind::Session,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: Some((2, 0)),
+    },
+    OptionInfo {
+        name: "visual-silence",
+        kind: OptionKind::Session,
+        value_type: OptionType::Choice(ACTIVITY_CHOICES),
+        array: false,
+        default: &["off"],
+        added: (1, 4),
+        removed: None,
+    },
+    OptionInfo {
+        name: "word-separators",
+        kind: OptionKind::Session,
+        value_type: OptionType::String,
+        array: false,
+        default: &["!\"#$%&'()*+,-./:;<=>?@[\\]^`{|}~"],
+        added: (1, 6),
+        removed: None,
+    },
+    // window options
+    OptionInfo {
+        name: "aggressive-resize",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "allow-rename",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 6),
+        removed: Some((3, 0)),
+    },
+    OptionInfo {
+        name: "alternate-screen",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (1, 2),
+        removed: Some((3, 0)),
+    },
+    OptionInfo {
+        name: "automatic-rename",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "automatic-rename-format",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["#{?pane_in_mode,[tmux],#{pane_current_command}}#{?pane_dead,[dead],}"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "c0-change-interval",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["100"],
+        added: (1, 7),
+        removed: Some((2, 1)),
+    },
+    OptionInfo {
+        name: "c0-change-trigger",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["250"],
+        added: (1, 7),
+        removed: Some((2, 1)),
+    },
+    OptionInfo {
+        name: "clock-mode-colour",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["blue"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "clock-mode-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Choice(CLOCK_MODE_STYLE_CHOICES),
+        array: false,
+        default: &["24"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "force-height",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["0"],
+        added: (1, 0),
+        removed: Some((2, 9)),
+    },
+    OptionInfo {
+        name: "force-width",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["0"],
+        added: (1, 0),
+        removed: Some((2, 9)),
+    },
+    OptionInfo {
+        name: "layout-history-limit",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["20"],
+        added: (1, 7),
+        removed: Some((1, 8)),
+    },
+    OptionInfo {
+        name: "main-pane-height",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["24"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "main-pane-width",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["80"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "mode-attr",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "mode-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["yellow"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "mode-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["black"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "mode-keys",
+        kind: OptionKind::Window,
+        value_type: OptionType::Choice(KEYS_CHOICES),
+        array: false,
+        default: &["emacs"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "mode-mouse",
+        kind: OptionKind::Window,
+        value_type: OptionType::Choice(MODE_MOUSE_CHOICES),
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: Some((2, 1)),
+    },
+    OptionInfo {
+        name: "mode-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["bg=yellow,fg=black"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "monitor-activity",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "monitor-content",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &[""],
+        added: (1, 0),
+        removed: Some((2, 0)),
+    },
+    OptionInfo {
+        name: "monitor-bell",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (2, 6),
+        removed: None,
+    },
+    OptionInfo {
+        name: "monitor-silence",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["0"],
+        added: (1, 4),
+        removed: None,
+    },
+    OptionInfo {
+        name: "other-pane-height",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["0"],
+        added: (1, 4),
+        removed: None,
+    },
+    OptionInfo {
+        name: "other-pane-width",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["0"],
+        added: (1, 4),
+        removed: None,
+    },
+    OptionInfo {
+        name: "pane-active-border-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["#{?pane_in_mode,fg=yellow,#{?synchronize-panes,fg=red,fg=green}}"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "pane-active-border-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (0, 8),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "pane-active-border-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["green"],
+        added: (0, 8),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "pane-base-index",
+        kind: OptionKind::Window,
+        value_type: OptionType::Number,
+        array: false,
+        default: &["0"],
+        added: (1, 6),
+        removed: None,
+    },
+    OptionInfo {
+        name: "pane-border-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (0, 8),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "pane-border-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (0, 8),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "pane-border-format",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["#{?pane_active,#[reverse],}#{pane_index}#[default] \"#{pane_title}\""],
+        added: (2, 3),
+        removed: None,
+    },
+    OptionInfo {
+        name: "pane-border-status",
+        kind: OptionKind::Window,
+        value_type: OptionType::Choice(PANE_BORDER_STATUS_CHOICES),
+        array: false,
+        default: &["off"],
+        added: (2, 3),
+        removed: None,
+    },
+    OptionInfo {
+        name: "pane-border-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (2, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "remain-on-exit",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: Some((3, 0)),
+    },
+    OptionInfo {
+        name: "synchronize-panes",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 2),
+        removed: Some((3, 2)),
+    },
+    OptionInfo {
+        name: "utf8",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (1, 0),
+        removed: Some((2, 2)),
+    },
+    OptionInfo {
+        name: "window-active-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (2, 1),
+        removed: Some((3, 0)),
+    },
+    OptionInfo {
+        name: "window-status-bell-attr",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["reverse"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-bell-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-bell-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-content-attr",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["reverse"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-content-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-content-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-activity-attr",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["reverse"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-activity-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-activity-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 6),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-attr",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-current-attr",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-current-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-current-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 0),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-alert-attr",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["reverse"],
+        added: (1, 3),
+        removed: Some((1, 6)),
+    },
+    OptionInfo {
+        name: "window-status-alert-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 3),
+        removed: Some((1, 6)),
+    },
+    OptionInfo {
+        name: "window-status-alert-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 3),
+        removed: Some((1, 6)),
+    },
+    OptionInfo {
+        name: "window-status-activity-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["reverse"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-status-bell-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["reverse"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-status-content-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["reverse"],
+        added: (1, 9),
+        removed: Some((2, 0)),
+    },
+    OptionInfo {
+        name: "window-status-current-format",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["#I:#W#{?window_flags,#{window_flags}, }"],
+        added: (1, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-status-last-attr",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["none"],
+        added: (1, 8),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-last-bg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 8),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-last-fg",
+        kind: OptionKind::Window,
+        value_type: OptionType::Colour,
+        array: false,
+        default: &["default"],
+        added: (1, 8),
+        removed: Some((1, 9)),
+    },
+    OptionInfo {
+        name: "window-status-current-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-status-format",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &["#I:#W#{?window_flags,#{window_flags}, }"],
+        added: (1, 2),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-status-last-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-status-separator",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &[" "],
+        added: (1, 7),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-status-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (1, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-size",
+        kind: OptionKind::Window,
+        value_type: OptionType::Choice(WINDOW_SIZE_CHOICES),
+        array: false,
+        default: &["latest"],
+        added: (2, 9),
+        removed: None,
+    },
+    OptionInfo {
+        name: "word-separators",
+        kind: OptionKind::Window,
+        value_type: OptionType::String,
+        array: false,
+        default: &[" -_@"],
+        added: (1, 2),
+        removed: Some((1, 6)),
+    },
+    OptionInfo {
+        name: "window-style",
+        kind: OptionKind::Window,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (2, 1),
+        removed: Some((3, 0)),
+    },
+    OptionInfo {
+        name: "wrap-search",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (1, 7),
+        removed: None,
+    },
+    OptionInfo {
+        name: "xterm-keys",
+        kind: OptionKind::Window,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (1, 0),
+        removed: None,
+    },
+    // pane options
+    OptionInfo {
+        name: "allow-rename",
+        kind: OptionKind::Pane,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (3, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "alternate-screen",
+        kind: OptionKind::Pane,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["on"],
+        added: (3, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "remain-on-exit",
+        kind: OptionKind::Pane,
+        value_type: OptionType::Choice(REMAIN_ON_EXIT_CHOICES),
+        array: false,
+        default: &["off"],
+        added: (3, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-active-style",
+        kind: OptionKind::Pane,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (3, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "window-style",
+        kind: OptionKind::Pane,
+        value_type: OptionType::Style,
+        array: false,
+        default: &["default"],
+        added: (3, 0),
+        removed: None,
+    },
+    OptionInfo {
+        name: "synchronize-panes",
+        kind: OptionKind::Pane,
+        value_type: OptionType::Flag,
+        array: false,
+        default: &["off"],
+        added: (3, 2),
+        removed: None,
+    },
+];
@@ -0,0 +1,38 @@
+/// Common trait for accessing options structure fields by tmux option name, without the
+/// `name value` lines round trip (array options keep their indices, values may contain new
+/// lines or be empty)
+///
+// Implemented by:
+//
+//  * [`ServerOptions`]
+//  * [`SessionOptions`]
+//  * [`WindowOptions`]
+//  * [`PaneOptions`]
+//
+// user options (`@name`) are set, but not returned by `values()`
+pub trait OptionsValuesTr {
+    /// Name, array index (array options only) and value of all set options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmux_interface::{OptionsValuesTr, ServerOptions};
+    ///
+    /// # #[cfg(feature = "tmux_2_4")]
+    /// # {
+    /// let options = ServerOptions::default().command_alias(Some(vec!["a=b", "c=d"]));
+    /// assert_eq!(
+    ///     options.values(),
+    ///     vec![
+    ///         ("command-alias", Some(0), "a=b".to_string()),
+    ///         ("command-alias", Some(1), "c=d".to_string())
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    fn values(&self) -> Vec<(&'static str, Option<usize>, String)>;
+
+    /// Set option field by name (array item if index is given), value is parsed the same way
+    /// as the `show-options` output value, unknown options are ignored
+    fn set_value(&mut self, name: &str, index: Option<usize>, value: Option<&str>);
+}
@@ -38,3 +38,6 @@ impl FromStr for RemainOnExit {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(RemainOnExit);
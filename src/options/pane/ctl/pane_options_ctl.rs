@@ -1,3 +1,4 @@
+use crate::{ArrayOptionsCtl, OptionScope};
 use crate::{
     Error, GetPaneOption, GetPaneOptionTr, PaneOptions, RemainOnExit, SetPaneOption,
     SetPaneOptionTr, SetPaneOptions, SetPaneOptionsTr, ShowOptions, Switch, Tmux, TmuxCommand,
@@ -118,7 +119,7 @@ impl<'a> PaneOptionsCtl<'a> {
             None => cmd,
         };
         let cmd = cmd.build();
-        let output = invoke(cmd)?.to_string();
+        let output = invoke(cmd)?.into_result()?.to_string();
         PaneOptions::from_str(&output)
     }
 
@@ -198,6 +199,11 @@ impl<'a> PaneOptionsCtl<'a> {
         Ok(result)
     }
 
+    /// Options scope of this ctl (`set-option -p [-t target]`)
+    pub fn scope(&self) -> OptionScope<'a> {
+        OptionScope::Pane(self.target.clone())
+    }
+
     /// tmux ^3.0:
     /// ```text
     /// allow-rename [on | off]
@@ -352,3 +358,14 @@ impl<'a> PaneOptionsCtl<'a> {
         ))
     }
 }
+
+#[cfg(feature = "tmux_1_7")]
+impl<'a> ArrayOptionsCtl<'a> for PaneOptionsCtl<'a> {
+    fn array_invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn array_scope(&self) -> OptionScope<'a> {
+        self.scope()
+    }
+}
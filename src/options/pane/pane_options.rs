@@ -1,5 +1,6 @@
 use super::constants::*;
-use crate::options::common::{cow_parse, get_parts, option_to_string};
+use crate::options::common::{cow_parse, get_parts, option_value, option_values_to_string};
+use crate::options::OptionsValuesTr;
 use crate::{Error, Switch};
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -8,8 +9,11 @@ use std::str::FromStr;
 
 #[cfg(feature = "tmux_3_0")]
 use crate::RemainOnExit;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 // TODO: check types
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Default, Clone, Debug)]
 pub struct PaneOptions<'a> {
     /// tmux ^3.0:
@@ -71,24 +75,7 @@ impl<'a> PaneOptions<'a> {
 
 impl<'a> fmt::Display for PaneOptions<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut v = Vec::new();
-
-        #[cfg(feature = "tmux_3_0")]
-        option_to_string(&mut v, ALLOW_RENAME, &self.allow_rename);
-        #[cfg(feature = "tmux_3_0")]
-        option_to_string(&mut v, ALTERNATE_SCREEN, &self.alternate_screen);
-        #[cfg(feature = "tmux_3_0")]
-        option_to_string(&mut v, REMAIN_ON_EXIT, &self.remain_on_exit);
-        #[cfg(feature = "tmux_3_0")]
-        option_to_string(&mut v, WINDOW_ACTIVE_STYLE, &self.window_active_style);
-        #[cfg(feature = "tmux_3_0")]
-        option_to_string(&mut v, WINDOW_STYLE, &self.window_style);
-        #[cfg(feature = "tmux_3_2")]
-        option_to_string(&mut v, SYNCHRONIZE_PANES, &self.synchronize_panes);
-        // #[cfg(feature = "tmux_3_0")]
-        // option_to_string(&mut v, , &self.user_options);
-        let s = v.join("\n");
-        write!(f, "{}", s)
+        write!(f, "{}", option_values_to_string(&self.values()))
     }
 }
 
@@ -187,38 +174,55 @@ impl<'a> FromStr for PaneOptions<'a> {
         let mut pane_options = PaneOptions::default();
 
         for line in s.lines() {
-            if let Some((name, _, value)) = get_parts(line) {
-                match name {
-                    #[cfg(feature = "tmux_3_0")]
-                    ALLOW_RENAME => pane_options.allow_rename = value.and_then(|s| s.parse().ok()),
-                    #[cfg(feature = "tmux_3_0")]
-                    ALTERNATE_SCREEN => {
-                        pane_options.alternate_screen = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_3_0")]
-                    REMAIN_ON_EXIT => {
-                        pane_options.remain_on_exit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_3_0")]
-                    WINDOW_ACTIVE_STYLE => pane_options.window_active_style = cow_parse(value),
-                    #[cfg(feature = "tmux_3_0")]
-                    WINDOW_STYLE => pane_options.window_style = cow_parse(value),
-                    #[cfg(feature = "tmux_3_2")]
-                    SYNCHRONIZE_PANES => {
-                        pane_options.synchronize_panes = value.and_then(|s| s.parse().ok())
-                    }
-                    _ => {
-                        // if user option (@user_option value)
-                        if let Some(name) = name.strip_prefix('@') {
-                            pane_options
-                                .user_options
-                                .insert(name.to_string(), cow_parse(value));
-                        }
-                    }
-                }
+            if let Some((name, i, value)) = get_parts(line) {
+                pane_options.set_value(name, i, value);
             }
         }
 
         Ok(pane_options)
     }
 }
+
+impl<'a> OptionsValuesTr for PaneOptions<'a> {
+    fn values(&self) -> Vec<(&'static str, Option<usize>, String)> {
+        let mut v = Vec::new();
+        #[cfg(feature = "tmux_3_0")]
+        option_value(&mut v, ALLOW_RENAME, &self.allow_rename);
+        #[cfg(feature = "tmux_3_0")]
+        option_value(&mut v, ALTERNATE_SCREEN, &self.alternate_screen);
+        #[cfg(feature = "tmux_3_0")]
+        option_value(&mut v, REMAIN_ON_EXIT, &self.remain_on_exit);
+        #[cfg(feature = "tmux_3_0")]
+        option_value(&mut v, WINDOW_ACTIVE_STYLE, &self.window_active_style);
+        #[cfg(feature = "tmux_3_0")]
+        option_value(&mut v, WINDOW_STYLE, &self.window_style);
+        #[cfg(feature = "tmux_3_2")]
+        option_value(&mut v, SYNCHRONIZE_PANES, &self.synchronize_panes);
+        // #[cfg(feature = "tmux_3_0")]
+        // option_value(&mut v, , &self.user_options);
+        v
+    }
+
+    fn set_value(&mut self, name: &str, _i: Option<usize>, value: Option<&str>) {
+        match name {
+            #[cfg(feature = "tmux_3_0")]
+            ALLOW_RENAME => self.allow_rename = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_3_0")]
+            ALTERNATE_SCREEN => self.alternate_screen = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_3_0")]
+            REMAIN_ON_EXIT => self.remain_on_exit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_3_0")]
+            WINDOW_ACTIVE_STYLE => self.window_active_style = cow_parse(value),
+            #[cfg(feature = "tmux_3_0")]
+            WINDOW_STYLE => self.window_style = cow_parse(value),
+            #[cfg(feature = "tmux_3_2")]
+            SYNCHRONIZE_PANES => self.synchronize_panes = value.and_then(|s| s.parse().ok()),
+            _ => {
+                // if user option (@user_option value)
+                if let Some(name) = name.strip_prefix('@') {
+                    self.user_options.insert(name.to_string(), cow_parse(value));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::options::common::option_value_unescape;
+use crate::{Error, OptionScope, TmuxCommand, TmuxOutput};
+use std::fmt;
+
+const SEPARATOR: char = ' ';
+const INHERITED_MARKER: char = '*';
+const ARRAY_INDEX_START: char = '[';
+
+/// Effective option value and the level of the inheritance chain it is set on, see
+/// [`OptionsCtl::resolve`](crate::OptionsCtl::resolve)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ResolvedOption<'a> {
+    /// option name (array options with or without index, e.g. `command-alias[1]`)
+    pub name: String,
+    /// effective value (unquoted), array items separated by newline, `None` if not set
+    pub value: Option<String>,
+    /// level the value is set on (e.g. `Window(Some("@1"))` for pane inheriting window
+    /// option), `None` if not set on any level
+    pub source: Option<OptionScope<'a>>,
+}
+
+impl<'a> ResolvedOption<'a> {
+    /// Resolve the option through the given levels (most specific first, e.g. pane, window,
+    /// global window)
+    ///
+    /// tmux ^3.0 reports inherited values (`show-options -A`, marked with `*`), so the
+    /// effective value is known from the first level, older versions are emulated by
+    /// querying each level until the option is found
+    pub fn resolve(
+        invoker: &dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+        name: &str,
+        chain: Vec<OptionScope<'a>>,
+    ) -> Result<Self, Error> {
+        let mut resolved = Self {
+            name: name.to_string(),
+            value: None,
+            source: None,
+        };
+
+        for scope in chain {
+            let cmd = scope.show_options().quiet().option(name.to_string());
+            #[cfg(feature = "tmux_3_0")]
+            let cmd = cmd.include_inherited();
+            let output = invoker(cmd.build())?.into_result()?.to_string();
+
+            let (value, inherited) = match parse_option(&output, name) {
+                Some(option) => option,
+                None => continue,
+            };
+            resolved.value.get_or_insert(value);
+            if !inherited {
+                resolved.source = Some(scope);
+                break;
+            }
+        }
+
+        Ok(resolved)
+    }
+}
+
+impl<'a> fmt::Display for ResolvedOption<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.value, &self.source) {
+            (Some(value), Some(source)) => write!(f, "{} {:?} ({})", self.name, value, source),
+            (Some(value), None) => write!(f, "{} {:?} (inherited)", self.name, value),
+            _ => write!(f, "{} (unset)", self.name),
+        }
+    }
+}
+
+// option value (array items joined) and inherited marker from `show-options` output
+// (`name value`, `name* value`, `name[0]* value`)
+fn parse_option(output: &str, name: &str) -> Option<(String, bool)> {
+    let mut values = Vec::new();
+    let mut inherited = false;
+    for line in output.lines() {
+        let (key, value) = match line.split_once(SEPARATOR) {
+            Some((key, value)) => (key, value),
+            None => (line, ""),
+        };
+        let (key, marker) = match key.strip_suffix(INHERITED_MARKER) {
+            Some(key) => (key, true),
+            None => (key, false),
+        };
+        let matches = key == name
+            || matches!(key.strip_prefix(name), Some(index) if index.starts_with(ARRAY_INDEX_START));
+        if matches {
+            inherited |= marker;
+            values.push(option_value_unescape(value));
+        }
+    }
+    match values.is_empty() {
+        true => None,
+        false => Some((values.join("\n"), inherited)),
+    }
+}
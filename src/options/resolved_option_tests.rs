@@ -0,0 +1,48 @@
+#[cfg(unix)]
+#[test]
+fn resolve() {
+    use crate::{mock_output, MockInvoker, OptionScope, ResolvedOption};
+
+    let mock = MockInvoker::new();
+    // pane inherits from window, window option set
+    let invoker = mock.invoker(|cmd| {
+        let flags: Vec<&str> = cmd.split(' ').collect();
+        if flags.contains(&"-g") {
+            mock_output(0, "mode-keys emacs\n")
+        } else if flags.contains(&"-w") {
+            mock_output(0, "mode-keys vi\n")
+        } else if flags.contains(&"-A") {
+            mock_output(0, "mode-keys* vi\n")
+        } else {
+            mock_output(0, "")
+        }
+    });
+    let chain = vec![
+        #[cfg(feature = "tmux_3_1")]
+        OptionScope::Pane(Some("%1".into())),
+        OptionScope::Window(Some("%1".into())),
+        OptionScope::GlobalWindow,
+    ];
+    let resolved = ResolvedOption::resolve(&invoker, "mode-keys", chain).unwrap();
+    assert_eq!(resolved.value, Some("vi".to_string()));
+    assert_eq!(
+        resolved.source,
+        Some(OptionScope::Window(Some("%1".into())))
+    );
+    assert_eq!(resolved.to_string(), "mode-keys \"vi\" (window %1)");
+
+    // array option, quoted items
+    let invoker =
+        mock.invoker(|_| mock_output(0, "command-alias[0] a=b\ncommand-alias[1] \"c=d e\"\n"));
+    let resolved =
+        ResolvedOption::resolve(&invoker, "command-alias", vec![OptionScope::Server]).unwrap();
+    assert_eq!(resolved.value, Some("a=b\nc=d e".to_string()));
+    assert_eq!(resolved.source, Some(OptionScope::Server));
+
+    // not set
+    let invoker = mock.invoker(|_| mock_output(0, ""));
+    let resolved =
+        ResolvedOption::resolve(&invoker, "@user", vec![OptionScope::GlobalSession]).unwrap();
+    assert_eq!(resolved.value, None);
+    assert_eq!(resolved.to_string(), "@user (unset)");
+}
@@ -39,3 +39,6 @@ impl FromStr for ExtendedKeys {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(ExtendedKeys);
@@ -40,3 +40,6 @@ impl FromStr for SetClipboard {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(SetClipboard);
@@ -1,3 +1,6 @@
+#[cfg(feature = "tmux_1_7")]
+use crate::ArrayOptionsCtl;
+use crate::OptionScope;
 use crate::{
     Error, GetServerOptionTr, GetServerOptionValue, GetUserOption, ServerOptions, SetClipboard,
     SetServerOption, SetServerOptionTr, SetServerOptions, SetServerOptionsTr, SetUserOption,
@@ -77,7 +80,7 @@ impl<'a> ServerOptionsCtl<'a> {
 
     pub fn get_all(&self) -> Result<ServerOptions<'a>, Error> {
         let cmd = ShowOptions::new().server().build();
-        let output = (self.invoker)(cmd)?.to_string();
+        let output = (self.invoker)(cmd)?.into_result()?.to_string();
         ServerOptions::from_str(&output)
     }
 
@@ -218,6 +221,22 @@ impl<'a> ServerOptionsCtl<'a> {
     //
     // output
     // }
+
+    /// Options scope of this ctl (`set-option -s`)
+    pub fn scope(&self) -> OptionScope<'a> {
+        OptionScope::Server
+    }
+}
+
+#[cfg(feature = "tmux_1_7")]
+impl<'a> ArrayOptionsCtl<'a> for ServerOptionsCtl<'a> {
+    fn array_invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn array_scope(&self) -> OptionScope<'a> {
+        self.scope()
+    }
 }
 
 impl<'a> ServerOptionsCtl<'a> {
@@ -2,9 +2,12 @@
 //use super::create_insert_vec;
 use super::*;
 use crate::options::common::{
-    array_insert, cow_parse, get_parts, option_array_to_string, option_to_string,
+    array_insert, cow_parse, get_parts, option_array_value, option_value, option_values_to_string,
 };
+use crate::options::OptionsValuesTr;
 use crate::{Error, Switch};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
@@ -20,6 +23,7 @@ use std::str::FromStr;
 // TODO: Vec variables solution for arrays
 // TODO: check types
 // TODO: command_alias and terminal_overrides both as String and as Vec<String> see tmux versions
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Default, Clone, Debug)]
 pub struct ServerOptions<'a> {
     /// `backspace key`
@@ -92,45 +96,7 @@ pub struct ServerOptions<'a> {
 
 impl<'a> fmt::Display for ServerOptions<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut v = Vec::new();
-        #[cfg(feature = "tmux_3_1")]
-        option_to_string(&mut v, BACKSPACE, &self.backspace);
-        #[cfg(feature = "tmux_1_5")]
-        option_to_string(&mut v, BUFFER_LIMIT, &self.buffer_limit);
-        #[cfg(feature = "tmux_2_4")]
-        option_array_to_string(&mut v, COMMAND_ALIAS, &self.command_alias);
-        #[cfg(feature = "tmux_2_1")]
-        option_to_string(&mut v, DEFAULT_TERMINAL, &self.default_terminal);
-        #[cfg(feature = "tmux_3_2")]
-        option_to_string(&mut v, COPY_COMMAND, &self.copy_command);
-        #[cfg(feature = "tmux_1_2")]
-        option_to_string(&mut v, ESCAPE_TIME, &self.escape_time);
-        #[cfg(feature = "tmux_2_7")]
-        option_to_string(&mut v, EXIT_EMPTY, &self.exit_empty);
-        #[cfg(feature = "tmux_1_4")]
-        option_to_string(&mut v, EXIT_UNATTACHED, &self.exit_unattached);
-        #[cfg(feature = "tmux_3_2")]
-        option_to_string(&mut v, EXTENDED_KEYS, &self.extended_keys);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(&mut v, FOCUS_EVENTS, &self.focus_events);
-        #[cfg(feature = "tmux_2_1")]
-        option_to_string(&mut v, HISTORY_FILE, &self.history_file);
-        #[cfg(feature = "tmux_2_0")]
-        option_to_string(&mut v, MESSAGE_LIMIT, &self.message_limit);
-        #[cfg(feature = "tmux_1_5")]
-        option_to_string(&mut v, SET_CLIPBOARD, &self.set_clipboard);
-        #[cfg(feature = "tmux_3_2")]
-        option_array_to_string(&mut v, TERMINAL_FEATURES, &self.terminal_features);
-        #[cfg(feature = "tmux_2_0")]
-        option_array_to_string(&mut v, TERMINAL_OVERRIDES, &self.terminal_overrides);
-        #[cfg(feature = "tmux_3_0")]
-        option_array_to_string(&mut v, USER_KEYS, &self.user_keys);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_0")))]
-        option_to_string(&mut v, QUIET, &self.quiet);
-        #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_4")))]
-        option_to_string(&mut v, DETACH_ON_DESTROY, &self.detach_on_destroy);
-        let s = v.join("\n");
-        write!(f, "{}", s)
+        write!(f, "{}", option_values_to_string(&self.values()))
     }
 }
 
@@ -454,91 +420,116 @@ impl<'a> FromStr for ServerOptions<'a> {
 
         for line in s.lines() {
             if let Some((name, i, value)) = get_parts(line) {
-                match name {
-                    #[cfg(feature = "tmux_3_1")]
-                    BACKSPACE => server_options.backspace = cow_parse(value),
-                    #[cfg(feature = "tmux_1_5")]
-                    BUFFER_LIMIT => {
-                        server_options.buffer_limit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_2_4")]
-                    COMMAND_ALIAS => array_insert(
-                        &mut server_options.command_alias,
-                        i,
-                        value.and_then(|s| s.parse().ok()),
-                    ),
-                    #[cfg(feature = "tmux_2_1")]
-                    DEFAULT_TERMINAL => server_options.default_terminal = cow_parse(value),
-                    #[cfg(feature = "tmux_3_2")]
-                    COPY_COMMAND => server_options.copy_command = cow_parse(value),
-                    #[cfg(feature = "tmux_1_2")]
-                    ESCAPE_TIME => server_options.escape_time = value.and_then(|s| s.parse().ok()),
-                    #[cfg(feature = "tmux_3_2")]
-                    EDITOR => server_options.editor = cow_parse(value),
-                    #[cfg(feature = "tmux_2_7")]
-                    EXIT_EMPTY => server_options.exit_empty = value.and_then(|s| s.parse().ok()),
-                    #[cfg(feature = "tmux_1_4")]
-                    EXIT_UNATTACHED => {
-                        server_options.exit_unattached = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_3_2")]
-                    EXTENDED_KEYS => {
-                        server_options.extended_keys = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    FOCUS_EVENTS => {
-                        server_options.focus_events = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_2_1")]
-                    HISTORY_FILE => server_options.history_file = cow_parse(value),
-                    #[cfg(feature = "tmux_2_0")]
-                    MESSAGE_LIMIT => {
-                        server_options.message_limit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_3_3")]
-                    PROMPT_HISTORY_LIMIT => {
-                        server_options.prompt_history_limit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_5")]
-                    SET_CLIPBOARD => {
-                        server_options.set_clipboard = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_3_2")]
-                    TERMINAL_FEATURES => array_insert(
-                        &mut server_options.terminal_features,
-                        i,
-                        value.and_then(|s| s.parse().ok()),
-                    ),
-                    #[cfg(feature = "tmux_2_0")]
-                    TERMINAL_OVERRIDES => array_insert(
-                        &mut server_options.terminal_overrides,
-                        i,
-                        value.and_then(|s| s.parse().ok()),
-                    ),
-                    #[cfg(feature = "tmux_3_0")]
-                    USER_KEYS => array_insert(
-                        &mut server_options.user_keys,
-                        i,
-                        value.and_then(|s| s.parse().ok()),
-                    ),
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_0")))]
-                    QUIET => server_options.quiet = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_4")))]
-                    DETACH_ON_DESTROY => {
-                        server_options.detach_on_destroy = value.and_then(|s| s.parse().ok())
-                    }
-                    _ => {
-                        // if user option (@user_option value)
-                        if let Some(name) = name.strip_prefix('@') {
-                            server_options
-                                .user_options
-                                .insert(name.to_string(), cow_parse(value));
-                        }
-                    }
-                }
+                server_options.set_value(name, i, value);
             }
         }
 
         Ok(server_options)
     }
 }
+
+impl<'a> OptionsValuesTr for ServerOptions<'a> {
+    fn values(&self) -> Vec<(&'static str, Option<usize>, String)> {
+        let mut v = Vec::new();
+        #[cfg(feature = "tmux_3_1")]
+        option_value(&mut v, BACKSPACE, &self.backspace);
+        #[cfg(feature = "tmux_1_5")]
+        option_value(&mut v, BUFFER_LIMIT, &self.buffer_limit);
+        #[cfg(feature = "tmux_2_4")]
+        option_array_value(&mut v, COMMAND_ALIAS, &self.command_alias);
+        #[cfg(feature = "tmux_2_1")]
+        option_value(&mut v, DEFAULT_TERMINAL, &self.default_terminal);
+        #[cfg(feature = "tmux_3_2")]
+        option_value(&mut v, COPY_COMMAND, &self.copy_command);
+        #[cfg(feature = "tmux_1_2")]
+        option_value(&mut v, ESCAPE_TIME, &self.escape_time);
+        #[cfg(feature = "tmux_2_7")]
+        option_value(&mut v, EXIT_EMPTY, &self.exit_empty);
+        #[cfg(feature = "tmux_1_4")]
+        option_value(&mut v, EXIT_UNATTACHED, &self.exit_unattached);
+        #[cfg(feature = "tmux_3_2")]
+        option_value(&mut v, EXTENDED_KEYS, &self.extended_keys);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(&mut v, FOCUS_EVENTS, &self.focus_events);
+        #[cfg(feature = "tmux_2_1")]
+        option_value(&mut v, HISTORY_FILE, &self.history_file);
+        #[cfg(feature = "tmux_2_0")]
+        option_value(&mut v, MESSAGE_LIMIT, &self.message_limit);
+        #[cfg(feature = "tmux_1_5")]
+        option_value(&mut v, SET_CLIPBOARD, &self.set_clipboard);
+        #[cfg(feature = "tmux_3_2")]
+        option_array_value(&mut v, TERMINAL_FEATURES, &self.terminal_features);
+        #[cfg(feature = "tmux_2_0")]
+        option_array_value(&mut v, TERMINAL_OVERRIDES, &self.terminal_overrides);
+        #[cfg(feature = "tmux_3_0")]
+        option_array_value(&mut v, USER_KEYS, &self.user_keys);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_0")))]
+        option_value(&mut v, QUIET, &self.quiet);
+        #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_4")))]
+        option_value(&mut v, DETACH_ON_DESTROY, &self.detach_on_destroy);
+        v
+    }
+
+    fn set_value(&mut self, name: &str, i: Option<usize>, value: Option<&str>) {
+        match name {
+            #[cfg(feature = "tmux_3_1")]
+            BACKSPACE => self.backspace = cow_parse(value),
+            #[cfg(feature = "tmux_1_5")]
+            BUFFER_LIMIT => self.buffer_limit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_2_4")]
+            COMMAND_ALIAS => array_insert(
+                &mut self.command_alias,
+                i,
+                value.and_then(|s| s.parse().ok()),
+            ),
+            #[cfg(feature = "tmux_2_1")]
+            DEFAULT_TERMINAL => self.default_terminal = cow_parse(value),
+            #[cfg(feature = "tmux_3_2")]
+            COPY_COMMAND => self.copy_command = cow_parse(value),
+            #[cfg(feature = "tmux_1_2")]
+            ESCAPE_TIME => self.escape_time = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_3_2")]
+            EDITOR => self.editor = cow_parse(value),
+            #[cfg(feature = "tmux_2_7")]
+            EXIT_EMPTY => self.exit_empty = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_4")]
+            EXIT_UNATTACHED => self.exit_unattached = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_3_2")]
+            EXTENDED_KEYS => self.extended_keys = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_9")]
+            FOCUS_EVENTS => self.focus_events = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_2_1")]
+            HISTORY_FILE => self.history_file = cow_parse(value),
+            #[cfg(feature = "tmux_2_0")]
+            MESSAGE_LIMIT => self.message_limit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_3_3")]
+            PROMPT_HISTORY_LIMIT => self.prompt_history_limit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_5")]
+            SET_CLIPBOARD => self.set_clipboard = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_3_2")]
+            TERMINAL_FEATURES => array_insert(
+                &mut self.terminal_features,
+                i,
+                value.and_then(|s| s.parse().ok()),
+            ),
+            #[cfg(feature = "tmux_2_0")]
+            TERMINAL_OVERRIDES => array_insert(
+                &mut self.terminal_overrides,
+                i,
+                value.and_then(|s| s.parse().ok()),
+            ),
+            #[cfg(feature = "tmux_3_0")]
+            USER_KEYS => array_insert(&mut self.user_keys, i, value.and_then(|s| s.parse().ok())),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_0")))]
+            QUIET => self.quiet = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_4")))]
+            DETACH_ON_DESTROY => self.detach_on_destroy = value.and_then(|s| s.parse().ok()),
+            _ => {
+                // if user option (@user_option value)
+                if let Some(name) = name.strip_prefix('@') {
+                    self.user_options.insert(name.to_string(), cow_parse(value));
+                }
+            }
+        }
+    }
+}
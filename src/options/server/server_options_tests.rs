@@ -90,6 +90,18 @@ fn to_string() {
     assert_eq!(server_options.to_string(), "buffer-limit 50");
 }
 
+#[cfg(feature = "tmux_3_0")]
+#[test]
+fn from_str_sparse_array() {
+    use crate::ServerOptions;
+
+    let options: ServerOptions = "user-keys[3] \"x\"\nuser-keys[1] y\n".parse().unwrap();
+    assert_eq!(
+        options.user_keys,
+        Some(vec!["".into(), "y".into(), "".into(), "\"x\"".into()])
+    );
+}
+
 #[test]
 fn from_str() {
     use crate::{ServerOptions, SetClipboard, Switch};
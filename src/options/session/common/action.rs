@@ -44,3 +44,6 @@ impl FromStr for Action {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(Action);
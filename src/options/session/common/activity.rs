@@ -39,3 +39,6 @@ impl FromStr for Activity {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(Activity);
@@ -45,3 +45,6 @@ impl FromStr for DestroyUnattached {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(DestroyUnattached);
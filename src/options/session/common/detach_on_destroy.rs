@@ -38,3 +38,6 @@ impl fmt::Display for DetachOnDestroy {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(DetachOnDestroy);
@@ -41,3 +41,6 @@ impl FromStr for MessageLine {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(MessageLine);
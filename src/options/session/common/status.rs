@@ -57,3 +57,6 @@ impl fmt::Display for Status {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(Status);
@@ -36,3 +36,6 @@ impl fmt::Display for StatusJustify {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(StatusJustify);
@@ -33,3 +33,6 @@ impl fmt::Display for StatusPosition {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(StatusPosition);
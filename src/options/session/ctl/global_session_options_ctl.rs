@@ -2,6 +2,10 @@ use crate::options::{
     GetGlobalSessionOption, GetGlobalSessionOptionValue, SessionOptionsCtl, SetGlobalSessionOption,
     SetGlobalSessionOptions,
 };
+#[cfg(feature = "tmux_1_7")]
+use crate::ArrayOptionsCtl;
+#[cfg(feature = "tmux_1_2")]
+use crate::OptionScope;
 use crate::{Error, Tmux, TmuxCommand, TmuxOutput};
 use std::borrow::Cow;
 
@@ -49,4 +53,20 @@ impl<'a> SessionOptionsCtl<'a> for GlobalSessionOptionsCtl<'a> {
     fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
         self.invoker
     }
+
+    #[cfg(feature = "tmux_1_2")]
+    fn scope(&self) -> OptionScope<'a> {
+        OptionScope::GlobalSession
+    }
+}
+
+#[cfg(feature = "tmux_1_7")]
+impl<'a> ArrayOptionsCtl<'a> for GlobalSessionOptionsCtl<'a> {
+    fn array_invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn array_scope(&self) -> OptionScope<'a> {
+        SessionOptionsCtl::scope(self)
+    }
 }
@@ -2,6 +2,8 @@ use crate::options::{
     GetLocalSessionOption, GetLocalSessionOptionValue, SessionOptionsCtl, SetLocalSessionOption,
     SetLocalSessionOptions,
 };
+#[cfg(feature = "tmux_1_7")]
+use crate::{ArrayOptionsCtl, OptionScope};
 use crate::{Error, Tmux, TmuxCommand, TmuxOutput};
 use std::borrow::Cow;
 
@@ -70,3 +72,14 @@ impl<'a> SessionOptionsCtl<'a> for LocalSessionOptionsCtl<'a> {
         self.invoker
     }
 }
+
+#[cfg(feature = "tmux_1_7")]
+impl<'a> ArrayOptionsCtl<'a> for LocalSessionOptionsCtl<'a> {
+    fn array_invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn array_scope(&self) -> OptionScope<'a> {
+        SessionOptionsCtl::scope(self)
+    }
+}
@@ -1,6 +1,8 @@
 use crate::options::{GetSessionOptionTr, SessionOptions, SetSessionOptionTr, SetSessionOptionsTr};
 #[cfg(feature = "tmux_3_4")]
 use crate::MessageLine;
+#[cfg(feature = "tmux_1_2")]
+use crate::OptionScope;
 use crate::{
     Action, Activity, DestroyUnattached, DetachOnDestroy, Error, Status, StatusJustify, StatusKeys,
     StatusPosition, Switch, TmuxCommand, TmuxOutput,
@@ -40,7 +42,7 @@ pub trait SessionOptionsCtl<'a> {
         invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
     ) -> Result<SessionOptions<'a>, Error> {
         let cmd = Self::GetterAll::all(target);
-        let output = (invoker)(cmd)?.to_string();
+        let output = (invoker)(cmd)?.into_result()?.to_string();
         SessionOptions::from_str(&output)
     }
 
@@ -373,6 +375,13 @@ pub trait SessionOptionsCtl<'a> {
         (self.invoker())(cmd)
     }
 
+    /// Options scope of this ctl (`set-option`/`show-options` flags and target), local session
+    /// options of the target by default
+    #[cfg(feature = "tmux_1_2")]
+    fn scope(&self) -> OptionScope<'a> {
+        OptionScope::Session(self.target())
+    }
+
     /// ### Manual
     ///
     /// tmux ^2.6:
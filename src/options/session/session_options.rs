@@ -1,11 +1,16 @@
 use super::*;
-use crate::options::common::{array_insert, cow_parse, get_parts, option_to_string};
+use crate::options::common::{
+    array_insert, cow_parse, get_parts, option_array_value, option_value, option_values_to_string,
+};
+use crate::options::OptionsValuesTr;
 use crate::options::StatusKeys;
 use crate::Switch;
 use crate::{
     Action, Activity, DestroyUnattached, DetachOnDestroy, Error, Status, StatusJustify,
     StatusPosition,
 };
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
@@ -14,6 +19,7 @@ use std::str::FromStr;
 // TODO: Vec variables solution
 // TODO: check types
 // 45 Available session options are:
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Default, Clone, Debug)]
 pub struct SessionOptions<'a> {
     //activity-action [any | none | current | other]
@@ -340,184 +346,7 @@ impl<'a> SessionOptions<'a> {
 
 impl<'a> fmt::Display for SessionOptions<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut v = Vec::new();
-
-        #[cfg(feature = "tmux_2_6")]
-        option_to_string(&mut v, ACTIVITY_ACTION, &self.activity_action);
-        #[cfg(feature = "tmux_1_8")]
-        option_to_string(&mut v, ASSUME_PASTE_TIME, &self.assume_paste_time);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, BASE_INDEX, &self.base_index);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, BELL_ACTION, &self.bell_action);
-        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_6")))]
-        option_to_string(&mut v, BELL_ON_ALERT, &self.bell_on_alert);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_4")))]
-        option_to_string(&mut v, BUFFER_LIMIT, &self.buffer_limit);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, DEFAULT_COMMAND, &self.default_command);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, DEFAULT_SHELL, &self.default_shell);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, DEFAULT_PATH, &self.default_path);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
-        option_to_string(&mut v, DEFAULT_TERMINAL, &self.default_terminal);
-        // #[cfg(feature = "tmux_2_9")]
-        // option_to_string(&mut v, DEFAULT_SIZE, &self.default_size);
-        #[cfg(feature = "tmux_1_5")]
-        option_to_string(&mut v, DESTROY_UNATTACHED, &self.destroy_unattached);
-        #[cfg(feature = "tmux_1_4")]
-        option_to_string(&mut v, DETACH_ON_DESTROY, &self.detach_on_destroy);
-        #[cfg(feature = "tmux_1_2")]
-        option_to_string(
-            &mut v,
-            DISPLAY_PANES_ACTIVE_COLOUR,
-            &self.display_panes_active_colour,
-        );
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, DISPLAY_PANES_COLOUR, &self.display_panes_colour);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, DISPLAY_PANES_TIME, &self.display_panes_time);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, DISPLAY_TIME, &self.display_time);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, HISTORY_LIMIT, &self.history_limit);
-        #[cfg(feature = "tmux_2_2")]
-        option_to_string(&mut v, KEY_TABLE, &self.key_table);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, LOCK_AFTER_TIME, &self.lock_after_time);
-        #[cfg(feature = "tmux_1_1")]
-        option_to_string(&mut v, LOCK_COMMAND, &self.lock_command);
-        #[cfg(all(feature = "tmux_1_1", not(feature = "tmux_2_1")))]
-        option_to_string(&mut v, LOCK_SERVER, &self.lock_server);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MESSAGE_ATTR, &self.message_attr);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MESSAGE_BG, &self.message_bg);
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MESSAGE_COMMAND_ATTR, &self.message_command_attr);
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MESSAGE_COMMAND_BG, &self.message_command_bg);
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MESSAGE_COMMAND_FG, &self.message_command_fg);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MESSAGE_FG, &self.message_fg);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(&mut v, MESSAGE_COMMAND_STYLE, &self.message_command_style);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_0")))]
-        option_to_string(&mut v, MESSAGE_LIMIT, &self.message_limit);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(&mut v, MESSAGE_STYLE, &self.message_style);
-        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
-        option_to_string(&mut v, MOUSE_RESIZE_PANE, &self.mouse_resize_pane);
-        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
-        option_to_string(&mut v, MOUSE_SELECT_PANE, &self.mouse_select_pane);
-        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
-        option_to_string(&mut v, MOUSE_SELECT_WINDOW, &self.mouse_select_window);
-        #[cfg(feature = "tmux_2_1")]
-        option_to_string(&mut v, MOUSE, &self.mouse);
-        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_2")))]
-        option_to_string(&mut v, MOUSE_UTF8, &self.mouse_utf8);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, PANE_ACTIVE_BORDER_BG, &self.pane_active_border_bg);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, PANE_ACTIVE_BORDER_FG, &self.pane_active_border_fg);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, PANE_BORDER_BG, &self.pane_border_bg);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, PANE_BORDER_FG, &self.pane_border_fg);
-        #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
-        option_to_string(
-            &mut v,
-            PANE_ACTIVE_BORDER_STYLE,
-            &self.pane_active_border_style,
-        );
-        #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
-        option_to_string(&mut v, PANE_BORDER_STYLE, &self.pane_border_style);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, PREFIX, &self.prefix);
-        #[cfg(feature = "tmux_1_6")]
-        option_to_string(&mut v, PREFIX2, &self.prefix2);
-        #[cfg(feature = "tmux_1_7")]
-        option_to_string(&mut v, RENUMBER_WINDOWS, &self.renumber_windows);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, REPEAT_TIME, &self.repeat_time);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_4")))]
-        option_to_string(&mut v, SET_REMAIN_ON_EXIT, &self.set_remain_on_exit);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, SET_TITLES, &self.set_titles);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, SET_TITLES_STRING, &self.set_titles_string);
-        #[cfg(feature = "tmux_2_6")]
-        option_to_string(&mut v, SILENCE_ACTION, &self.silence_action);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, STATUS, &self.status);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_ATTR, &self.status_attr);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_BG, &self.status_bg);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_FG, &self.status_fg);
-        // #[cfg(feature = "tmux_2_9")]
-        // option_to_string(&mut v, STATUS_FORMAT, &self.status_format);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, STATUS_INTERVAL, &self.status_interval);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, STATUS_JUSTIFY, &self.status_justify);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, STATUS_KEYS, &self.status_keys);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, STATUS_LEFT, &self.status_left);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_LEFT_ATTR, &self.status_left_attr);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_LEFT_BG, &self.status_left_bg);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_LEFT_FG, &self.status_left_fg);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, STATUS_LEFT_LENGTH, &self.status_left_length);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(&mut v, STATUS_LEFT_STYLE, &self.status_left_style);
-        #[cfg(feature = "tmux_1_7")]
-        option_to_string(&mut v, STATUS_POSITION, &self.status_position);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, STATUS_RIGHT, &self.status_right);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_RIGHT_ATTR, &self.status_right_attr);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_RIGHT_BG, &self.status_right_bg);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, STATUS_RIGHT_FG, &self.status_right_fg);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, STATUS_RIGHT_LENGTH, &self.status_right_length);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(&mut v, STATUS_RIGHT_STYLE, &self.status_right_style);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(&mut v, STATUS_STYLE, &self.status_style);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_2")))]
-        option_to_string(&mut v, STATUS_UTF8, &self.status_utf8);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
-        option_to_string(&mut v, TERMINAL_OVERRIDES, &self.terminal_overrides);
-        // #[cfg(feature = "tmux_1_0")]
-        // option_to_string(&mut v, UPDATE_ENVIRONMENT, &self.update_environment);
-        // #[cfg(all(feature = "tmux_2_6", not(feature = "tmux_3_0")))]
-        // option_to_string(&mut v, USER_KEYS, &self.user_keys);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, VISUAL_ACTIVITY, &self.visual_activity);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, VISUAL_BELL, &self.visual_bell);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
-        option_to_string(&mut v, VISUAL_CONTENT, &self.visual_content);
-        #[cfg(feature = "tmux_1_4")]
-        option_to_string(&mut v, VISUAL_SILENCE, &self.visual_silence);
-        #[cfg(feature = "tmux_1_6")]
-        option_to_string(&mut v, WORD_SEPARATORS, &self.word_separators);
-
-        // option_to_string(&mut v, , &self.user_options);
-
-        // option_to_string(&mut v, USER_OPTIONS, &self.user_options);
-        let s = v.join("\n");
-        write!(f, "{}", s)
+        write!(f, "{}", option_values_to_string(&self.values()))
     }
 }
 
@@ -1244,255 +1073,374 @@ impl<'a> FromStr for SessionOptions<'a> {
 
         for line in s.lines() {
             if let Some((name, i, value)) = get_parts(line) {
-                match name {
-                    #[cfg(feature = "tmux_2_6")]
-                    ACTIVITY_ACTION => {
-                        session_options.activity_action = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_8")]
-                    ASSUME_PASTE_TIME => {
-                        session_options.assume_paste_time = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    BASE_INDEX => session_options.base_index = value.and_then(|s| s.parse().ok()),
-                    #[cfg(feature = "tmux_1_0")]
-                    BELL_ACTION => session_options.bell_action = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_6")))]
-                    BELL_ON_ALERT => {
-                        session_options.bell_on_alert = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_4")))]
-                    BUFFER_LIMIT => {
-                        session_options.buffer_limit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    DEFAULT_COMMAND => session_options.default_command = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    DEFAULT_SHELL => session_options.default_shell = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    DEFAULT_PATH => session_options.default_path = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
-                    DEFAULT_TERMINAL => session_options.default_terminal = cow_parse(value),
-                    // #[cfg(feature = "tmux_2_9")]
-                    // DEFAULT_SIZE => {
-                    // session_options.default_size = value.and_then(|s| s.parse().ok())
-                    // }
-                    #[cfg(feature = "tmux_1_5")]
-                    DESTROY_UNATTACHED => {
-                        session_options.destroy_unattached = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_4")]
-                    DETACH_ON_DESTROY => {
-                        session_options.detach_on_destroy = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_2")]
-                    DISPLAY_PANES_ACTIVE_COLOUR => {
-                        session_options.display_panes_active_colour = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    DISPLAY_PANES_COLOUR => session_options.display_panes_colour = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    DISPLAY_PANES_TIME => {
-                        session_options.display_panes_time = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    DISPLAY_TIME => {
-                        session_options.display_time = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    HISTORY_LIMIT => {
-                        session_options.history_limit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_2_2")]
-                    KEY_TABLE => session_options.key_table = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    LOCK_AFTER_TIME => {
-                        session_options.lock_after_time = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_1")]
-                    LOCK_COMMAND => session_options.lock_command = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_1", not(feature = "tmux_2_1")))]
-                    LOCK_SERVER => session_options.lock_server = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    MESSAGE_ATTR => session_options.message_attr = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    MESSAGE_BG => session_options.message_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    MESSAGE_COMMAND_ATTR => session_options.message_command_attr = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    MESSAGE_COMMAND_BG => session_options.message_command_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    MESSAGE_COMMAND_FG => session_options.message_command_fg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    MESSAGE_FG => session_options.message_fg = cow_parse(value),
-                    #[cfg(feature = "tmux_1_9")]
-                    MESSAGE_COMMAND_STYLE => {
-                        session_options.message_command_style = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_0")))]
-                    MESSAGE_LIMIT => {
-                        session_options.message_limit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    MESSAGE_STYLE => session_options.message_style = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
-                    MOUSE_RESIZE_PANE => {
-                        session_options.mouse_resize_pane = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
-                    MOUSE_SELECT_PANE => {
-                        session_options.mouse_select_pane = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
-                    MOUSE_SELECT_WINDOW => {
-                        session_options.mouse_select_window = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_2_1")]
-                    MOUSE => session_options.mouse = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_2")))]
-                    MOUSE_UTF8 => session_options.mouse_utf8 = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
-                    PANE_ACTIVE_BORDER_BG => {
-                        session_options.pane_active_border_bg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
-                    PANE_ACTIVE_BORDER_FG => {
-                        session_options.pane_active_border_fg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
-                    PANE_BORDER_BG => session_options.pane_border_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
-                    PANE_BORDER_FG => session_options.pane_active_border_fg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
-                    PANE_ACTIVE_BORDER_STYLE => {
-                        session_options.pane_active_border_style = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
-                    PANE_BORDER_STYLE => session_options.pane_border_style = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    PREFIX => session_options.prefix = cow_parse(value),
-                    #[cfg(feature = "tmux_1_6")]
-                    PREFIX2 => session_options.prefix2 = cow_parse(value),
-                    #[cfg(feature = "tmux_1_7")]
-                    RENUMBER_WINDOWS => {
-                        session_options.renumber_windows = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    REPEAT_TIME => session_options.repeat_time = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_4")))]
-                    SET_REMAIN_ON_EXIT => {
-                        session_options.set_remain_on_exit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    SET_TITLES => session_options.set_titles = value.and_then(|s| s.parse().ok()),
-                    #[cfg(feature = "tmux_1_0")]
-                    SET_TITLES_STRING => session_options.set_titles_string = cow_parse(value),
-                    #[cfg(feature = "tmux_2_6")]
-                    SILENCE_ACTION => {
-                        session_options.silence_action = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    STATUS => session_options.status = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_ATTR => session_options.status_attr = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_BG => session_options.status_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_FG => session_options.status_fg = cow_parse(value),
-                    #[cfg(feature = "tmux_2_9")]
-                    STATUS_FORMAT => array_insert(
-                        &mut session_options.status_format,
-                        i,
-                        value.and_then(|s| s.parse().ok()),
-                    ),
-                    #[cfg(feature = "tmux_1_0")]
-                    STATUS_INTERVAL => {
-                        session_options.status_interval = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    STATUS_JUSTIFY => {
-                        session_options.status_justify = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    STATUS_KEYS => session_options.status_keys = value.and_then(|s| s.parse().ok()),
-                    #[cfg(feature = "tmux_1_0")]
-                    STATUS_LEFT => session_options.status_left = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_LEFT_ATTR => session_options.status_left_attr = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_LEFT_BG => session_options.status_left_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_LEFT_FG => session_options.status_left_fg = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    STATUS_LEFT_LENGTH => {
-                        session_options.status_left_length = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    STATUS_LEFT_STYLE => session_options.status_left_style = cow_parse(value),
-                    #[cfg(feature = "tmux_1_7")]
-                    STATUS_POSITION => {
-                        session_options.status_position = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    STATUS_RIGHT => session_options.status_right = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_RIGHT_ATTR => session_options.status_right_attr = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_RIGHT_BG => session_options.status_right_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    STATUS_RIGHT_FG => session_options.status_right_fg = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    STATUS_RIGHT_LENGTH => {
-                        session_options.status_right_length = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    STATUS_RIGHT_STYLE => session_options.status_right_style = cow_parse(value),
-                    #[cfg(feature = "tmux_1_9")]
-                    STATUS_STYLE => session_options.status_style = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_2")))]
-                    STATUS_UTF8 => session_options.status_utf8 = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
-                    TERMINAL_OVERRIDES => session_options.terminal_overrides = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    UPDATE_ENVIRONMENT => array_insert(
-                        &mut session_options.update_environment,
-                        i,
-                        value.and_then(|s| s.parse().ok()),
-                    ),
-                    #[cfg(all(feature = "tmux_2_6", not(feature = "tmux_3_0")))]
-                    USER_KEYS => array_insert(
-                        &mut session_options.user_keys,
-                        i,
-                        value.and_then(|s| s.parse().ok()),
-                    ),
-                    #[cfg(feature = "tmux_1_0")]
-                    VISUAL_ACTIVITY => {
-                        session_options.visual_activity = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    VISUAL_BELL => session_options.visual_bell = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
-                    VISUAL_CONTENT => {
-                        session_options.visual_content = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_4")]
-                    VISUAL_SILENCE => {
-                        session_options.visual_silence = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_6")]
-                    WORD_SEPARATORS => session_options.word_separators = cow_parse(value),
-                    _ => {
-                        // if user option (@user_option value)
-                        if let Some(name) = name.strip_prefix('@') {
-                            session_options
-                                .user_options
-                                .insert(name.to_string(), cow_parse(value));
-                        }
-                    }
-                }
+                session_options.set_value(name, i, value);
             }
         }
 
         Ok(session_options)
     }
 }
+
+impl<'a> OptionsValuesTr for SessionOptions<'a> {
+    fn values(&self) -> Vec<(&'static str, Option<usize>, String)> {
+        let mut v = Vec::new();
+        #[cfg(feature = "tmux_2_6")]
+        option_value(&mut v, ACTIVITY_ACTION, &self.activity_action);
+        #[cfg(feature = "tmux_1_8")]
+        option_value(&mut v, ASSUME_PASTE_TIME, &self.assume_paste_time);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, BASE_INDEX, &self.base_index);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, BELL_ACTION, &self.bell_action);
+        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_6")))]
+        option_value(&mut v, BELL_ON_ALERT, &self.bell_on_alert);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_4")))]
+        option_value(&mut v, BUFFER_LIMIT, &self.buffer_limit);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, DEFAULT_COMMAND, &self.default_command);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, DEFAULT_SHELL, &self.default_shell);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, DEFAULT_PATH, &self.default_path);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
+        option_value(&mut v, DEFAULT_TERMINAL, &self.default_terminal);
+        // #[cfg(feature = "tmux_2_9")]
+        // option_value(&mut v, DEFAULT_SIZE, &self.default_size);
+        #[cfg(feature = "tmux_1_5")]
+        option_value(&mut v, DESTROY_UNATTACHED, &self.destroy_unattached);
+        #[cfg(feature = "tmux_1_4")]
+        option_value(&mut v, DETACH_ON_DESTROY, &self.detach_on_destroy);
+        #[cfg(feature = "tmux_1_2")]
+        option_value(
+            &mut v,
+            DISPLAY_PANES_ACTIVE_COLOUR,
+            &self.display_panes_active_colour,
+        );
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, DISPLAY_PANES_COLOUR, &self.display_panes_colour);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, DISPLAY_PANES_TIME, &self.display_panes_time);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, DISPLAY_TIME, &self.display_time);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, HISTORY_LIMIT, &self.history_limit);
+        #[cfg(feature = "tmux_2_2")]
+        option_value(&mut v, KEY_TABLE, &self.key_table);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, LOCK_AFTER_TIME, &self.lock_after_time);
+        #[cfg(feature = "tmux_1_1")]
+        option_value(&mut v, LOCK_COMMAND, &self.lock_command);
+        #[cfg(all(feature = "tmux_1_1", not(feature = "tmux_2_1")))]
+        option_value(&mut v, LOCK_SERVER, &self.lock_server);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MESSAGE_ATTR, &self.message_attr);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MESSAGE_BG, &self.message_bg);
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MESSAGE_COMMAND_ATTR, &self.message_command_attr);
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MESSAGE_COMMAND_BG, &self.message_command_bg);
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MESSAGE_COMMAND_FG, &self.message_command_fg);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MESSAGE_FG, &self.message_fg);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(&mut v, MESSAGE_COMMAND_STYLE, &self.message_command_style);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_0")))]
+        option_value(&mut v, MESSAGE_LIMIT, &self.message_limit);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(&mut v, MESSAGE_STYLE, &self.message_style);
+        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
+        option_value(&mut v, MOUSE_RESIZE_PANE, &self.mouse_resize_pane);
+        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
+        option_value(&mut v, MOUSE_SELECT_PANE, &self.mouse_select_pane);
+        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
+        option_value(&mut v, MOUSE_SELECT_WINDOW, &self.mouse_select_window);
+        #[cfg(feature = "tmux_2_1")]
+        option_value(&mut v, MOUSE, &self.mouse);
+        #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_2")))]
+        option_value(&mut v, MOUSE_UTF8, &self.mouse_utf8);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
+        option_value(&mut v, PANE_ACTIVE_BORDER_BG, &self.pane_active_border_bg);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
+        option_value(&mut v, PANE_ACTIVE_BORDER_FG, &self.pane_active_border_fg);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
+        option_value(&mut v, PANE_BORDER_BG, &self.pane_border_bg);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
+        option_value(&mut v, PANE_BORDER_FG, &self.pane_border_fg);
+        #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
+        option_value(
+            &mut v,
+            PANE_ACTIVE_BORDER_STYLE,
+            &self.pane_active_border_style,
+        );
+        #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
+        option_value(&mut v, PANE_BORDER_STYLE, &self.pane_border_style);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, PREFIX, &self.prefix);
+        #[cfg(feature = "tmux_1_6")]
+        option_value(&mut v, PREFIX2, &self.prefix2);
+        #[cfg(feature = "tmux_1_7")]
+        option_value(&mut v, RENUMBER_WINDOWS, &self.renumber_windows);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, REPEAT_TIME, &self.repeat_time);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_4")))]
+        option_value(&mut v, SET_REMAIN_ON_EXIT, &self.set_remain_on_exit);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, SET_TITLES, &self.set_titles);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, SET_TITLES_STRING, &self.set_titles_string);
+        #[cfg(feature = "tmux_2_6")]
+        option_value(&mut v, SILENCE_ACTION, &self.silence_action);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, STATUS, &self.status);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_ATTR, &self.status_attr);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_BG, &self.status_bg);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_FG, &self.status_fg);
+        #[cfg(feature = "tmux_2_9")]
+        option_array_value(&mut v, STATUS_FORMAT, &self.status_format);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, STATUS_INTERVAL, &self.status_interval);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, STATUS_JUSTIFY, &self.status_justify);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, STATUS_KEYS, &self.status_keys);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, STATUS_LEFT, &self.status_left);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_LEFT_ATTR, &self.status_left_attr);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_LEFT_BG, &self.status_left_bg);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_LEFT_FG, &self.status_left_fg);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, STATUS_LEFT_LENGTH, &self.status_left_length);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(&mut v, STATUS_LEFT_STYLE, &self.status_left_style);
+        #[cfg(feature = "tmux_1_7")]
+        option_value(&mut v, STATUS_POSITION, &self.status_position);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, STATUS_RIGHT, &self.status_right);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_RIGHT_ATTR, &self.status_right_attr);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_RIGHT_BG, &self.status_right_bg);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, STATUS_RIGHT_FG, &self.status_right_fg);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, STATUS_RIGHT_LENGTH, &self.status_right_length);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(&mut v, STATUS_RIGHT_STYLE, &self.status_right_style);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(&mut v, STATUS_STYLE, &self.status_style);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_2")))]
+        option_value(&mut v, STATUS_UTF8, &self.status_utf8);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
+        option_value(&mut v, TERMINAL_OVERRIDES, &self.terminal_overrides);
+        #[cfg(feature = "tmux_1_0")]
+        option_array_value(&mut v, UPDATE_ENVIRONMENT, &self.update_environment);
+        #[cfg(all(feature = "tmux_2_6", not(feature = "tmux_3_0")))]
+        option_array_value(&mut v, USER_KEYS, &self.user_keys);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, VISUAL_ACTIVITY, &self.visual_activity);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, VISUAL_BELL, &self.visual_bell);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
+        option_value(&mut v, VISUAL_CONTENT, &self.visual_content);
+        #[cfg(feature = "tmux_1_4")]
+        option_value(&mut v, VISUAL_SILENCE, &self.visual_silence);
+        #[cfg(feature = "tmux_1_6")]
+        option_value(&mut v, WORD_SEPARATORS, &self.word_separators);
+
+        // option_value(&mut v, , &self.user_options);
+
+        // option_value(&mut v, USER_OPTIONS, &self.user_options);
+        v
+    }
+
+    fn set_value(&mut self, name: &str, i: Option<usize>, value: Option<&str>) {
+        match name {
+            #[cfg(feature = "tmux_2_6")]
+            ACTIVITY_ACTION => self.activity_action = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_8")]
+            ASSUME_PASTE_TIME => self.assume_paste_time = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            BASE_INDEX => self.base_index = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            BELL_ACTION => self.bell_action = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_6")))]
+            BELL_ON_ALERT => self.bell_on_alert = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_4")))]
+            BUFFER_LIMIT => self.buffer_limit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            DEFAULT_COMMAND => self.default_command = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            DEFAULT_SHELL => self.default_shell = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            DEFAULT_PATH => self.default_path = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
+            DEFAULT_TERMINAL => self.default_terminal = cow_parse(value),
+            // #[cfg(feature = "tmux_2_9")]
+            // DEFAULT_SIZE => {
+            // self.default_size = value.and_then(|s| s.parse().ok())
+            // }
+            #[cfg(feature = "tmux_1_5")]
+            DESTROY_UNATTACHED => self.destroy_unattached = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_4")]
+            DETACH_ON_DESTROY => self.detach_on_destroy = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_2")]
+            DISPLAY_PANES_ACTIVE_COLOUR => self.display_panes_active_colour = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            DISPLAY_PANES_COLOUR => self.display_panes_colour = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            DISPLAY_PANES_TIME => self.display_panes_time = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            DISPLAY_TIME => self.display_time = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            HISTORY_LIMIT => self.history_limit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_2_2")]
+            KEY_TABLE => self.key_table = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            LOCK_AFTER_TIME => self.lock_after_time = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_1")]
+            LOCK_COMMAND => self.lock_command = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_1", not(feature = "tmux_2_1")))]
+            LOCK_SERVER => self.lock_server = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            MESSAGE_ATTR => self.message_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            MESSAGE_BG => self.message_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            MESSAGE_COMMAND_ATTR => self.message_command_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            MESSAGE_COMMAND_BG => self.message_command_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            MESSAGE_COMMAND_FG => self.message_command_fg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            MESSAGE_FG => self.message_fg = cow_parse(value),
+            #[cfg(feature = "tmux_1_9")]
+            MESSAGE_COMMAND_STYLE => self.message_command_style = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_2_0")))]
+            MESSAGE_LIMIT => self.message_limit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_9")]
+            MESSAGE_STYLE => self.message_style = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
+            MOUSE_RESIZE_PANE => self.mouse_resize_pane = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
+            MOUSE_SELECT_PANE => self.mouse_select_pane = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_1")))]
+            MOUSE_SELECT_WINDOW => self.mouse_select_window = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_2_1")]
+            MOUSE => self.mouse = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_5", not(feature = "tmux_2_2")))]
+            MOUSE_UTF8 => self.mouse_utf8 = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
+            PANE_ACTIVE_BORDER_BG => self.pane_active_border_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
+            PANE_ACTIVE_BORDER_FG => self.pane_active_border_fg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
+            PANE_BORDER_BG => self.pane_border_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_9")))]
+            PANE_BORDER_FG => self.pane_active_border_fg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
+            PANE_ACTIVE_BORDER_STYLE => self.pane_active_border_style = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
+            PANE_BORDER_STYLE => self.pane_border_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            PREFIX => self.prefix = cow_parse(value),
+            #[cfg(feature = "tmux_1_6")]
+            PREFIX2 => self.prefix2 = cow_parse(value),
+            #[cfg(feature = "tmux_1_7")]
+            RENUMBER_WINDOWS => self.renumber_windows = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            REPEAT_TIME => self.repeat_time = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_4")))]
+            SET_REMAIN_ON_EXIT => self.set_remain_on_exit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            SET_TITLES => self.set_titles = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            SET_TITLES_STRING => self.set_titles_string = cow_parse(value),
+            #[cfg(feature = "tmux_2_6")]
+            SILENCE_ACTION => self.silence_action = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            STATUS => self.status = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_ATTR => self.status_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_BG => self.status_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_FG => self.status_fg = cow_parse(value),
+            #[cfg(feature = "tmux_2_9")]
+            STATUS_FORMAT => array_insert(
+                &mut self.status_format,
+                i,
+                value.and_then(|s| s.parse().ok()),
+            ),
+            #[cfg(feature = "tmux_1_0")]
+            STATUS_INTERVAL => self.status_interval = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            STATUS_JUSTIFY => self.status_justify = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            STATUS_KEYS => self.status_keys = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            STATUS_LEFT => self.status_left = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_LEFT_ATTR => self.status_left_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_LEFT_BG => self.status_left_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_LEFT_FG => self.status_left_fg = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            STATUS_LEFT_LENGTH => self.status_left_length = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_9")]
+            STATUS_LEFT_STYLE => self.status_left_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_7")]
+            STATUS_POSITION => self.status_position = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            STATUS_RIGHT => self.status_right = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_RIGHT_ATTR => self.status_right_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_RIGHT_BG => self.status_right_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            STATUS_RIGHT_FG => self.status_right_fg = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            STATUS_RIGHT_LENGTH => self.status_right_length = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_9")]
+            STATUS_RIGHT_STYLE => self.status_right_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_9")]
+            STATUS_STYLE => self.status_style = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_2")))]
+            STATUS_UTF8 => self.status_utf8 = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
+            TERMINAL_OVERRIDES => self.terminal_overrides = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            UPDATE_ENVIRONMENT => array_insert(
+                &mut self.update_environment,
+                i,
+                value.and_then(|s| s.parse().ok()),
+            ),
+            #[cfg(all(feature = "tmux_2_6", not(feature = "tmux_3_0")))]
+            USER_KEYS => array_insert(&mut self.user_keys, i, value.and_then(|s| s.parse().ok())),
+            #[cfg(feature = "tmux_1_0")]
+            VISUAL_ACTIVITY => self.visual_activity = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            VISUAL_BELL => self.visual_bell = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
+            VISUAL_CONTENT => self.visual_content = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_4")]
+            VISUAL_SILENCE => self.visual_silence = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_6")]
+            WORD_SEPARATORS => self.word_separators = cow_parse(value),
+            _ => {
+                // if user option (@user_option value)
+                if let Some(name) = name.strip_prefix('@') {
+                    self.user_options.insert(name.to_string(), cow_parse(value));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::options::common::{option_value_escape, option_value_unescape};
+use crate::{Error, OptionScope, Tmux, TmuxCommand, TmuxCommands, TmuxOutput};
+#[cfg(feature = "json")]
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+const USER_OPTION_MARKER: char = '@';
+const SEPARATOR: char = ' ';
+
+/// Typed key-value store in user options (`@name value`) of the server, session, window or
+/// pane
+///
+/// Names are given without `@` marker, values are read from the given scope only (not
+/// inherited from parent options)
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{OptionScope, UserOptionsCtl};
+///
+/// let store = UserOptionsCtl::new(OptionScope::Pane(Some("%1".into())));
+/// store.set("ticket-id", 1024).unwrap();
+/// store.set("ticket-owner", "alice").unwrap();
+///
+/// let id: Option<usize> = store.get("ticket-id").unwrap();
+/// assert_eq!(id, Some(1024));
+///
+/// let ticket = store.list::<String>("ticket-").unwrap();
+/// assert_eq!(ticket["ticket-owner"], "alice");
+///
+/// store.delete_all("ticket-").unwrap();
+/// ```
+pub struct UserOptionsCtl<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// options the user options are stored in
+    pub scope: OptionScope<'a>,
+}
+
+impl<'a> UserOptionsCtl<'a> {
+    pub fn new(scope: OptionScope<'a>) -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            scope,
+        }
+    }
+
+    pub fn with_invoker(
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+        scope: OptionScope<'a>,
+    ) -> Self {
+        Self { invoker, scope }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Get the user option value, `None` if not set
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, Error> {
+        let name = user_option_name(name);
+        let cmd = self
+            .scope
+            .show_options()
+            .quiet()
+            .option(name.clone())
+            .build();
+        let output = (self.invoker)(cmd)?.into_result()?.to_string();
+        let value = parse_user_options(&output).find(|(key, _)| *key == name);
+        value
+            .map(|(_, value)| value.parse().map_err(|_| Error::ParseUserOption))
+            .transpose()
+    }
+
+    /// Set the user option value, quotes, newlines and trailing `;` are kept as is
+    pub fn set<T: fmt::Display>(&self, name: &str, value: T) -> Result<(), Error> {
+        let value = option_value_escape(&value.to_string()).into_owned();
+        let cmd = self.scope.set_option(user_option_name(name), Some(value));
+        (self.invoker)(cmd)?.into_result()?;
+        Ok(())
+    }
+
+    /// Unset the user option, not set option is ignored
+    pub fn delete(&self, name: &str) -> Result<(), Error> {
+        let cmd = self.scope.set_option(user_option_name(name), None);
+        (self.invoker)(cmd)?.into_result()?;
+        Ok(())
+    }
+
+    /// All user options with names starting with the given prefix (names without `@`
+    /// marker), empty prefix for all user options
+    pub fn list<T: FromStr>(&self, prefix: &str) -> Result<BTreeMap<String, T>, Error> {
+        let prefix = user_option_name(prefix);
+        let cmd = self.scope.show_options().build();
+        let output = (self.invoker)(cmd)?.into_result()?.to_string();
+        let options = parse_user_options(&output)
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .map(|(key, value)| {
+                let value = value.parse().map_err(|_| Error::ParseUserOption)?;
+                Ok((key[USER_OPTION_MARKER.len_utf8()..].to_string(), value))
+            })
+            .collect();
+        options
+    }
+
+    /// Unset all user options with names starting with the given prefix, single invocation
+    pub fn delete_all(&self, prefix: &str) -> Result<(), Error> {
+        let names = self.list::<String>(prefix)?.into_keys();
+        let mut cmds = TmuxCommands::new();
+        for name in names {
+            cmds.push(self.scope.set_option(user_option_name(&name), None));
+        }
+        if !cmds.commands.is_empty() {
+            (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        }
+        Ok(())
+    }
+
+    /// Get the JSON encoded user option value, `None` if not set
+    #[cfg(feature = "json")]
+    pub fn get_json<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>, Error> {
+        match self.get::<String>(name)? {
+            Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the user option value JSON encoded
+    #[cfg(feature = "json")]
+    pub fn set_json<T: Serialize>(&self, name: &str, value: &T) -> Result<(), Error> {
+        self.set(name, serde_json::to_string(value)?)
+    }
+}
+
+// user option name with `@` marker
+fn user_option_name(name: &str) -> String {
+    format!("{}{}", USER_OPTION_MARKER, name)
+}
+
+// user options names and unescaped values from `show-options` output (`@name value`)
+fn parse_user_options(output: &str) -> impl Iterator<Item = (&str, String)> {
+    output
+        .lines()
+        .filter(|line| line.starts_with(USER_OPTION_MARKER))
+        .map(|line| match line.split_once(SEPARATOR) {
+            Some((key, value)) => (key, option_value_unescape(value)),
+            None => (line, String::new()),
+        })
+}
@@ -0,0 +1,62 @@
+#[cfg(unix)]
+#[test]
+fn user_options_ctl() {
+    use crate::{mock_output, Error, MockInvoker, OptionScope, UserOptionsCtl};
+
+    let mock = MockInvoker::new();
+    let invoker = mock.invoker(|_| {
+        let stdout =
+            "status on\n@ticket-id 1024\n@ticket-title \"a \\\"b\\\"\\nc;\"\n@owner alice\n";
+        mock_output(0, stdout)
+    });
+    let store = UserOptionsCtl::with_invoker(&invoker, OptionScope::GlobalSession);
+
+    assert_eq!(store.get::<usize>("ticket-id").unwrap(), Some(1024));
+    assert_eq!(
+        store.get::<String>("ticket-title").unwrap(),
+        Some("a \"b\"\nc;".to_string())
+    );
+    assert_eq!(store.get::<String>("ticket").unwrap(), None);
+    assert!(matches!(
+        store.get::<usize>("owner"),
+        Err(Error::ParseUserOption)
+    ));
+
+    let ticket = store.list::<String>("ticket-").unwrap();
+    assert_eq!(
+        ticket.keys().collect::<Vec<_>>(),
+        ["ticket-id", "ticket-title"]
+    );
+    assert_eq!(store.list::<String>("").unwrap().len(), 3);
+
+    mock.clear();
+    store.set("ticket-title", "c;").unwrap();
+    store.delete("owner").unwrap();
+    #[cfg(not(feature = "cmd_alias"))]
+    let cmd = "set-option";
+    #[cfg(feature = "cmd_alias")]
+    let cmd = "set";
+    assert_eq!(
+        *mock.cmds.borrow(),
+        [
+            format!("{} -g @ticket-title c\\;", cmd),
+            format!("{} -g -u @owner", cmd),
+        ]
+    );
+
+    #[cfg(feature = "json")]
+    {
+        mock.clear();
+        store.set_json("owners", &vec!["alice", "bob"]).unwrap();
+        assert_eq!(
+            *mock.cmds.borrow(),
+            [format!("{} -g @owners [\"alice\",\"bob\"]", cmd)]
+        );
+        let id: Option<usize> = store.get_json("ticket-id").unwrap();
+        assert_eq!(id, Some(1024));
+        assert!(matches!(
+            store.get_json::<usize>("owner"),
+            Err(Error::Json(_))
+        ));
+    }
+}
@@ -33,3 +33,6 @@ impl fmt::Display for ClockModeStyle {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(ClockModeStyle);
@@ -39,3 +39,6 @@ impl fmt::Display for ModeMouse {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(ModeMouse);
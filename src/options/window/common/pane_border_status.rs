@@ -36,3 +36,6 @@ impl fmt::Display for PaneBorderStatus {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(PaneBorderStatus);
@@ -42,3 +42,6 @@ impl fmt::Display for WindowSize {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(WindowSize);
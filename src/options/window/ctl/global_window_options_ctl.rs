@@ -1,3 +1,7 @@
+#[cfg(feature = "tmux_1_7")]
+use crate::ArrayOptionsCtl;
+#[cfg(feature = "tmux_1_2")]
+use crate::OptionScope;
 use crate::{
     Error, GetGlobalWindowOption, GetGlobalWindowOptionValue, SetGlobalWindowOption,
     SetGlobalWindowOptions, Tmux, TmuxCommand, TmuxOutput, WindowOptionsCtl,
@@ -49,4 +53,20 @@ impl<'a> WindowOptionsCtl<'a> for GlobalWindowOptionsCtl<'a> {
     fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
         self.invoker
     }
+
+    #[cfg(feature = "tmux_1_2")]
+    fn scope(&self) -> OptionScope<'a> {
+        OptionScope::GlobalWindow
+    }
+}
+
+#[cfg(feature = "tmux_1_7")]
+impl<'a> ArrayOptionsCtl<'a> for GlobalWindowOptionsCtl<'a> {
+    fn array_invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn array_scope(&self) -> OptionScope<'a> {
+        WindowOptionsCtl::scope(self)
+    }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "tmux_1_7")]
+use crate::{ArrayOptionsCtl, OptionScope};
 use crate::{
     Error, GetLocalWindowOption, GetLocalWindowOptionValue, SetLocalWindowOption,
     SetLocalWindowOptions, Tmux, TmuxCommand, TmuxOutput, WindowOptionsCtl,
@@ -64,3 +66,14 @@ impl<'a> WindowOptionsCtl<'a> for LocalWindowOptionsCtl<'a> {
         self.invoker
     }
 }
+
+#[cfg(feature = "tmux_1_7")]
+impl<'a> ArrayOptionsCtl<'a> for LocalWindowOptionsCtl<'a> {
+    fn array_invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    fn array_scope(&self) -> OptionScope<'a> {
+        WindowOptionsCtl::scope(self)
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
 use crate::ModeMouse;
+#[cfg(feature = "tmux_1_2")]
+use crate::OptionScope;
 use crate::{
     ClockModeStyle, Error, GetWindowOptionTr, SetWindowOptionTr, SetWindowOptionsTr, StatusKeys,
     Switch, TmuxCommand, TmuxOutput, WindowOptions,
@@ -34,7 +36,7 @@ pub trait WindowOptionsCtl<'a> {
         invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
     ) -> Result<WindowOptions<'a>, Error> {
         let cmd = Self::GetterAll::all(target);
-        let output = (invoker)(cmd)?.to_string();
+        let output = (invoker)(cmd)?.into_result()?.to_string();
         WindowOptions::from_str(&output)
     }
 
@@ -246,6 +248,13 @@ pub trait WindowOptionsCtl<'a> {
         (self.invoker())(cmd)
     }
 
+    /// Options scope of this ctl (`set-option`/`show-options` flags and target), local window
+    /// options of the target by default
+    #[cfg(feature = "tmux_1_2")]
+    fn scope(&self) -> OptionScope<'a> {
+        OptionScope::Window(self.target())
+    }
+
     // # Manual
     //
     // tmux ^1.0:
@@ -1,5 +1,6 @@
 use super::*;
-use crate::options::common::{cow_parse, get_parts, option_to_string};
+use crate::options::common::{cow_parse, get_parts, option_value, option_values_to_string};
+use crate::options::OptionsValuesTr;
 use crate::options::StatusKeys;
 use crate::Error;
 use crate::Switch;
@@ -10,8 +11,11 @@ use std::str::FromStr;
 
 #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
 use crate::ModeMouse;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 // TODO: check types
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Default, Clone, Debug)]
 pub struct WindowOptions<'a> {
     /// tmux ^1.0:
@@ -548,238 +552,7 @@ pub struct WindowOptions<'a> {
 
 impl<'a> fmt::Display for WindowOptions<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut v = Vec::new();
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, AGGRESSIVE_RESIZE, &self.aggressive_resize);
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_3_0")))]
-        option_to_string(&mut v, ALLOW_RENAME, &self.allow_rename);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_0")))]
-        option_to_string(&mut v, ALTERNATE_SCREEN, &self.alternate_screen);
-        #[cfg(feature = "tmux_1_0")] // 0.8
-        option_to_string(&mut v, AUTOMATIC_RENAME, &self.automatic_rename);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(
-            &mut v,
-            AUTOMATIC_RENAME_FORMAT,
-            &self.automatic_rename_format,
-        );
-        #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_1")))]
-        option_to_string(&mut v, C0_CHANGE_INTERVAL, &self.c0_change_interval);
-        #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_1")))]
-        option_to_string(&mut v, C0_CHANGE_TRIGGER, &self.c0_change_trigger);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, CLOCK_MODE_COLOUR, &self.clock_mode_colour);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, CLOCK_MODE_STYLE, &self.clock_mode_style);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
-        option_to_string(&mut v, FORCE_HEIGHT, &self.force_height);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
-        option_to_string(&mut v, FORCE_WIDTH, &self.force_width);
-        #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_1_8")))]
-        option_to_string(&mut v, LAYOUT_HISTORY_LIMIT, &self.layout_history_limit);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, MAIN_PANE_HEIGHT, &self.main_pane_height);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, MAIN_PANE_WIDTH, &self.main_pane_width);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MODE_ATTR, &self.mode_attr);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MODE_BG, &self.mode_bg);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, MODE_FG, &self.mode_fg);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, MODE_KEYS, &self.mode_keys);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
-        option_to_string(&mut v, MODE_MOUSE, &self.mode_mouse);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(&mut v, MODE_STYLE, &self.mode_style);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, MONITOR_ACTIVITY, &self.monitor_activity);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
-        option_to_string(&mut v, MONITOR_CONTENT, &self.monitor_content);
-        #[cfg(feature = "tmux_2_6")]
-        option_to_string(&mut v, MONITOR_BELL, &self.monitor_bell);
-        #[cfg(feature = "tmux_1_4")]
-        option_to_string(&mut v, MONITOR_SILENCE, &self.monitor_silence);
-        #[cfg(feature = "tmux_1_4")]
-        option_to_string(&mut v, OTHER_PANE_HEIGHT, &self.other_pane_height);
-        #[cfg(feature = "tmux_1_4")]
-        option_to_string(&mut v, OTHER_PANE_WIDTH, &self.other_pane_width);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(
-            &mut v,
-            PANE_ACTIVE_BORDER_STYLE,
-            &self.pane_active_border_style,
-        );
-        #[cfg(feature = "tmux_1_6")]
-        option_to_string(&mut v, PANE_BASE_INDEX, &self.pane_base_index);
-        #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, PANE_BORDER_BG, &self.pane_border_bg);
-        #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, PANE_BORDER_FG, &self.pane_border_fg);
-        #[cfg(feature = "tmux_2_3")]
-        option_to_string(&mut v, PANE_BORDER_FORMAT, &self.pane_border_format);
-        #[cfg(feature = "tmux_2_3")]
-        option_to_string(&mut v, PANE_BORDER_STATUS, &self.pane_border_status);
-        #[cfg(feature = "tmux_2_0")]
-        option_to_string(&mut v, PANE_BORDER_STYLE, &self.pane_border_style);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_3_0")))]
-        option_to_string(&mut v, REMAIN_ON_EXIT, &self.remain_on_exit);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_2")))]
-        option_to_string(&mut v, SYNCHRONIZE_PANES, &self.synchronize_panes);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_2")))]
-        option_to_string(&mut v, UTF8, &self.utf8);
-        #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_3_0")))]
-        option_to_string(&mut v, WINDOW_ACTIVE_STYLE, &self.window_active_style);
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_BELL_ATTR,
-            &self.window_status_bell_attr,
-        );
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, WINDOW_STATUS_BELL_BG, &self.window_status_bell_bg);
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, WINDOW_STATUS_BELL_FG, &self.window_status_bell_fg);
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CONTENT_ATTR,
-            &self.window_status_content_attr,
-        );
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CONTENT_BG,
-            &self.window_status_content_bg,
-        );
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CONTENT_FG,
-            &self.window_status_content_fg,
-        );
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_ACTIVITY_ATTR,
-            &self.window_status_activity_attr,
-        );
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_ACTIVITY_BG,
-            &self.window_status_activity_bg,
-        );
-        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_ACTIVITY_FG,
-            &self.window_status_activity_fg,
-        );
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, WINDOW_STATUS_ATTR, &self.window_status_attr);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, WINDOW_STATUS_BG, &self.window_status_bg);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, WINDOW_STATUS_FG, &self.window_status_fg);
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CURRENT_ATTR,
-            &self.window_status_current_attr,
-        );
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CURRENT_BG,
-            &self.window_status_current_bg,
-        );
-        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CURRENT_FG,
-            &self.window_status_current_fg,
-        );
-        #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_ALERT_ATTR,
-            &self.window_status_alert_attr,
-        );
-        #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
-        option_to_string(&mut v, WINDOW_STATUS_ALERT_BG, &self.window_status_alert_bg);
-        #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
-        option_to_string(&mut v, WINDOW_STATUS_ALERT_FG, &self.window_status_alert_fg);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_ACTIVITY_STYLE,
-            &self.window_status_activity_style,
-        );
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_BELL_STYLE,
-            &self.window_status_bell_style,
-        );
-        #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CONTENT_STYLE,
-            &self.window_status_content_style,
-        );
-        #[cfg(feature = "tmux_1_2")]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CURRENT_FORMAT,
-            &self.window_status_current_format,
-        );
-        #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_LAST_ATTR,
-            &self.window_status_last_attr,
-        );
-        #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, WINDOW_STATUS_LAST_BG, &self.window_status_last_bg);
-        #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
-        option_to_string(&mut v, WINDOW_STATUS_LAST_FG, &self.window_status_last_fg);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_CURRENT_STYLE,
-            &self.window_status_current_style,
-        );
-        #[cfg(feature = "tmux_1_2")]
-        option_to_string(&mut v, WINDOW_STATUS_FORMAT, &self.window_status_format);
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_LAST_STYLE,
-            &self.window_status_last_style,
-        );
-        #[cfg(feature = "tmux_1_7")]
-        option_to_string(
-            &mut v,
-            WINDOW_STATUS_SEPARATOR,
-            &self.window_status_separator,
-        );
-        #[cfg(feature = "tmux_1_9")]
-        option_to_string(&mut v, WINDOW_STATUS_STYLE, &self.window_status_style);
-        #[cfg(feature = "tmux_2_9")]
-        option_to_string(&mut v, WINDOW_SIZE, &self.window_size);
-        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_6")))]
-        option_to_string(&mut v, WORD_SEPARATORS, &self.word_separators);
-        #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_3_0")))]
-        option_to_string(&mut v, WINDOW_STYLE, &self.window_style);
-        #[cfg(feature = "tmux_1_7")]
-        option_to_string(&mut v, WRAP_SEARCH, &self.wrap_search);
-        #[cfg(feature = "tmux_1_0")]
-        option_to_string(&mut v, XTERM_KEYS, &self.xterm_keys);
-        // option_to_string(&mut v, USER_OPTIONS, &self.user_options);
-        let s = v.join("\n");
-        write!(f, "{}", s)
+        write!(f, "{}", option_values_to_string(&self.values()))
     }
 }
 impl<'a> WindowOptions<'a> {
@@ -1518,268 +1291,406 @@ impl<'a> FromStr for WindowOptions<'a> {
         let mut window_options = WindowOptions::default();
 
         for line in s.lines() {
-            if let Some((name, _i, value)) = get_parts(line) {
-                match name {
-                    #[cfg(feature = "tmux_1_0")]
-                    AGGRESSIVE_RESIZE => {
-                        window_options.aggressive_resize = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_3_0")))]
-                    ALLOW_RENAME => {
-                        window_options.allow_rename = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_0")))]
-                    ALTERNATE_SCREEN => {
-                        window_options.alternate_screen = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")] // 0.8
-                    AUTOMATIC_RENAME => {
-                        window_options.automatic_rename = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    AUTOMATIC_RENAME_FORMAT => {
-                        window_options.automatic_rename_format = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_1")))]
-                    C0_CHANGE_INTERVAL => {
-                        window_options.c0_change_interval = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_1")))]
-                    C0_CHANGE_TRIGGER => {
-                        window_options.c0_change_trigger = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    CLOCK_MODE_COLOUR => window_options.clock_mode_colour = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    CLOCK_MODE_STYLE => {
-                        window_options.clock_mode_style = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
-                    FORCE_HEIGHT => {
-                        window_options.force_height = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
-                    FORCE_WIDTH => window_options.force_width = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_1_8")))]
-                    LAYOUT_HISTORY_LIMIT => {
-                        window_options.layout_history_limit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    MAIN_PANE_HEIGHT => {
-                        window_options.main_pane_height = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_0")]
-                    MAIN_PANE_WIDTH => {
-                        window_options.main_pane_width = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    MODE_ATTR => window_options.mode_attr = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    MODE_BG => window_options.mode_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    MODE_FG => window_options.mode_fg = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    MODE_KEYS => window_options.mode_keys = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
-                    MODE_MOUSE => window_options.mode_mouse = value.and_then(|s| s.parse().ok()),
-                    #[cfg(feature = "tmux_1_9")]
-                    MODE_STYLE => window_options.mode_style = cow_parse(value),
-                    #[cfg(feature = "tmux_1_0")]
-                    MONITOR_ACTIVITY => {
-                        window_options.monitor_activity = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
-                    MONITOR_CONTENT => window_options.monitor_content = cow_parse(value),
-                    #[cfg(feature = "tmux_2_6")]
-                    MONITOR_BELL => {
-                        window_options.monitor_bell = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_4")]
-                    MONITOR_SILENCE => {
-                        window_options.monitor_silence = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_4")]
-                    OTHER_PANE_HEIGHT => {
-                        window_options.other_pane_height = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_4")]
-                    OTHER_PANE_WIDTH => {
-                        window_options.other_pane_width = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    PANE_ACTIVE_BORDER_STYLE => {
-                        window_options.pane_active_border_style = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
-                    PANE_ACTIVE_BORDER_BG => {
-                        window_options.pane_active_border_bg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
-                    PANE_ACTIVE_BORDER_FG => {
-                        window_options.pane_active_border_fg = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_6")]
-                    PANE_BASE_INDEX => {
-                        window_options.pane_base_index = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
-                    PANE_BORDER_BG => window_options.pane_border_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
-                    PANE_BORDER_FG => window_options.pane_border_fg = cow_parse(value),
-                    #[cfg(feature = "tmux_2_3")]
-                    PANE_BORDER_FORMAT => window_options.pane_border_format = cow_parse(value),
-                    #[cfg(feature = "tmux_2_3")]
-                    PANE_BORDER_STATUS => {
-                        window_options.pane_border_status = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(feature = "tmux_2_0")]
-                    PANE_BORDER_STYLE => window_options.pane_border_style = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_3_0")))]
-                    REMAIN_ON_EXIT => {
-                        window_options.remain_on_exit = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_2")))]
-                    SYNCHRONIZE_PANES => {
-                        window_options.synchronize_panes = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_2")))]
-                    UTF8 => window_options.utf8 = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_3_0")))]
-                    WINDOW_ACTIVE_STYLE => window_options.window_active_style = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_BELL_ATTR => {
-                        window_options.window_status_bell_attr = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_BELL_BG => {
-                        window_options.window_status_bell_bg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_BELL_FG => {
-                        window_options.window_status_bell_fg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_CONTENT_ATTR => {
-                        window_options.window_status_content_attr = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_CONTENT_BG => {
-                        window_options.window_status_content_bg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_CONTENT_FG => {
-                        window_options.window_status_content_fg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_ACTIVITY_ATTR => {
-                        window_options.window_status_activity_attr = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_ACTIVITY_BG => {
-                        window_options.window_status_activity_bg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_ACTIVITY_FG => {
-                        window_options.window_status_activity_fg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_ATTR => window_options.window_status_attr = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_BG => window_options.window_status_bg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_FG => window_options.window_status_fg = cow_parse(value),
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_CURRENT_ATTR => {
-                        window_options.window_status_current_attr = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_CURRENT_BG => {
-                        window_options.window_status_current_bg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_CURRENT_FG => {
-                        window_options.window_status_current_fg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
-                    WINDOW_STATUS_ALERT_ATTR => {
-                        window_options.window_status_alert_attr = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
-                    WINDOW_STATUS_ALERT_BG => {
-                        window_options.window_status_alert_bg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
-                    WINDOW_STATUS_ALERT_FG => {
-                        window_options.window_status_alert_fg = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    WINDOW_STATUS_ACTIVITY_STYLE => {
-                        window_options.window_status_activity_style = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    WINDOW_STATUS_BELL_STYLE => {
-                        window_options.window_status_bell_style = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
-                    WINDOW_STATUS_CONTENT_STYLE => {
-                        window_options.window_status_content_style = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_2")]
-                    WINDOW_STATUS_CURRENT_FORMAT => {
-                        window_options.window_status_current_format = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_LAST_ATTR => {
-                        window_options.window_status_last_attr = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_LAST_BG => {
-                        window_options.window_status_last_bg = cow_parse(value)
-                    }
-                    #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
-                    WINDOW_STATUS_LAST_FG => {
-                        window_options.window_status_last_fg = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    WINDOW_STATUS_CURRENT_STYLE => {
-                        window_options.window_status_current_style = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_2")]
-                    WINDOW_STATUS_FORMAT => window_options.window_status_format = cow_parse(value),
-                    #[cfg(feature = "tmux_1_9")]
-                    WINDOW_STATUS_LAST_STYLE => {
-                        window_options.window_status_last_style = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_7")]
-                    WINDOW_STATUS_SEPARATOR => {
-                        window_options.window_status_separator = cow_parse(value)
-                    }
-                    #[cfg(feature = "tmux_1_9")]
-                    WINDOW_STATUS_STYLE => window_options.window_status_style = cow_parse(value),
-                    #[cfg(feature = "tmux_2_9")]
-                    WINDOW_SIZE => window_options.window_size = value.and_then(|s| s.parse().ok()),
-                    #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_6")))]
-                    WORD_SEPARATORS => {
-                        window_options.word_separators = value.and_then(|s| s.parse().ok())
-                    }
-                    #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_3_0")))]
-                    WINDOW_STYLE => window_options.window_style = cow_parse(value),
-                    #[cfg(feature = "tmux_1_7")]
-                    WRAP_SEARCH => window_options.wrap_search = value.and_then(|s| s.parse().ok()),
-                    #[cfg(feature = "tmux_1_0")]
-                    XTERM_KEYS => window_options.xterm_keys = value.and_then(|s| s.parse().ok()),
-                    _ => {
-                        // if user option (@user_option value)
-                        if let Some(name) = name.strip_prefix('@') {
-                            window_options
-                                .user_options
-                                .insert(name.to_string(), cow_parse(value));
-                        }
-                    }
-                }
+            if let Some((name, i, value)) = get_parts(line) {
+                window_options.set_value(name, i, value);
             }
         }
 
         Ok(window_options)
     }
 }
+
+impl<'a> OptionsValuesTr for WindowOptions<'a> {
+    fn values(&self) -> Vec<(&'static str, Option<usize>, String)> {
+        let mut v = Vec::new();
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, AGGRESSIVE_RESIZE, &self.aggressive_resize);
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_3_0")))]
+        option_value(&mut v, ALLOW_RENAME, &self.allow_rename);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_0")))]
+        option_value(&mut v, ALTERNATE_SCREEN, &self.alternate_screen);
+        #[cfg(feature = "tmux_1_0")] // 0.8
+        option_value(&mut v, AUTOMATIC_RENAME, &self.automatic_rename);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(
+            &mut v,
+            AUTOMATIC_RENAME_FORMAT,
+            &self.automatic_rename_format,
+        );
+        #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_1")))]
+        option_value(&mut v, C0_CHANGE_INTERVAL, &self.c0_change_interval);
+        #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_1")))]
+        option_value(&mut v, C0_CHANGE_TRIGGER, &self.c0_change_trigger);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, CLOCK_MODE_COLOUR, &self.clock_mode_colour);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, CLOCK_MODE_STYLE, &self.clock_mode_style);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
+        option_value(&mut v, FORCE_HEIGHT, &self.force_height);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
+        option_value(&mut v, FORCE_WIDTH, &self.force_width);
+        #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_1_8")))]
+        option_value(&mut v, LAYOUT_HISTORY_LIMIT, &self.layout_history_limit);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, MAIN_PANE_HEIGHT, &self.main_pane_height);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, MAIN_PANE_WIDTH, &self.main_pane_width);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MODE_ATTR, &self.mode_attr);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MODE_BG, &self.mode_bg);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, MODE_FG, &self.mode_fg);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, MODE_KEYS, &self.mode_keys);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
+        option_value(&mut v, MODE_MOUSE, &self.mode_mouse);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(&mut v, MODE_STYLE, &self.mode_style);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, MONITOR_ACTIVITY, &self.monitor_activity);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
+        option_value(&mut v, MONITOR_CONTENT, &self.monitor_content);
+        #[cfg(feature = "tmux_2_6")]
+        option_value(&mut v, MONITOR_BELL, &self.monitor_bell);
+        #[cfg(feature = "tmux_1_4")]
+        option_value(&mut v, MONITOR_SILENCE, &self.monitor_silence);
+        #[cfg(feature = "tmux_1_4")]
+        option_value(&mut v, OTHER_PANE_HEIGHT, &self.other_pane_height);
+        #[cfg(feature = "tmux_1_4")]
+        option_value(&mut v, OTHER_PANE_WIDTH, &self.other_pane_width);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(
+            &mut v,
+            PANE_ACTIVE_BORDER_STYLE,
+            &self.pane_active_border_style,
+        );
+        #[cfg(feature = "tmux_1_6")]
+        option_value(&mut v, PANE_BASE_INDEX, &self.pane_base_index);
+        #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
+        option_value(&mut v, PANE_BORDER_BG, &self.pane_border_bg);
+        #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
+        option_value(&mut v, PANE_BORDER_FG, &self.pane_border_fg);
+        #[cfg(feature = "tmux_2_3")]
+        option_value(&mut v, PANE_BORDER_FORMAT, &self.pane_border_format);
+        #[cfg(feature = "tmux_2_3")]
+        option_value(&mut v, PANE_BORDER_STATUS, &self.pane_border_status);
+        #[cfg(feature = "tmux_2_0")]
+        option_value(&mut v, PANE_BORDER_STYLE, &self.pane_border_style);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_3_0")))]
+        option_value(&mut v, REMAIN_ON_EXIT, &self.remain_on_exit);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_2")))]
+        option_value(&mut v, SYNCHRONIZE_PANES, &self.synchronize_panes);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_2")))]
+        option_value(&mut v, UTF8, &self.utf8);
+        #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_3_0")))]
+        option_value(&mut v, WINDOW_ACTIVE_STYLE, &self.window_active_style);
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_BELL_ATTR,
+            &self.window_status_bell_attr,
+        );
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(&mut v, WINDOW_STATUS_BELL_BG, &self.window_status_bell_bg);
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(&mut v, WINDOW_STATUS_BELL_FG, &self.window_status_bell_fg);
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CONTENT_ATTR,
+            &self.window_status_content_attr,
+        );
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CONTENT_BG,
+            &self.window_status_content_bg,
+        );
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CONTENT_FG,
+            &self.window_status_content_fg,
+        );
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_ACTIVITY_ATTR,
+            &self.window_status_activity_attr,
+        );
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_ACTIVITY_BG,
+            &self.window_status_activity_bg,
+        );
+        #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_ACTIVITY_FG,
+            &self.window_status_activity_fg,
+        );
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, WINDOW_STATUS_ATTR, &self.window_status_attr);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, WINDOW_STATUS_BG, &self.window_status_bg);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(&mut v, WINDOW_STATUS_FG, &self.window_status_fg);
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CURRENT_ATTR,
+            &self.window_status_current_attr,
+        );
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CURRENT_BG,
+            &self.window_status_current_bg,
+        );
+        #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CURRENT_FG,
+            &self.window_status_current_fg,
+        );
+        #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_ALERT_ATTR,
+            &self.window_status_alert_attr,
+        );
+        #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
+        option_value(&mut v, WINDOW_STATUS_ALERT_BG, &self.window_status_alert_bg);
+        #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
+        option_value(&mut v, WINDOW_STATUS_ALERT_FG, &self.window_status_alert_fg);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_ACTIVITY_STYLE,
+            &self.window_status_activity_style,
+        );
+        #[cfg(feature = "tmux_1_9")]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_BELL_STYLE,
+            &self.window_status_bell_style,
+        );
+        #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CONTENT_STYLE,
+            &self.window_status_content_style,
+        );
+        #[cfg(feature = "tmux_1_2")]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CURRENT_FORMAT,
+            &self.window_status_current_format,
+        );
+        #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_LAST_ATTR,
+            &self.window_status_last_attr,
+        );
+        #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
+        option_value(&mut v, WINDOW_STATUS_LAST_BG, &self.window_status_last_bg);
+        #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
+        option_value(&mut v, WINDOW_STATUS_LAST_FG, &self.window_status_last_fg);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_CURRENT_STYLE,
+            &self.window_status_current_style,
+        );
+        #[cfg(feature = "tmux_1_2")]
+        option_value(&mut v, WINDOW_STATUS_FORMAT, &self.window_status_format);
+        #[cfg(feature = "tmux_1_9")]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_LAST_STYLE,
+            &self.window_status_last_style,
+        );
+        #[cfg(feature = "tmux_1_7")]
+        option_value(
+            &mut v,
+            WINDOW_STATUS_SEPARATOR,
+            &self.window_status_separator,
+        );
+        #[cfg(feature = "tmux_1_9")]
+        option_value(&mut v, WINDOW_STATUS_STYLE, &self.window_status_style);
+        #[cfg(feature = "tmux_2_9")]
+        option_value(&mut v, WINDOW_SIZE, &self.window_size);
+        #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_6")))]
+        option_value(&mut v, WORD_SEPARATORS, &self.word_separators);
+        #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_3_0")))]
+        option_value(&mut v, WINDOW_STYLE, &self.window_style);
+        #[cfg(feature = "tmux_1_7")]
+        option_value(&mut v, WRAP_SEARCH, &self.wrap_search);
+        #[cfg(feature = "tmux_1_0")]
+        option_value(&mut v, XTERM_KEYS, &self.xterm_keys);
+        // option_value(&mut v, USER_OPTIONS, &self.user_options);
+        v
+    }
+
+    fn set_value(&mut self, name: &str, _i: Option<usize>, value: Option<&str>) {
+        match name {
+            #[cfg(feature = "tmux_1_0")]
+            AGGRESSIVE_RESIZE => self.aggressive_resize = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_3_0")))]
+            ALLOW_RENAME => self.allow_rename = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_0")))]
+            ALTERNATE_SCREEN => self.alternate_screen = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")] // 0.8
+            AUTOMATIC_RENAME => self.automatic_rename = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_9")]
+            AUTOMATIC_RENAME_FORMAT => self.automatic_rename_format = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_1")))]
+            C0_CHANGE_INTERVAL => self.c0_change_interval = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_2_1")))]
+            C0_CHANGE_TRIGGER => self.c0_change_trigger = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            CLOCK_MODE_COLOUR => self.clock_mode_colour = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            CLOCK_MODE_STYLE => self.clock_mode_style = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
+            FORCE_HEIGHT => self.force_height = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
+            FORCE_WIDTH => self.force_width = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_7", not(feature = "tmux_1_8")))]
+            LAYOUT_HISTORY_LIMIT => self.layout_history_limit = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            MAIN_PANE_HEIGHT => self.main_pane_height = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            MAIN_PANE_WIDTH => self.main_pane_width = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            MODE_ATTR => self.mode_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            MODE_BG => self.mode_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            MODE_FG => self.mode_fg = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            MODE_KEYS => self.mode_keys = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_1")))]
+            MODE_MOUSE => self.mode_mouse = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_9")]
+            MODE_STYLE => self.mode_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_0")]
+            MONITOR_ACTIVITY => self.monitor_activity = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_0")))]
+            MONITOR_CONTENT => self.monitor_content = cow_parse(value),
+            #[cfg(feature = "tmux_2_6")]
+            MONITOR_BELL => self.monitor_bell = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_4")]
+            MONITOR_SILENCE => self.monitor_silence = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_4")]
+            OTHER_PANE_HEIGHT => self.other_pane_height = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_4")]
+            OTHER_PANE_WIDTH => self.other_pane_width = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_9")]
+            PANE_ACTIVE_BORDER_STYLE => self.pane_active_border_style = cow_parse(value),
+            #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
+            PANE_ACTIVE_BORDER_BG => self.pane_active_border_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
+            PANE_ACTIVE_BORDER_FG => self.pane_active_border_fg = cow_parse(value),
+            #[cfg(feature = "tmux_1_6")]
+            PANE_BASE_INDEX => self.pane_base_index = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
+            PANE_BORDER_BG => self.pane_border_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_0_8", not(feature = "tmux_1_9")))]
+            PANE_BORDER_FG => self.pane_border_fg = cow_parse(value),
+            #[cfg(feature = "tmux_2_3")]
+            PANE_BORDER_FORMAT => self.pane_border_format = cow_parse(value),
+            #[cfg(feature = "tmux_2_3")]
+            PANE_BORDER_STATUS => self.pane_border_status = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_2_0")]
+            PANE_BORDER_STYLE => self.pane_border_style = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_3_0")))]
+            REMAIN_ON_EXIT => self.remain_on_exit = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_3_2")))]
+            SYNCHRONIZE_PANES => self.synchronize_panes = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_2")))]
+            UTF8 => self.utf8 = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_3_0")))]
+            WINDOW_ACTIVE_STYLE => self.window_active_style = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_BELL_ATTR => self.window_status_bell_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_BELL_BG => self.window_status_bell_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_BELL_FG => self.window_status_bell_fg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_CONTENT_ATTR => self.window_status_content_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_CONTENT_BG => self.window_status_content_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_CONTENT_FG => self.window_status_content_fg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_ACTIVITY_ATTR => self.window_status_activity_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_ACTIVITY_BG => self.window_status_activity_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_ACTIVITY_FG => self.window_status_activity_fg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_ATTR => self.window_status_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_BG => self.window_status_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_FG => self.window_status_fg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_CURRENT_ATTR => self.window_status_current_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_CURRENT_BG => self.window_status_current_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_CURRENT_FG => self.window_status_current_fg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
+            WINDOW_STATUS_ALERT_ATTR => self.window_status_alert_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
+            WINDOW_STATUS_ALERT_BG => self.window_status_alert_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_3", not(feature = "tmux_1_6")))]
+            WINDOW_STATUS_ALERT_FG => self.window_status_alert_fg = cow_parse(value),
+            #[cfg(feature = "tmux_1_9")]
+            WINDOW_STATUS_ACTIVITY_STYLE => self.window_status_activity_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_9")]
+            WINDOW_STATUS_BELL_STYLE => self.window_status_bell_style = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_9", not(feature = "tmux_2_0")))]
+            WINDOW_STATUS_CONTENT_STYLE => self.window_status_content_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_2")]
+            WINDOW_STATUS_CURRENT_FORMAT => self.window_status_current_format = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_LAST_ATTR => self.window_status_last_attr = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_LAST_BG => self.window_status_last_bg = cow_parse(value),
+            #[cfg(all(feature = "tmux_1_8", not(feature = "tmux_1_9")))]
+            WINDOW_STATUS_LAST_FG => self.window_status_last_fg = cow_parse(value),
+            #[cfg(feature = "tmux_1_9")]
+            WINDOW_STATUS_CURRENT_STYLE => self.window_status_current_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_2")]
+            WINDOW_STATUS_FORMAT => self.window_status_format = cow_parse(value),
+            #[cfg(feature = "tmux_1_9")]
+            WINDOW_STATUS_LAST_STYLE => self.window_status_last_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_7")]
+            WINDOW_STATUS_SEPARATOR => self.window_status_separator = cow_parse(value),
+            #[cfg(feature = "tmux_1_9")]
+            WINDOW_STATUS_STYLE => self.window_status_style = cow_parse(value),
+            #[cfg(feature = "tmux_2_9")]
+            WINDOW_SIZE => self.window_size = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_1_2", not(feature = "tmux_1_6")))]
+            WORD_SEPARATORS => self.word_separators = value.and_then(|s| s.parse().ok()),
+            #[cfg(all(feature = "tmux_2_1", not(feature = "tmux_3_0")))]
+            WINDOW_STYLE => self.window_style = cow_parse(value),
+            #[cfg(feature = "tmux_1_7")]
+            WRAP_SEARCH => self.wrap_search = value.and_then(|s| s.parse().ok()),
+            #[cfg(feature = "tmux_1_0")]
+            XTERM_KEYS => self.xterm_keys = value.and_then(|s| s.parse().ok()),
+            _ => {
+                // if user option (@user_option value)
+                if let Some(name) = name.strip_prefix('@') {
+                    self.user_options.insert(name.to_string(), cow_parse(value));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! Streaming of the pane output into the Rust program (`pipe-pane`)
+pub mod pane_log;
+pub mod pane_stream;
+
+pub use pane_log::PaneLog;
+pub use pane_stream::PaneStream;
+
+#[cfg(test)]
+#[path = "."]
+mod pipe_tests {
+    pub mod pane_log_tests;
+    pub mod pane_stream_tests;
+}
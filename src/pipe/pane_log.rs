@@ -0,0 +1,168 @@
+use crate::Error;
+use std::fs::{self, File, OpenOptions};
+use std::io::{ErrorKind, Read, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const LOG_EXTENSION: &str = "log";
+/// default log file size limit (10 MiB)
+const MAX_SIZE: u64 = 10 * 1024 * 1024;
+/// default number of rotated log files
+const MAX_FILES: usize = 5;
+const BUFFER_SIZE: usize = 8 * 1024;
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Timestamped rotating log file of the pane output
+///
+/// Each line is prefixed with the UTC time it was read (`2024-01-31T12:00:00.000Z `). The log
+/// file (`<dir>/<name>.log`) is rotated (`<name>.log.1` ... `<name>.log.<max_files>`, the oldest
+/// is removed) when it would exceed the size limit.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::thread;
+/// use tmux_interface::{PaneLog, PaneStream};
+///
+/// let logger = thread::spawn(|| {
+///     let stream = PaneStream::new("%1")?;
+///     PaneLog::new("/tmp/logs", "pane_1")
+///         .max_size(1024 * 1024)
+///         .record(stream)
+/// });
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PaneLog {
+    /// Directory of the log files, created if missing
+    pub dir: PathBuf,
+    /// Log file name (without extension)
+    pub name: String,
+    /// Log file size limit in bytes
+    pub max_size: u64,
+    /// Number of rotated log files kept
+    pub max_files: usize,
+}
+
+impl PaneLog {
+    pub fn new<P: Into<PathBuf>, S: Into<String>>(dir: P, name: S) -> Self {
+        Self {
+            dir: dir.into(),
+            name: name.into(),
+            max_size: MAX_SIZE,
+            max_files: MAX_FILES,
+        }
+    }
+
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Current log file path
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(format!("{}.{}", self.name, LOG_EXTENSION))
+    }
+
+    /// Rotated log file path (`1` is the newest)
+    pub fn rotated_path(&self, i: usize) -> PathBuf {
+        self.dir
+            .join(format!("{}.{}.{}", self.name, LOG_EXTENSION, i))
+    }
+
+    /// Write the output to the log until the end of the stream (e.g.
+    /// [`PaneStream`](crate::PaneStream))
+    pub fn record<R: Read>(&self, mut reader: R) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path())?;
+        let mut size = file.metadata()?.len();
+        let mut buf = [0; BUFFER_SIZE];
+        let mut line_start = true;
+        loop {
+            let len = match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err.into()),
+            };
+
+            let prefix = format!("{} ", timestamp(SystemTime::now()));
+            let mut chunk = Vec::with_capacity(len);
+            for &byte in &buf[..len] {
+                if line_start {
+                    chunk.extend_from_slice(prefix.as_bytes());
+                }
+                chunk.push(byte);
+                line_start = byte == b'\n';
+            }
+
+            if size > 0 && size + chunk.len() as u64 > self.max_size {
+                file = self.rotate()?;
+                size = 0;
+            }
+            file.write_all(&chunk)?;
+            size += chunk.len() as u64;
+        }
+        file.flush()?;
+        Ok(())
+    }
+
+    // rotated files are renamed (the oldest is replaced), new log file is created
+    fn rotate(&self) -> Result<File, Error> {
+        let path = self.path();
+        match self.max_files {
+            0 => fs::remove_file(&path)?,
+            _ => {
+                for i in (1..self.max_files).rev() {
+                    let rotated = self.rotated_path(i);
+                    if rotated.exists() {
+                        fs::rename(rotated, self.rotated_path(i + 1))?;
+                    }
+                }
+                fs::rename(&path, self.rotated_path(1))?;
+            }
+        }
+        Ok(File::create(path)?)
+    }
+}
+
+// UTC time `YYYY-MM-DDTHH:MM:SS.mmmZ`
+pub(crate) fn timestamp(time: SystemTime) -> String {
+    let duration = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let (days, seconds) = (
+        duration.as_secs() / SECONDS_PER_DAY,
+        duration.as_secs() % SECONDS_PER_DAY,
+    );
+
+    // civil date from days since epoch (era of 400 years, year starting in March)
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let (year, month) = match month {
+        0..=9 => (era * 400 + year_of_era, month + 3),
+        _ => (era * 400 + year_of_era + 1, month - 9),
+    };
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60,
+        duration.subsec_millis()
+    )
+}
@@ -0,0 +1,50 @@
+#[test]
+fn timestamp() {
+    use crate::pipe::pane_log::timestamp;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let time = |secs: u64, millis: u64| UNIX_EPOCH + Duration::from_millis(secs * 1000 + millis);
+    assert_eq!(timestamp(time(0, 0)), "1970-01-01T00:00:00.000Z");
+    assert_eq!(timestamp(time(951_782_400, 5)), "2000-02-29T00:00:00.005Z");
+    assert_eq!(
+        timestamp(time(1_700_000_000, 120)),
+        "2023-11-14T22:13:20.120Z"
+    );
+    assert_eq!(
+        timestamp(time(4_102_444_799, 999)),
+        "2099-12-31T23:59:59.999Z"
+    );
+}
+
+#[test]
+fn pane_log() {
+    use crate::PaneLog;
+    use std::fs;
+
+    let dir = std::env::temp_dir().join(format!("pane_log_test_{}", std::process::id()));
+    let log = PaneLog::new(&dir, "pane_1").max_size(40).max_files(2);
+
+    log.record("first\nsecond".as_bytes()).unwrap();
+    let content = fs::read_to_string(log.path()).unwrap();
+    let lines: Vec<&str> = content.split('\n').collect();
+    // `YYYY-MM-DDTHH:MM:SS.mmmZ ` prefix
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].ends_with("Z first") && lines[0].len() == 30);
+    assert!(lines[1].ends_with("Z second") && lines[1].len() == 31);
+
+    // rotated when the size limit would be exceeded, the oldest removed
+    log.record("third\n".as_bytes()).unwrap();
+    log.record("fourth\n".as_bytes()).unwrap();
+    assert!(fs::read_to_string(log.path())
+        .unwrap()
+        .ends_with("Z fourth\n"));
+    assert!(fs::read_to_string(log.rotated_path(1))
+        .unwrap()
+        .ends_with("Z third\n"));
+    assert!(fs::read_to_string(log.rotated_path(2))
+        .unwrap()
+        .ends_with("Z second"));
+    assert!(!log.rotated_path(3).exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
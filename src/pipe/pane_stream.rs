@@ -0,0 +1,172 @@
+use crate::sync::channel::channel_name;
+use crate::{DisplayMessage, Error, PipePane, Tmux, TmuxCommand, TmuxOutput};
+use std::borrow::Cow;
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+const FIFO_PREFIX: &str = "tmux_interface_pipe";
+const MKFIFO: &str = "mkfifo";
+const PANE_DEAD: &str = "#{pane_dead}";
+const PANE_ALIVE: &str = "0";
+const PANE_PIPE: &str = "#{pane_pipe}";
+const PIPE_CLOSED: &str = "0";
+const PIPE_EXISTS: &str = "output of the pane is piped already";
+// the pipe command is started by the shell
+const OPEN_TIMEOUT: Duration = Duration::from_secs(5);
+const SINGLE_QUOTE: char = '\'';
+
+/// Output of the pane streamed into the Rust program (raw bytes, escape sequences included)
+///
+/// The output is piped (`pipe-pane -o -O`) into the FIFO (temporary file created by `mkfifo`)
+/// read by the stream, the pane output must not be piped already ([`Error::Tmux`]). The stream
+/// ends (`read()` returns `0`) when the pane is closed or the output is piped elsewhere,
+/// [`pane_dead()`](PaneStream::pane_dead) tells if the pane died. [`Error::Timeout`] is returned
+/// if the pipe command does not open the FIFO in time.
+///
+/// The FIFO is closed and removed when dropped, the pipe command exits and tmux closes the pipe
+/// on the next output of the pane (the pipe started by others later is not closed).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io::{BufRead, BufReader};
+/// use tmux_interface::PaneStream;
+///
+/// let stream = PaneStream::new("%1").unwrap();
+/// for line in BufReader::new(stream).lines() {
+///     println!("{}", line.unwrap());
+/// }
+/// ```
+pub struct PaneStream<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    pub target_pane: Cow<'a, str>,
+    fifo: PathBuf,
+    // opened after the pipe command opened the FIFO for writing
+    file: File,
+}
+
+impl<'a> PaneStream<'a> {
+    /// Start streaming the output of the pane
+    pub fn new<S: Into<Cow<'a, str>>>(target_pane: S) -> Result<Self, Error> {
+        Self::with_invoker(target_pane, &|cmd| Tmux::with_command(cmd).output())
+    }
+
+    pub fn with_invoker<S: Into<Cow<'a, str>>>(
+        target_pane: S,
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Result<Self, Error> {
+        let target_pane = target_pane.into();
+        let fifo = env::temp_dir().join(channel_name(FIFO_PREFIX));
+        let output = Command::new(MKFIFO).arg(&fifo).output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(io::Error::other(stderr.trim()).into());
+        }
+
+        let file = open(invoker, target_pane.clone(), &fifo);
+        if file.is_err() {
+            fs::remove_file(&fifo)?;
+        }
+        let file = file?;
+        Ok(Self {
+            invoker,
+            target_pane,
+            fifo,
+            file,
+        })
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Pane died (`#{pane_dead}`, `remain-on-exit` is set) or does not exist anymore
+    pub fn pane_dead(&self) -> Result<bool, Error> {
+        let display_message = DisplayMessage::new()
+            .print()
+            .target_pane(self.target_pane.clone())
+            .message(PANE_DEAD);
+        let output = (self.invoker)(display_message.build())?;
+        // missing pane is an error or an empty output
+        Ok(!output.success() || output.to_string().trim() != PANE_ALIVE)
+    }
+
+    /// Stop piping the output (`pipe-pane` without command, the current pipe of the pane is
+    /// closed), the remaining output can be read until the end of the stream
+    pub fn stop(&self) -> Result<(), Error> {
+        let pipe_pane = PipePane::new().target_pane(self.target_pane.clone());
+        (self.invoker)(pipe_pane.build())?.into_result()?;
+        Ok(())
+    }
+}
+
+impl<'a> Read for PaneStream<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.file.read(buf)
+    }
+}
+
+impl<'a> Drop for PaneStream<'a> {
+    fn drop(&mut self) {
+        // pipe command exits when the file is closed, the pipe of the pane is not closed here
+        let _ = fs::remove_file(&self.fifo);
+    }
+}
+
+// pipe the output into the FIFO, waits until the pipe command opens it
+fn open<'a>(
+    invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    target_pane: Cow<'a, str>,
+    fifo: &Path,
+) -> Result<File, Error> {
+    // existing pipe would be closed by `-o`
+    let display_message = DisplayMessage::new()
+        .print()
+        .target_pane(target_pane.clone())
+        .message(PANE_PIPE);
+    let output = invoker(display_message.build())?.into_result()?;
+    if output.to_string().trim() != PIPE_CLOSED {
+        return Err(Error::Tmux(PIPE_EXISTS.to_string()));
+    }
+
+    // pane output connected to the command stdin (`-O`), opened only if no pipe exists (`-o`)
+    let pipe_pane = PipePane::new()
+        .open()
+        .stdin()
+        .target_pane(target_pane)
+        .shell_command(format!("cat > {}", shell_quote(fifo)));
+    invoker(pipe_pane.build())?.into_result()?;
+    open_with_timeout(fifo, OPEN_TIMEOUT)
+}
+
+// opening the FIFO for reading blocks until it is opened for writing
+fn open_with_timeout(fifo: &Path, timeout: Duration) -> Result<File, Error> {
+    let (sender, receiver) = mpsc::channel();
+    let path = fifo.to_path_buf();
+    let opener = thread::spawn(move || {
+        let _ = sender.send(File::open(path));
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(file) => Ok(file?),
+        Err(_) => {
+            // blocked opening is completed by the writer opened here
+            let writer = OpenOptions::new().write(true).open(fifo);
+            let _ = opener.join();
+            drop(writer);
+            Err(Error::Timeout)
+        }
+    }
+}
+
+// single quoted shell argument
+fn shell_quote(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    let escaped = path.replace(SINGLE_QUOTE, "'\\''");
+    format!("{}{}{}", SINGLE_QUOTE, escaped, SINGLE_QUOTE)
+}
@@ -0,0 +1,58 @@
+#[test]
+fn pane_stream() {
+    use crate::{mock_output, Error, MockInvoker, PaneStream};
+    use std::cell::{Cell, RefCell};
+    use std::io::Read;
+    use std::process::Command;
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let (display, pipe) = ("display-message", "pipe-pane");
+    #[cfg(feature = "cmd_alias")]
+    let (display, pipe) = ("display", "pipep");
+
+    let mock = MockInvoker::new();
+    let children = RefCell::new(Vec::new());
+    let piped = Cell::new("0\n");
+    let invoker = mock.invoker(|cmd| {
+        if cmd.starts_with(display) {
+            return mock_output(0, piped.get());
+        }
+        // pipe command started by tmux, the pane output replaced
+        if let Some(i) = cmd.find("cat > ") {
+            let child = Command::new("sh")
+                .args(["-c", &format!("printf 'out\\n' | {}", &cmd[i..])])
+                .spawn()?;
+            children.borrow_mut().push(child);
+        }
+        mock_output(0, "")
+    });
+
+    let mut stream = PaneStream::with_invoker("%1", &invoker).unwrap();
+    let mut output = String::new();
+    stream.read_to_string(&mut output).unwrap();
+    assert_eq!(output, "out\n");
+    for mut child in children.borrow_mut().drain(..) {
+        assert!(child.wait().unwrap().success());
+    }
+    assert_eq!(
+        mock.cmds.borrow()[0],
+        format!("{} -p -t %1 #{{pane_pipe}}", display)
+    );
+    let fifo = mock.cmds.borrow()[1]
+        .trim_start_matches(&format!("{} -O -o -t %1 cat > ", pipe))
+        .trim_matches('\'')
+        .to_string();
+    assert!(std::path::Path::new(&fifo).exists());
+
+    // pipe of the pane is not closed
+    drop(stream);
+    assert_eq!(mock.cmds.borrow().len(), 2);
+    assert!(!std::path::Path::new(&fifo).exists());
+
+    // output piped already, the pipe is not replaced
+    mock.clear();
+    piped.set("1\n");
+    let result = PaneStream::with_invoker("%1", &invoker);
+    assert!(matches!(result, Err(Error::Tmux(_))));
+    assert_eq!(mock.cmds.borrow().len(), 1);
+}
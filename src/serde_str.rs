@@ -0,0 +1,71 @@
+//! `Serialize` and `Deserialize` implementations using the tmux string form of a type
+//! (`Display` and `FromStr`), e.g. layouts, styles, flags and option values
+
+/// Implement `Serialize` and `Deserialize` for the type using its `Display` and `FromStr`
+/// implementations
+///
+/// ```text
+/// serde_str!(Layout);
+/// serde_str!(<'a> StyleList<'a>);
+/// ```
+macro_rules! serde_str {
+    (<$($lt:lifetime),*> $t:ty) => {
+        impl<$($lt),*> serde::Serialize for $t {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de, $($lt),*> serde::Deserialize<'de> for $t {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(|_| {
+                    serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Str(&s),
+                        &stringify!($t),
+                    )
+                })
+            }
+        }
+    };
+    ($t:ty) => {
+        serde_str!(<> $t);
+    };
+}
+
+/// Implement `Serialize` for the type using its `Display` implementation
+///
+/// ```text
+/// serialize_str!(<'a> TargetPaneExt<'a>);
+/// ```
+macro_rules! serialize_str {
+    (<$($lt:lifetime),*> $t:ty) => {
+        impl<$($lt),*> serde::Serialize for $t {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+    };
+}
+
+/// Implement `Serialize` and `Deserialize` for the type borrowing its values, using its
+/// `Display` and `From<&str>` implementations
+///
+/// ```text
+/// serde_str_borrowed!(TargetPane);
+/// ```
+macro_rules! serde_str_borrowed {
+    ($t:ident) => {
+        serialize_str!(<'a> $t<'a>);
+
+        impl<'de: 'a, 'a> serde::Deserialize<'de> for $t<'a> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <&'de str>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+#[path = "serde_str_tests.rs"]
+mod serde_str_tests;
@@ -0,0 +1,141 @@
+#[test]
+fn layout() {
+    use crate::{Layout, LayoutCell};
+
+    let s = "cf3a,200x50,0,0{100x50,0,0,0,99x50,101,0,1}";
+    let layout: Layout = s.parse().unwrap();
+    let json = serde_json::to_string(&layout).unwrap();
+    assert_eq!(json, format!("\"{}\"", s));
+    assert_eq!(serde_json::from_str::<Layout>(&json).unwrap(), layout);
+
+    let cell: LayoutCell = "100x50,0,0,0".parse().unwrap();
+    assert_eq!(serde_json::to_string(&cell).unwrap(), "\"100x50,0,0,0\"");
+
+    assert!(serde_json::from_str::<Layout>("\"bad\"").is_err());
+}
+
+#[test]
+fn flags() {
+    use crate::{PaneTabs, SessionStack, WindowFlags};
+
+    let flags: WindowFlags = "*Z".parse().unwrap();
+    assert_eq!(serde_json::to_string(&flags).unwrap(), "\"*Z\"");
+    assert_eq!(
+        serde_json::from_str::<WindowFlags>("\"*Z\"").unwrap(),
+        flags
+    );
+
+    let stack = SessionStack(vec![1, 3, 2]);
+    assert_eq!(serde_json::to_string(&stack).unwrap(), "\"1,3,2\"");
+    assert_eq!(
+        serde_json::from_str::<SessionStack>("\"1,3,2\"").unwrap(),
+        stack
+    );
+
+    let tabs = PaneTabs(vec![8, 16]);
+    assert_eq!(serde_json::to_string(&tabs).unwrap(), "\"8,16\"");
+    assert_eq!(serde_json::from_str::<PaneTabs>("\"8,16\"").unwrap(), tabs);
+}
+
+#[test]
+fn styles() {
+    use crate::{Colour, Style, StyleList};
+
+    let style = Style::Fg(Colour::ColourSet256(100));
+    assert_eq!(serde_json::to_string(&style).unwrap(), "\"fg=colour100\"");
+    assert_eq!(
+        serde_json::from_str::<Style>("\"fg=colour100\"").unwrap(),
+        style
+    );
+    assert_eq!(
+        serde_json::from_str::<Colour>("\"#ff0000\"").unwrap(),
+        Colour::HEX(0xff0000)
+    );
+
+    let styles: StyleList = "fg=red,bold".parse().unwrap();
+    let json = serde_json::to_string(&styles).unwrap();
+    assert_eq!(json, "\"fg=red,bold\"");
+    assert_eq!(serde_json::from_str::<StyleList>(&json).unwrap(), styles);
+}
+
+#[cfg(feature = "tmux_1_0")]
+#[test]
+fn options() {
+    use crate::{SessionOptions, Switch};
+
+    assert_eq!(serde_json::to_string(&Switch::On).unwrap(), "\"on\"");
+    assert_eq!(
+        serde_json::from_str::<Switch>("\"off\"").unwrap(),
+        Switch::Off
+    );
+
+    let options = SessionOptions::new().status_left(Some("#S"));
+    let json = serde_json::to_string(&options).unwrap();
+    assert_eq!(
+        serde_json::from_str::<SessionOptions>(&json).unwrap(),
+        options
+    );
+}
+
+#[cfg(feature = "tmux_1_6")]
+#[test]
+fn variables() {
+    use crate::{Session, Window};
+
+    let window = Window {
+        name: Some("editor".to_string()),
+        layout: Some("d963,80x24,0,0,43".parse().unwrap()),
+        ..Default::default()
+    };
+    let value = serde_json::to_value(&window).unwrap();
+    assert_eq!(value["name"], "editor");
+    assert_eq!(value["layout"], "d963,80x24,0,0,43");
+    assert_eq!(serde_json::from_value::<Window>(value).unwrap(), window);
+
+    let session = Session {
+        name: Some("project".to_string()),
+        ..Default::default()
+    };
+    let json = serde_json::to_string(&session).unwrap();
+    assert_eq!(serde_json::from_str::<Session>(&json).unwrap(), session);
+}
+
+#[test]
+fn targets() {
+    use crate::{
+        TargetPane, TargetPaneExt, TargetPaneToken, TargetSession, TargetWindow, TargetWindowExt,
+        TargetWindowToken,
+    };
+
+    let json = serde_json::to_string(&TargetSession::ExactName("project")).unwrap();
+    assert_eq!(json, "\"=project\"");
+    assert!(matches!(
+        serde_json::from_str::<TargetSession>(&json).unwrap(),
+        TargetSession::ExactName("project")
+    ));
+
+    let json = serde_json::to_string(&TargetWindow::Token(TargetWindowToken::Last)).unwrap();
+    assert_eq!(json, "\":!\"");
+    assert!(matches!(
+        serde_json::from_str::<TargetWindow>(&json).unwrap(),
+        TargetWindow::Token(TargetWindowToken::Last)
+    ));
+
+    let json = serde_json::to_string(&TargetPane::Id(1)).unwrap();
+    assert_eq!(json, "\"%1\"");
+    assert!(matches!(
+        serde_json::from_str::<TargetPane>(&json).unwrap(),
+        TargetPane::Id(1)
+    ));
+    assert!(matches!(
+        serde_json::from_str::<TargetPaneToken>("\"{top-left}\"").unwrap(),
+        TargetPaneToken::TopLeft
+    ));
+    assert!(serde_json::from_str::<TargetWindowToken>("\"{top}\"").is_err());
+
+    let session = TargetSession::Id(1);
+    let window = TargetWindowExt::index(Some(&session), 2);
+    let pane = TargetPaneExt::index(Some(&window), 3);
+    assert_eq!(serde_json::to_string(&window).unwrap(), "\"$1:2\"");
+    assert_eq!(serde_json::to_string(&pane).unwrap(), "\"$1:2.3\"");
+}
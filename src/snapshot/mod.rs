@@ -0,0 +1,12 @@
+//! Session snapshot and restore (resurrect-style)
+pub mod snapshot;
+pub mod snapshot_ctl;
+
+pub use snapshot::{PaneSnapshot, SessionSnapshot, Snapshot, WindowSnapshot, SNAPSHOT_VERSION};
+pub use snapshot_ctl::SnapshotCtl;
+
+#[cfg(test)]
+#[path = "."]
+mod snapshot_tests {
+    pub mod snapshot_tests;
+}
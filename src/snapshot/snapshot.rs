@@ -0,0 +1,289 @@
+use crate::{Error, Layout};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Current snapshot file format version
+pub const SNAPSHOT_VERSION: usize = 1;
+
+const SNAPSHOT_HEADER: &str = "tmux_interface_snapshot";
+const SNAPSHOT_SESSION: &str = "session";
+const SNAPSHOT_WINDOW: &str = "window";
+const SNAPSHOT_PANE: &str = "pane";
+const SNAPSHOT_SEPARATOR: char = '\t';
+
+/// State of all sessions of a tmux server (resurrect-style), see
+/// [`SnapshotCtl`](crate::SnapshotCtl)
+///
+/// Snapshot is saved as a line based, tab separated, versioned text file:
+///
+/// ```text
+/// tmux_interface_snapshot <version>
+/// session <name>
+/// window <index> <name> <layout> <active> <zoomed>
+/// pane <index> <current_path> <start_command> <current_command> <active> <contents>
+/// ```
+///
+/// Windows belong to the preceding session, panes to the preceding window. Tabs, carriage
+/// returns, new lines and backslashes in the values are escaped (`\t`, `\r`, `\n`, `\\`)
+#[derive(Clone, PartialEq, Debug)]
+pub struct Snapshot {
+    /// file format version
+    pub version: usize,
+    pub sessions: Vec<SessionSnapshot>,
+}
+
+/// Session state, part of the [`Snapshot`]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SessionSnapshot {
+    /// `session_name`
+    pub name: String,
+    pub windows: Vec<WindowSnapshot>,
+}
+
+/// Window state, part of the [`SessionSnapshot`]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct WindowSnapshot {
+    /// `window_index`
+    pub index: usize,
+    /// `window_name`
+    pub name: String,
+    /// `window_layout`
+    pub layout: Option<Layout>,
+    /// `window_active`
+    pub active: bool,
+    /// `window_zoomed_flag`
+    pub zoomed: bool,
+    pub panes: Vec<PaneSnapshot>,
+}
+
+/// Pane state, part of the [`WindowSnapshot`]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct PaneSnapshot {
+    /// `pane_index`
+    pub index: usize,
+    /// `pane_current_path`
+    pub current_path: Option<String>,
+    /// `pane_start_command`
+    pub start_command: Option<String>,
+    /// `pane_current_command`
+    pub current_command: Option<String>,
+    /// `pane_active`
+    pub active: bool,
+    /// pane scrollback (`capture-pane -p -S -`), if requested, export only (not restored)
+    pub contents: Option<String>,
+}
+
+impl Default for Snapshot {
+    fn default() -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            sessions: Vec::new(),
+        }
+    }
+}
+
+impl Snapshot {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Load snapshot from file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Save snapshot to file
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+}
+
+impl PaneSnapshot {
+    /// Command to be re-run in the restored pane, if its program is in `commands`
+    ///
+    /// `pane_start_command` is preferred, `pane_current_command` is used otherwise
+    pub fn restore_command(&self, commands: &[&str]) -> Option<&str> {
+        let allowed = |s: &str| matches!(s.split_whitespace().next(), Some(program) if commands.contains(&program));
+        self.start_command
+            .as_deref()
+            // tmux quotes the start command (e.g. `"vim file"`)
+            .map(|s| {
+                s.strip_prefix('"')
+                    .and_then(|s| s.strip_suffix('"'))
+                    .unwrap_or(s)
+            })
+            .filter(|s| allowed(s))
+            .or_else(|| self.current_command.as_deref().filter(|s| allowed(s)))
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(s: &str) -> Result<String, Error> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('\\') => unescaped.push('\\'),
+                Some('t') => unescaped.push('\t'),
+                Some('r') => unescaped.push('\r'),
+                Some('n') => unescaped.push('\n'),
+                _ => return Err(Error::ParseSnapshot),
+            },
+            c => unescaped.push(c),
+        }
+    }
+    Ok(unescaped)
+}
+
+fn escape_option(s: &Option<String>) -> String {
+    s.as_deref().map(escape).unwrap_or_default()
+}
+
+fn unescape_option(s: &str) -> Result<Option<String>, Error> {
+    if s.is_empty() {
+        Ok(None)
+    } else {
+        unescape(s).map(Some)
+    }
+}
+
+fn parse_bool(s: &str) -> Result<bool, Error> {
+    match s {
+        "1" => Ok(true),
+        "0" => Ok(false),
+        _ => Err(Error::ParseSnapshot),
+    }
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sep = SNAPSHOT_SEPARATOR;
+        writeln!(f, "{}{}{}", SNAPSHOT_HEADER, sep, self.version)?;
+        for session in &self.sessions {
+            writeln!(f, "{}{}{}", SNAPSHOT_SESSION, sep, escape(&session.name))?;
+            for window in &session.windows {
+                writeln!(
+                    f,
+                    "{}{sep}{}{sep}{}{sep}{}{sep}{}{sep}{}",
+                    SNAPSHOT_WINDOW,
+                    window.index,
+                    escape(&window.name),
+                    window
+                        .layout
+                        .as_ref()
+                        .map(|l| l.to_string())
+                        .unwrap_or_default(),
+                    window.active as u8,
+                    window.zoomed as u8,
+                    sep = sep
+                )?;
+                for pane in &window.panes {
+                    writeln!(
+                        f,
+                        "{}{sep}{}{sep}{}{sep}{}{sep}{}{sep}{}{sep}{}",
+                        SNAPSHOT_PANE,
+                        pane.index,
+                        escape_option(&pane.current_path),
+                        escape_option(&pane.start_command),
+                        escape_option(&pane.current_command),
+                        pane.active as u8,
+                        escape_option(&pane.contents),
+                        sep = sep
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Snapshot {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut lines = s.lines();
+
+        let header: Vec<&str> = lines
+            .next()
+            .ok_or(Error::ParseSnapshot)?
+            .split(SNAPSHOT_SEPARATOR)
+            .collect();
+        let version = match header.as_slice() {
+            [SNAPSHOT_HEADER, version] => version.parse::<usize>()?,
+            _ => return Err(Error::ParseSnapshot),
+        };
+        if version != SNAPSHOT_VERSION {
+            return Err(Error::ParseSnapshot);
+        }
+
+        let mut snapshot = Snapshot {
+            version,
+            sessions: Vec::new(),
+        };
+        for line in lines.filter(|line| !line.is_empty()) {
+            let fields: Vec<&str> = line.split(SNAPSHOT_SEPARATOR).collect();
+            match fields.as_slice() {
+                [SNAPSHOT_SESSION, name] => snapshot.sessions.push(SessionSnapshot {
+                    name: unescape(name)?,
+                    windows: Vec::new(),
+                }),
+                [SNAPSHOT_WINDOW, index, name, layout, active, zoomed] => {
+                    let window = WindowSnapshot {
+                        index: index.parse()?,
+                        name: unescape(name)?,
+                        layout: match *layout {
+                            "" => None,
+                            layout => Some(layout.parse()?),
+                        },
+                        active: parse_bool(active)?,
+                        zoomed: parse_bool(zoomed)?,
+                        panes: Vec::new(),
+                    };
+                    snapshot
+                        .sessions
+                        .last_mut()
+                        .ok_or(Error::ParseSnapshot)?
+                        .windows
+                        .push(window);
+                }
+                [SNAPSHOT_PANE, index, current_path, start_command, current_command, active, contents] =>
+                {
+                    let pane = PaneSnapshot {
+                        index: index.parse()?,
+                        current_path: unescape_option(current_path)?,
+                        start_command: unescape_option(start_command)?,
+                        current_command: unescape_option(current_command)?,
+                        active: parse_bool(active)?,
+                        contents: unescape_option(contents)?,
+                    };
+                    snapshot
+                        .sessions
+                        .last_mut()
+                        .and_then(|session| session.windows.last_mut())
+                        .ok_or(Error::ParseSnapshot)?
+                        .panes
+                        .push(pane);
+                }
+                _ => return Err(Error::ParseSnapshot),
+            }
+        }
+        Ok(snapshot)
+    }
+}
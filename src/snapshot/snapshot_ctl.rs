@@ -0,0 +1,274 @@
+use crate::{
+    CapturePane, Error, HasSession, MoveWindow, NewSession, NewWindow, PaneSnapshot, PanesCtl,
+    ResizePane, SelectLayout, SelectPane, SelectWindow, SendKeys, SessionSnapshot, SessionsCtl,
+    Snapshot, SplitWindow, Tmux, TmuxCommand, TmuxCommands, TmuxOutput, WindowSnapshot, WindowsCtl,
+};
+
+const ENTER_KEY: &str = "Enter";
+const NEW_SESSION_FORMAT: &str = "#{window_id} #{window_index}";
+const WINDOW_ID_FORMAT: &str = "#{window_id}";
+const PANE_ID_FORMAT: &str = "#{pane_id}";
+/// layout spreading the panes evenly, the window has space for the next split
+const TILED_LAYOUT: &str = "tiled";
+/// `capture-pane -S -` start of the history
+const HISTORY_START: &str = "-";
+
+/// Record all sessions, windows and panes of a tmux server into a [`Snapshot`] and
+/// restore them into a (fresh) server
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{Snapshot, SnapshotCtl};
+///
+/// let snapshot = SnapshotCtl::new().snapshot(false).unwrap();
+/// snapshot.to_file("/tmp/tmux.snapshot").unwrap();
+///
+/// // ... server restart
+///
+/// let snapshot = Snapshot::from_file("/tmp/tmux.snapshot").unwrap();
+/// SnapshotCtl::new().restore(&snapshot, &["vim", "htop"]).unwrap();
+/// ```
+pub struct SnapshotCtl<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+}
+
+impl<'a> Default for SnapshotCtl<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+        }
+    }
+}
+
+impl<'a> SnapshotCtl<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self { invoker }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Record all sessions, `scrollback` additionally captures the pane history
+    /// (`capture-pane -p -S -`) for export, it is not restored
+    pub fn snapshot(&self, scrollback: bool) -> Result<Snapshot, Error> {
+        let mut snapshot = Snapshot::new();
+
+        for session in SessionsCtl::get_all_ext(self.invoker)? {
+            let name = session.name.ok_or(Error::Snapshot)?;
+            let mut session_snapshot = SessionSnapshot {
+                name: name.clone(),
+                windows: Vec::new(),
+            };
+
+            for window in WindowsCtl::get_all_ext(Some(format!("={}", name)), self.invoker)? {
+                let window_id = window.id.ok_or(Error::Snapshot)?;
+                let mut window_snapshot = WindowSnapshot {
+                    index: window.index.ok_or(Error::Snapshot)?,
+                    name: window.name.unwrap_or_default(),
+                    layout: window.layout,
+                    active: window.active.unwrap_or_default(),
+                    zoomed: window.zoomed_flag.unwrap_or_default(),
+                    panes: Vec::new(),
+                };
+
+                for pane in PanesCtl::get_all_ext(Some(format!("@{}", window_id)), self.invoker)? {
+                    let contents = match (scrollback, pane.id) {
+                        (true, Some(pane_id)) => Some(self.capture(pane_id)?),
+                        _ => None,
+                    };
+                    window_snapshot.panes.push(PaneSnapshot {
+                        index: pane.index.ok_or(Error::Snapshot)?,
+                        current_path: pane.current_path,
+                        start_command: pane.start_command,
+                        current_command: pane.current_command,
+                        active: pane.active.unwrap_or_default(),
+                        contents,
+                    });
+                }
+                session_snapshot.windows.push(window_snapshot);
+            }
+            snapshot.sessions.push(session_snapshot);
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Recreate sessions, windows and panes of the snapshot, existing sessions are
+    /// skipped
+    ///
+    /// Layouts are applied using `select-layout`, commands of the panes are re-run only
+    /// if their program is in `commands` (see [`PaneSnapshot::restore_command`]), the pane
+    /// scrollback is not restored. Returns names of the restored sessions
+    pub fn restore(&self, snapshot: &Snapshot, commands: &[&str]) -> Result<Vec<String>, Error> {
+        let mut restored = Vec::new();
+        for session in &snapshot.sessions {
+            if session.windows.is_empty() || self.has_session(&session.name)? {
+                continue;
+            }
+            self.restore_session(session, commands)?;
+            restored.push(session.name.clone());
+        }
+        Ok(restored)
+    }
+
+    fn restore_session(&self, session: &SessionSnapshot, commands: &[&str]) -> Result<(), Error> {
+        let target = format!("={}", session.name);
+        let mut active_window = None;
+
+        for (i, window) in session.windows.iter().enumerate() {
+            let first_pane = window.panes.first();
+            let path = first_pane.and_then(|pane| pane.current_path.clone());
+
+            let window_id = if i == 0 {
+                let mut cmd = NewSession::new()
+                    .detached()
+                    .print()
+                    .format(NEW_SESSION_FORMAT)
+                    .session_name(session.name.clone())
+                    .window_name(window.name.clone());
+                if let Some(path) = path {
+                    cmd = cmd.start_directory(path);
+                }
+                if let Some(layout) = &window.layout {
+                    cmd = cmd.width(layout.cell.x).height(layout.cell.y);
+                }
+                let output = (self.invoker)(cmd.build())?.into_result()?.to_string();
+                let mut output = output.split_whitespace();
+                let window_id = output.next().ok_or(Error::Snapshot)?.to_string();
+                let index = output.next().ok_or(Error::Snapshot)?.parse::<usize>()?;
+                // base-index of the new server may differ
+                if index != window.index {
+                    let move_window = MoveWindow::new()
+                        .src_window(window_id.clone())
+                        .dst_window(format!("{}:{}", target, window.index));
+                    (self.invoker)(move_window.build())?.into_result()?;
+                }
+                window_id
+            } else {
+                let mut cmd = NewWindow::new()
+                    .detached()
+                    .print()
+                    .format(WINDOW_ID_FORMAT)
+                    .window_name(window.name.clone())
+                    .target_window(format!("{}:{}", target, window.index));
+                if let Some(path) = path {
+                    cmd = cmd.start_directory(path);
+                }
+                let output = (self.invoker)(cmd.build())?.into_result()?;
+                output.to_string().trim().to_string()
+            };
+
+            self.restore_panes(&window_id, window, commands)?;
+            if window.active {
+                active_window = Some(window_id);
+            }
+        }
+
+        if let Some(window_id) = active_window {
+            let select_window = SelectWindow::new().target_window(window_id);
+            (self.invoker)(select_window.build())?.into_result()?;
+        }
+        Ok(())
+    }
+
+    fn restore_panes(
+        &self,
+        window_id: &str,
+        window: &WindowSnapshot,
+        commands: &[&str],
+    ) -> Result<(), Error> {
+        // the first pane is created with the window
+        let mut pane_ids = vec![self.first_pane(window_id)?];
+
+        for pane in window.panes.iter().skip(1) {
+            // split the last pane to keep the pane indices in order
+            let last_pane = pane_ids[pane_ids.len() - 1].clone();
+            let mut split_window = SplitWindow::new()
+                .detached()
+                .print()
+                .format(PANE_ID_FORMAT)
+                .target_pane(last_pane);
+            if let Some(path) = &pane.current_path {
+                split_window = split_window.start_directory(path.clone());
+            }
+            // split pane is halved, the space is spread before the next split
+            let select_layout = SelectLayout::new()
+                .target_pane(window_id.to_string())
+                .layout_name(TILED_LAYOUT);
+            let cmds = TmuxCommands::new()
+                .cmd(split_window.build())
+                .cmd(select_layout.build());
+            let output = (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+            pane_ids.push(output.to_string().trim().to_string());
+        }
+
+        if let Some(layout) = &window.layout {
+            let select_layout = SelectLayout::new()
+                .target_pane(window_id.to_string())
+                .layout_name(layout.to_string());
+            (self.invoker)(select_layout.build())?.into_result()?;
+        }
+
+        for (pane, pane_id) in window.panes.iter().zip(&pane_ids) {
+            // command sent as literal text (`send-keys -l`) followed by `Enter`
+            if let Some(command) = pane.restore_command(commands) {
+                let cmds = TmuxCommands::new()
+                    .cmd(
+                        SendKeys::new()
+                            .disable_lookup()
+                            .target_pane(pane_id.clone())
+                            .key(command.to_string()),
+                    )
+                    .cmd(SendKeys::new().target_pane(pane_id.clone()).key(ENTER_KEY));
+                (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+            }
+        }
+
+        if let Some((_, pane_id)) = window
+            .panes
+            .iter()
+            .zip(&pane_ids)
+            .find(|(pane, _)| pane.active)
+        {
+            let select_pane = SelectPane::new().target_pane(pane_id.clone());
+            (self.invoker)(select_pane.build())?.into_result()?;
+            if window.zoomed {
+                let resize_pane = ResizePane::new().zoom().target_pane(pane_id.clone());
+                (self.invoker)(resize_pane.build())?.into_result()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn has_session(&self, name: &str) -> Result<bool, Error> {
+        let cmd = HasSession::new()
+            .target_session(format!("={}", name))
+            .build();
+        Ok((self.invoker)(cmd)?.success())
+    }
+
+    fn first_pane(&self, window_id: &str) -> Result<String, Error> {
+        PanesCtl::get_all_ext(Some(window_id.to_string()), self.invoker)?
+            .into_iter()
+            .next()
+            .and_then(|pane| pane.id)
+            .map(|id| format!("%{}", id))
+            .ok_or(Error::Snapshot)
+    }
+
+    fn capture(&self, pane_id: usize) -> Result<String, Error> {
+        let capture_pane = CapturePane::new()
+            .stdout()
+            .start_line(HISTORY_START)
+            .target_pane(format!("%{}", pane_id));
+        Ok((self.invoker)(capture_pane.build())?
+            .into_result()?
+            .to_string())
+    }
+}
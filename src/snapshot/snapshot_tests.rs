@@ -0,0 +1,174 @@
+#[test]
+fn to_string() {
+    use crate::{PaneSnapshot, SessionSnapshot, Snapshot, WindowSnapshot};
+
+    let snapshot = Snapshot {
+        version: 1,
+        sessions: vec![SessionSnapshot {
+            name: "project".to_string(),
+            windows: vec![WindowSnapshot {
+                index: 1,
+                name: "editor".to_string(),
+                layout: Some("d963,80x24,0,0,43".parse().unwrap()),
+                active: true,
+                zoomed: false,
+                panes: vec![PaneSnapshot {
+                    index: 0,
+                    current_path: Some("/tmp".to_string()),
+                    start_command: None,
+                    current_command: Some("bash".to_string()),
+                    active: true,
+                    contents: Some("$ ls\ta\\b\n".to_string()),
+                }],
+            }],
+        }],
+    };
+    assert_eq!(
+        snapshot.to_string(),
+        "tmux_interface_snapshot\t1\n\
+         session\tproject\n\
+         window\t1\teditor\td963,80x24,0,0,43\t1\t0\n\
+         pane\t0\t/tmp\t\tbash\t1\t$ ls\\ta\\\\b\\n\n"
+    );
+}
+
+#[test]
+fn from_str() {
+    use crate::{PaneSnapshot, SessionSnapshot, Snapshot, WindowSnapshot};
+
+    let s = "tmux_interface_snapshot\t1\n\
+             session\tproject\n\
+             window\t0\teditor\tcf3a,200x50,0,0{100x50,0,0,0,99x50,101,0,1}\t1\t1\n\
+             pane\t0\t/tmp\tvim\tvim\t0\t\n\
+             pane\t1\t/home\t\tbash\t1\tline 1\\nline 2\n\
+             window\t1\tshell\t\t0\t0\n\
+             session\tother\n";
+    let snapshot: Snapshot = s.parse().unwrap();
+    let expected = Snapshot {
+        version: 1,
+        sessions: vec![
+            SessionSnapshot {
+                name: "project".to_string(),
+                windows: vec![
+                    WindowSnapshot {
+                        index: 0,
+                        name: "editor".to_string(),
+                        layout: Some(
+                            "cf3a,200x50,0,0{100x50,0,0,0,99x50,101,0,1}"
+                                .parse()
+                                .unwrap(),
+                        ),
+                        active: true,
+                        zoomed: true,
+                        panes: vec![
+                            PaneSnapshot {
+                                index: 0,
+                                current_path: Some("/tmp".to_string()),
+                                start_command: Some("vim".to_string()),
+                                current_command: Some("vim".to_string()),
+                                active: false,
+                                contents: None,
+                            },
+                            PaneSnapshot {
+                                index: 1,
+                                current_path: Some("/home".to_string()),
+                                start_command: None,
+                                current_command: Some("bash".to_string()),
+                                active: true,
+                                contents: Some("line 1\nline 2".to_string()),
+                            },
+                        ],
+                    },
+                    WindowSnapshot {
+                        index: 1,
+                        name: "shell".to_string(),
+                        ..Default::default()
+                    },
+                ],
+            },
+            SessionSnapshot {
+                name: "other".to_string(),
+                windows: Vec::new(),
+            },
+        ],
+    };
+    assert_eq!(snapshot, expected);
+
+    // round trip
+    assert_eq!(snapshot.to_string().parse::<Snapshot>().unwrap(), snapshot);
+}
+
+#[test]
+fn carriage_return() {
+    use crate::{PaneSnapshot, SessionSnapshot, Snapshot, WindowSnapshot};
+
+    let snapshot = Snapshot {
+        version: 1,
+        sessions: vec![SessionSnapshot {
+            name: "dos\r".to_string(),
+            windows: vec![WindowSnapshot {
+                name: "editor".to_string(),
+                panes: vec![PaneSnapshot {
+                    contents: Some("line 1\r\nline 2\r".to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+        }],
+    };
+    let s = snapshot.to_string();
+    assert!(s.contains("session\tdos\\r\n"));
+    assert!(s.ends_with("\tline 1\\r\\nline 2\\r\n"));
+    assert_eq!(s.parse::<Snapshot>().unwrap(), snapshot);
+}
+
+#[test]
+fn from_str_error() {
+    use crate::Snapshot;
+
+    let errors = [
+        "",
+        "tmux_interface_snapshot\t2\n",
+        "tmux_interface_snapshot\n",
+        "something\t1\n",
+        // window without session
+        "tmux_interface_snapshot\t1\nwindow\t0\tname\t\t0\t0\n",
+        // pane without window
+        "tmux_interface_snapshot\t1\nsession\ta\npane\t0\t\t\t\t0\t\n",
+        "tmux_interface_snapshot\t1\nsession\ta\nwindow\tx\tname\t\t0\t0\n",
+        "tmux_interface_snapshot\t1\nsession\ta\nwindow\t0\tname\t\t2\t0\n",
+        "tmux_interface_snapshot\t1\nsession\ta\nwindow\t0\tname\tbad\t0\t0\n",
+        "tmux_interface_snapshot\t1\nsession\ta\\x\n",
+        "tmux_interface_snapshot\t1\nunknown\n",
+    ];
+    for s in errors.iter() {
+        assert!(s.parse::<Snapshot>().is_err(), "{:?}", s);
+    }
+}
+
+#[test]
+fn restore_command() {
+    use crate::PaneSnapshot;
+
+    let commands = ["vim", "htop"];
+
+    let pane = PaneSnapshot {
+        start_command: Some("\"vim src/lib.rs\"".to_string()),
+        current_command: Some("vim".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(pane.restore_command(&commands), Some("vim src/lib.rs"));
+
+    let pane = PaneSnapshot {
+        current_command: Some("htop".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(pane.restore_command(&commands), Some("htop"));
+
+    let pane = PaneSnapshot {
+        start_command: Some("rm -rf /tmp/x".to_string()),
+        current_command: Some("bash".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(pane.restore_command(&commands), None);
+}
@@ -35,3 +35,6 @@ impl FromStr for Align {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(Align);
@@ -1,5 +1,7 @@
+use crate::Error;
 use std::borrow::Cow;
 use std::fmt;
+use std::str::FromStr;
 
 const BLACK: &str = "black";
 const RED: &str = "red";
@@ -13,9 +15,12 @@ const BRIGHTRED: &str = "brightred";
 const BRIGHTGREEN: &str = "brightgreen";
 const BRIGHTYELLOW: &str = "brightyellow";
 // COLOURSET
+const COLOUR_PREFIX: &str = "colour";
+const COLOR_PREFIX: &str = "color";
 const DEFAULT: &str = "default";
 const TERMINAL: &str = "terminal";
 // HEX
+const HEX_PREFIX: &str = "#";
 
 /// if supported the bright variants brightred, brightgreen, brightyellow
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -76,30 +81,48 @@ impl fmt::Display for Colour {
     }
 }
 
-//use std::str::FromStr;
-//
-// TODO: ColourSet256, HEX
-//impl FromStr for Colour {
-//type Err = ();
+impl FromStr for Colour {
+    type Err = Error;
 
-//fn from_str(s: &str) -> Result<Self, Self::Err> {
-//unimplemented!();
-//match s {
-//BLACK => Ok(Self::Black),
-//RED => Ok(Self::Red),
-//GREEN => Ok(Self::Green),
-//BLUE => Ok(Self::Blue),
-//MAGENTA => Ok(Self::Magenta),
-//CYAN => Ok(Self::Cyan),
-//WHITE => Ok(Self::White),
-//BRIGHTRED => Ok(Self::BrightRed),
-//BRIGHTGREEN => Ok(Self::BrightGreen),
-//BRIGHTYELLOW => Ok(Self::BrightYellow),
-//// ColourSet256
-//DEFAULT => Ok(Self::Default),
-//TERMINAL => Ok(Self::Terminal),
-//// HEX
-//_ => Err(()),
-//}
-//}
-//}
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            BLACK => Ok(Self::Black),
+            RED => Ok(Self::Red),
+            GREEN => Ok(Self::Green),
+            YELLOW => Ok(Self::Yellow),
+            BLUE => Ok(Self::Blue),
+            MAGENTA => Ok(Self::Magenta),
+            CYAN => Ok(Self::Cyan),
+            WHITE => Ok(Self::White),
+            BRIGHTRED => Ok(Self::BrightRed),
+            BRIGHTGREEN => Ok(Self::BrightGreen),
+            BRIGHTYELLOW => Ok(Self::BrightYellow),
+            DEFAULT => Ok(Self::Default),
+            TERMINAL => Ok(Self::Terminal),
+            s => {
+                if let Some(n) = s
+                    .strip_prefix(COLOUR_PREFIX)
+                    .or_else(|| s.strip_prefix(COLOR_PREFIX))
+                {
+                    n.parse()
+                        .map(Self::ColourSet256)
+                        .map_err(|_| Error::ParseColour)
+                } else if let Some(hex) = s.strip_prefix(HEX_PREFIX) {
+                    match hex.len() {
+                        6 if hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                            u32::from_str_radix(hex, 16)
+                                .map(Self::HEX)
+                                .map_err(|_| Error::ParseColour)
+                        }
+                        _ => Err(Error::ParseColour),
+                    }
+                } else {
+                    Err(Error::ParseColour)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+serde_str!(Colour);
@@ -39,3 +39,6 @@ impl FromStr for List {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(List);
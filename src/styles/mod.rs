@@ -4,6 +4,7 @@ pub mod align;
 pub mod colour;
 pub mod list;
 pub mod range;
+pub mod status_line_composer;
 pub mod style;
 pub mod style_list;
 
@@ -11,5 +12,13 @@ pub use align::Align;
 pub use colour::Colour;
 pub use list::List;
 pub use range::Range;
+pub use status_line_composer::{StatusLine, StatusSegment, MOUSE_DOWN_1_STATUS};
 pub use style::Style;
 pub use style_list::StyleList;
+
+#[cfg(test)]
+#[path = "."]
+mod styles_tests {
+    pub mod status_line_composer_tests;
+    pub mod style_tests;
+}
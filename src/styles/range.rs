@@ -1,14 +1,22 @@
+use crate::Error;
 use std::fmt;
+use std::str::FromStr;
 
 const LEFT: &str = "left";
 const RIGHT: &str = "right";
-// WINDOW
+const WINDOW: &str = "window";
+const RANGE_ARGUMENT_SEPARATOR: char = '|';
+#[cfg(feature = "tmux_3_4")]
+const USER: &str = "user";
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum Range {
     Left,
     Right,
     Window(u32),
+    /// user range with an argument (up to 15 bytes), reported as `mouse_status_range`
+    #[cfg(feature = "tmux_3_4")]
+    User(String),
 }
 
 impl fmt::Display for Range {
@@ -16,24 +24,34 @@ impl fmt::Display for Range {
         let s = match self {
             Self::Left => LEFT.to_string(),
             Self::Right => RIGHT.to_string(),
-            Self::Window(index) => format!("{}|{}", "window", index),
+            Self::Window(index) => format!("{}{}{}", WINDOW, RANGE_ARGUMENT_SEPARATOR, index),
+            #[cfg(feature = "tmux_3_4")]
+            Self::User(argument) => format!("{}{}{}", USER, RANGE_ARGUMENT_SEPARATOR, argument),
         };
         write!(f, "{}", s)
     }
 }
 
-//use std::str::FromStr;
+impl FromStr for Range {
+    type Err = Error;
 
-//// TODO: window
-//impl FromStr for Range {
-//type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(RANGE_ARGUMENT_SEPARATOR) {
+            None => match s {
+                LEFT => Ok(Self::Left),
+                RIGHT => Ok(Self::Right),
+                _ => Err(Error::ParseRange),
+            },
+            Some((WINDOW, index)) => index
+                .parse()
+                .map(Self::Window)
+                .map_err(|_| Error::ParseRange),
+            #[cfg(feature = "tmux_3_4")]
+            Some((USER, argument)) => Ok(Self::User(argument.to_string())),
+            _ => Err(Error::ParseRange),
+        }
+    }
+}
 
-//fn from_str(s: &str) -> Result<Self, Self::Err> {
-//unimplemented!();
-//match s {
-//LEFT => Ok(Self::Left),
-//RIGHT => Ok(Self::Right),
-//_ => Err(()),
-//}
-//}
-//}
+#[cfg(feature = "serde")]
+serde_str!(Range);
@@ -0,0 +1,268 @@
+#[cfg(feature = "tmux_3_4")]
+use super::Range;
+use super::{Align, List, Style, StyleList};
+#[cfg(feature = "tmux_1_0")]
+use crate::SessionOptions;
+use std::borrow::Cow;
+use std::fmt;
+
+/// Key name of the mouse event on the status line, used with `bind-key -n`
+pub const MOUSE_DOWN_1_STATUS: &str = "MouseDown1Status";
+
+const STYLE_BEGIN: &str = "#[";
+const STYLE_END: &str = "]";
+const STYLE_SEPARATOR: &str = ",";
+// user range argument limits (ref: style.c -> style_parse())
+const RANGE_USER_MAX: usize = 15;
+const RANGE_USER_INVALID: &[char] = &[',', ']'];
+// `status-format[]` line set by the status line
+#[cfg(feature = "tmux_2_9")]
+const STATUS_FORMAT_INDEX: usize = 0;
+
+/// One part of a status line: styles, alignment, list marker, format content and
+/// an optional user range (click target)
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{Align, Colour, Style, StyleList, StatusSegment};
+///
+/// let mut styles = StyleList::new();
+/// styles.add(Style::Fg(Colour::Red));
+/// let segment = StatusSegment::new()
+///     .style(styles)
+///     .align(Align::Right)
+///     .content("#{host}");
+/// assert_eq!(segment.to_string(), "#[fg=red,align=right]#{host}#[default]");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct StatusSegment<'a> {
+    /// segment identifier, emitted as `range=user|id` (tmux ^3.4)
+    pub id: Option<Cow<'a, str>>,
+    /// styles applied to the segment content
+    pub style: Option<StyleList<'a>>,
+    /// `align=` (tmux ^2.9)
+    pub align: Option<Align>,
+    /// `list=` marker (tmux ^2.9)
+    pub list: Option<List>,
+    /// format expression (e.g. `#{session_name}`)
+    pub content: Option<Cow<'a, str>>,
+}
+
+impl<'a> fmt::Display for StatusSegment<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render(true))
+    }
+}
+
+impl<'a> StatusSegment<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// segment identifier, reported back as `mouse_status_range` if clicked
+    ///
+    /// tmux limits user range arguments to 15 bytes, `,` and `]` are not allowed, the
+    /// identifier is sanitized accordingly if rendered (see [`StatusSegment::range_id()`])
+    pub fn id<S: Into<Cow<'a, str>>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn style(mut self, style: StyleList<'a>) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn list(mut self, list: List) -> Self {
+        self.list = Some(list);
+        self
+    }
+
+    pub fn content<S: Into<Cow<'a, str>>>(mut self, content: S) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// identifier as emitted in `range=user|id`: `,` and `]` removed and truncated to
+    /// 15 bytes
+    pub fn range_id(&self) -> Option<String> {
+        self.id.as_deref().map(|id| {
+            let mut id: String = id
+                .chars()
+                .filter(|c| !RANGE_USER_INVALID.contains(c))
+                .collect();
+            let mut len = id.len().min(RANGE_USER_MAX);
+            while !id.is_char_boundary(len) {
+                len -= 1;
+            }
+            id.truncate(len);
+            id
+        })
+    }
+
+    /// render segment, `markers` enables `align`, `list` and `range` styles (tmux ^2.9)
+    fn render(&self, markers: bool) -> String {
+        let mut begin: Vec<String> = Vec::new();
+        let mut end: Vec<Style> = Vec::new();
+
+        if let Some(styles) = self.style.as_ref().and_then(|s| s.styles.as_ref()) {
+            if !styles.is_empty() {
+                begin.extend(styles.iter().map(|s| s.to_string()));
+                end.push(Style::Default);
+            }
+        }
+
+        if markers {
+            if let Some(align) = &self.align {
+                begin.push(Style::Align(align.clone()).to_string());
+            }
+            if let Some(list) = &self.list {
+                begin.push(Style::List(list.clone()).to_string());
+                end.insert(0, Style::NoList);
+            }
+            #[cfg(feature = "tmux_3_4")]
+            if let Some(id) = self.range_id() {
+                begin.push(Style::Range(Range::User(id)).to_string());
+                end.insert(0, Style::NoRange);
+            }
+        }
+
+        let mut s = String::new();
+        if !begin.is_empty() {
+            s.push_str(&format!(
+                "{}{}{}",
+                STYLE_BEGIN,
+                begin.join(STYLE_SEPARATOR),
+                STYLE_END
+            ));
+        }
+        if let Some(content) = &self.content {
+            s.push_str(content);
+        }
+        if !end.is_empty() {
+            let end: Vec<String> = end.iter().map(|s| s.to_string()).collect();
+            s.push_str(&format!(
+                "{}{}{}",
+                STYLE_BEGIN,
+                end.join(STYLE_SEPARATOR),
+                STYLE_END
+            ));
+        }
+        s
+    }
+}
+
+/// Status line built from [`StatusSegment`]s
+///
+/// Depending on tmux version it is rendered as a single `status-format[]` line
+/// (tmux ^2.9) or split into `status-left` and `status-right` values
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{Align, StatusLine, StatusSegment};
+///
+/// let status_line = StatusLine::new()
+///     .segment(StatusSegment::new().content("#S"))
+///     .segment(StatusSegment::new().align(Align::Right).content("%H:%M"));
+/// assert_eq!(status_line.status_left(), "#S");
+/// assert_eq!(status_line.status_right(), "%H:%M");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct StatusLine<'a> {
+    pub segments: Vec<StatusSegment<'a>>,
+}
+
+impl<'a> fmt::Display for StatusLine<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s: String = self.segments.iter().map(|s| s.render(true)).collect();
+        write!(f, "{}", s)
+    }
+}
+
+impl<'a> StatusLine<'a> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// append segment
+    pub fn segment(mut self, segment: StatusSegment<'a>) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    /// `status-format[]` line value (tmux ^2.9)
+    #[cfg(feature = "tmux_2_9")]
+    pub fn status_format(&self) -> String {
+        self.to_string()
+    }
+
+    /// `status-left` value, built from segments without alignment or aligned left
+    pub fn status_left(&self) -> String {
+        self.aligned(|align| matches!(align, None | Some(Align::Left)))
+    }
+
+    /// `status-right` value, built from segments aligned right
+    pub fn status_right(&self) -> String {
+        self.aligned(|align| matches!(align, Some(Align::Right)))
+    }
+
+    fn aligned<F: Fn(Option<&Align>) -> bool>(&self, f: F) -> String {
+        self.segments
+            .iter()
+            .filter(|s| f(s.align.as_ref()))
+            .map(|s| s.render(false))
+            .collect()
+    }
+
+    /// Set status line options according to the tmux version
+    ///
+    /// * tmux ^2.9: `status-format[0]`, other lines are kept
+    /// * tmux ^1.0: `status-left` and `status-right` (centred segments are skipped)
+    #[cfg(feature = "tmux_2_9")]
+    pub fn session_options(&self, mut options: SessionOptions<'a>) -> SessionOptions<'a> {
+        let lines = options.status_format.get_or_insert_with(Vec::new);
+        match lines.get_mut(STATUS_FORMAT_INDEX) {
+            Some(line) => *line = Cow::Owned(self.status_format()),
+            None => lines.push(Cow::Owned(self.status_format())),
+        }
+        options
+    }
+
+    /// Set status line options according to the tmux version
+    ///
+    /// * tmux ^2.9: `status-format[0]`, other lines are kept
+    /// * tmux ^1.0: `status-left` and `status-right` (centred segments are skipped)
+    #[cfg(all(feature = "tmux_1_0", not(feature = "tmux_2_9")))]
+    pub fn session_options(&self, options: SessionOptions<'a>) -> SessionOptions<'a> {
+        options
+            .status_left(Some(self.status_left()))
+            .status_right(Some(self.status_right()))
+    }
+
+    /// Map the `mouse_status_range` value of a `MouseDown1Status` event back to the
+    /// identifier of the clicked segment
+    #[cfg(feature = "tmux_3_4")]
+    pub fn segment_id(&self, mouse_status_range: &str) -> Option<&str> {
+        self.segments
+            .iter()
+            .find(|s| s.range_id().as_deref() == Some(mouse_status_range))
+            .and_then(|s| s.id.as_deref())
+    }
+
+    /// `bind-key -n MouseDown1Status command`, `#{mouse_status_range}` can be used
+    /// in the command to pass the clicked segment identifier
+    #[cfg(feature = "tmux_3_4")]
+    pub fn mouse_binding<S: Into<Cow<'a, str>>>(command: S) -> crate::BindKey<'a> {
+        crate::BindKey::new()
+            .root()
+            .key(MOUSE_DOWN_1_STATUS)
+            .command(command)
+    }
+}
@@ -0,0 +1,107 @@
+#[test]
+fn status_segment() {
+    use crate::{Colour, List, StatusSegment, Style, StyleList};
+
+    let segment = StatusSegment::new().content("#S");
+    assert_eq!(segment.to_string(), "#S");
+
+    let mut styles = StyleList::new();
+    styles.add(Style::Bg(Colour::Blue)).add(Style::Bold);
+    let segment = StatusSegment::new()
+        .style(styles)
+        .list(List::On)
+        .content("#{W:#I}");
+    assert_eq!(
+        segment.to_string(),
+        "#[bg=blue,bold,list=on]#{W:#I}#[nolist,default]"
+    );
+}
+
+#[test]
+fn status_line() {
+    use crate::{Align, StatusLine, StatusSegment};
+
+    let status_line = StatusLine::new()
+        .segment(StatusSegment::new().content("[#S]"))
+        .segment(StatusSegment::new().align(Align::Centre).content("#W"))
+        .segment(StatusSegment::new().align(Align::Right).content("%H:%M"));
+
+    assert_eq!(status_line.status_left(), "[#S]");
+    assert_eq!(status_line.status_right(), "%H:%M");
+    #[cfg(feature = "tmux_2_9")]
+    assert_eq!(
+        status_line.status_format(),
+        "[#S]#[align=centre]#W#[align=right]%H:%M"
+    );
+}
+
+#[cfg(feature = "tmux_3_4")]
+#[test]
+fn status_line_user_range() {
+    use crate::{StatusLine, StatusSegment};
+
+    let status_line = StatusLine::new()
+        .segment(StatusSegment::new().id("build").content("#{@build}"))
+        .segment(StatusSegment::new().id("mail").content("#{@mail}"));
+
+    assert_eq!(
+        status_line.to_string(),
+        "#[range=user|build]#{@build}#[norange]#[range=user|mail]#{@mail}#[norange]"
+    );
+    assert_eq!(status_line.segment_id("mail"), Some("mail"));
+    assert_eq!(status_line.segment_id("window"), None);
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let cmd = "bind-key";
+    #[cfg(feature = "cmd_alias")]
+    let cmd = "bind";
+
+    assert_eq!(
+        StatusLine::mouse_binding("run-shell 'echo #{mouse_status_range}'")
+            .build()
+            .to_string(),
+        format!(
+            "{} -n MouseDown1Status run-shell 'echo #{{mouse_status_range}}'",
+            cmd
+        )
+    );
+}
+
+#[cfg(feature = "tmux_3_4")]
+#[test]
+fn status_segment_range_id() {
+    use crate::{StatusLine, StatusSegment};
+
+    let segment = StatusSegment::new()
+        .id("a,b]c-long-identifier")
+        .content("#S");
+    assert_eq!(segment.range_id().as_deref(), Some("abc-long-identi"));
+    assert_eq!(
+        segment.to_string(),
+        "#[range=user|abc-long-identi]#S#[norange]"
+    );
+
+    let status_line = StatusLine::new().segment(segment);
+    assert_eq!(
+        status_line.segment_id("abc-long-identi"),
+        Some("a,b]c-long-identifier")
+    );
+}
+
+#[cfg(feature = "tmux_2_9")]
+#[test]
+fn status_line_session_options() {
+    use crate::{SessionOptions, StatusLine, StatusSegment};
+
+    let status_line = StatusLine::new().segment(StatusSegment::new().content("#S"));
+
+    let options = status_line.session_options(SessionOptions::default());
+    assert_eq!(options.status_format, Some(vec!["#S".into()]));
+
+    let options = SessionOptions::default().status_format(Some(vec!["first", "second"]));
+    let options = status_line.session_options(options);
+    assert_eq!(
+        options.status_format,
+        Some(vec!["#S".into(), "second".into()])
+    );
+}
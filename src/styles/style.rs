@@ -1,5 +1,7 @@
 use super::{Align, Colour, List, Range};
+use crate::Error;
 use std::fmt;
+use std::str::FromStr;
 
 // XXX: split into colours and attributes
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -13,6 +15,8 @@ pub enum Style {
     /// Set no attributes (turn off any active attributes)
     // right name: None
     NoStyle,
+    /// Reset to the default style
+    Default,
     /// Set an attribute.
     /// Any of the attributes may be prefixed with ‘no’ to unset.
     /// acs is the terminal alternate character set.
@@ -70,6 +74,7 @@ impl fmt::Display for Style {
             Self::Us(colour) => format!("us={}", colour),
             // right name: None
             Self::NoStyle => "none".to_string(),
+            Self::Default => "default".to_string(),
             Self::Acs => "acs".to_string(),
             Self::NoAcs => "noacs".to_string(),
             Self::Bright => "bright".to_string(),
@@ -113,3 +118,66 @@ impl fmt::Display for Style {
         write!(f, "{}", s)
     }
 }
+
+impl FromStr for Style {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Self::NoStyle),
+            "default" => Ok(Self::Default),
+            "acs" => Ok(Self::Acs),
+            "noacs" => Ok(Self::NoAcs),
+            "bright" => Ok(Self::Bright),
+            "nobright" => Ok(Self::NoBright),
+            "bold" => Ok(Self::Bold),
+            "nobold" => Ok(Self::NoBold),
+            "dim" => Ok(Self::Dim),
+            "nodim" => Ok(Self::NoDim),
+            "underscore" => Ok(Self::Underscore),
+            "nounderscore" => Ok(Self::NoUnderscore),
+            "blink" => Ok(Self::Blink),
+            "noblink" => Ok(Self::NoBlink),
+            "reverse" => Ok(Self::Reverse),
+            "noreverse" => Ok(Self::NoReverse),
+            "hidden" => Ok(Self::Hidden),
+            "nohidden" => Ok(Self::NoHidden),
+            "italics" => Ok(Self::Italics),
+            "noitalics" => Ok(Self::NoItalics),
+            "overline" => Ok(Self::Overline),
+            "nooverline" => Ok(Self::NoOverline),
+            "strikethrough" => Ok(Self::Strikethrough),
+            "nostrikethrough" => Ok(Self::NoStrikethrough),
+            "double-underscore" => Ok(Self::DoubleUnderscore),
+            "nodouble-underscore" => Ok(Self::NoDoubleUnderscore),
+            "curly-underscore" => Ok(Self::CurlyUnderscore),
+            "nocurly-underscore" => Ok(Self::NoCurlyUnderscore),
+            "dotted-underscore" => Ok(Self::DottedUnderscore),
+            "nodotted-underscore" => Ok(Self::NoDottedUnderscore),
+            "dashed-underscore" => Ok(Self::DashedUnderscore),
+            "nodashed-underscore" => Ok(Self::NoDashedUnderscore),
+            "noalign" => Ok(Self::NoAlign),
+            "nolist" => Ok(Self::NoList),
+            "push-default" => Ok(Self::PushDefault),
+            "pop-default" => Ok(Self::PopDefault),
+            "norange" => Ok(Self::NoRange),
+            s => match s.split_once('=') {
+                Some(("fg", colour)) => Ok(Self::Fg(colour.parse()?)),
+                Some(("bg", colour)) => Ok(Self::Bg(colour.parse()?)),
+                #[cfg(feature = "tmux_3_4")]
+                Some(("us", colour)) => Ok(Self::Us(colour.parse()?)),
+                Some(("align", align)) => align
+                    .parse()
+                    .map(Self::Align)
+                    .map_err(|_| Error::ParseStyle),
+                Some(("fill", colour)) => Ok(Self::Fill(colour.parse()?)),
+                Some(("list", list)) => list.parse().map(Self::List).map_err(|_| Error::ParseStyle),
+                Some(("range", range)) => Ok(Self::Range(range.parse()?)),
+                _ => Err(Error::ParseStyle),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+serde_str!(Style);
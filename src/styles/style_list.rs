@@ -1,5 +1,7 @@
 use super::Style;
+use crate::Error;
 use std::fmt;
+use std::str::FromStr;
 
 const SPACE_SEPRATOR: &str = " ";
 const COMMA_SEPRATOR: &str = ",";
@@ -24,6 +26,24 @@ impl<'a> fmt::Display for StyleList<'a> {
     }
 }
 
+// NOTE: tmux source: style.c -> style_parse()
+impl<'a> FromStr for StyleList<'a> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut style_list = StyleList::new();
+        if !s.contains(COMMA_SEPRATOR) && s.contains(SPACE_SEPRATOR) {
+            style_list.space_separator();
+        }
+        for style in s.split([',', ' ', '\n']) {
+            if !style.is_empty() {
+                style_list.add(style.parse()?);
+            }
+        }
+        Ok(style_list)
+    }
+}
+
 impl<'a> StyleList<'a> {
     pub fn new() -> Self {
         Default::default()
@@ -44,3 +64,6 @@ impl<'a> StyleList<'a> {
         self
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(<'a> StyleList<'a>);
@@ -0,0 +1,77 @@
+#[test]
+fn colour_from_str() {
+    use crate::Colour;
+
+    assert_eq!("red".parse::<Colour>().unwrap(), Colour::Red);
+    assert_eq!(
+        "colour99".parse::<Colour>().unwrap(),
+        Colour::ColourSet256(99)
+    );
+    assert_eq!("color7".parse::<Colour>().unwrap(), Colour::ColourSet256(7));
+    assert_eq!("#ffa000".parse::<Colour>().unwrap(), Colour::HEX(0xffa000));
+    assert_eq!("terminal".parse::<Colour>().unwrap(), Colour::Terminal);
+
+    for s in &[
+        "",
+        "rouge",
+        "colour256",
+        "colour",
+        "#fff",
+        "#+ffff0",
+        "#ffffffff",
+    ] {
+        assert!(s.parse::<Colour>().is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn style_from_str() {
+    use crate::{Align, Colour, List, Range, Style};
+
+    assert_eq!("bold".parse::<Style>().unwrap(), Style::Bold);
+    assert_eq!(
+        "nodouble-underscore".parse::<Style>().unwrap(),
+        Style::NoDoubleUnderscore
+    );
+    assert_eq!("fg=blue".parse::<Style>().unwrap(), Style::Fg(Colour::Blue));
+    assert_eq!(
+        "align=centre".parse::<Style>().unwrap(),
+        Style::Align(Align::Centre)
+    );
+    assert_eq!(
+        "list=left-marker".parse::<Style>().unwrap(),
+        Style::List(List::LeftMarker)
+    );
+    assert_eq!(
+        "range=window|3".parse::<Style>().unwrap(),
+        Style::Range(Range::Window(3))
+    );
+
+    for s in &[
+        "",
+        "fg",
+        "fg=",
+        "bg=nope",
+        "align=middle",
+        "range=window|x",
+        "=bold",
+    ] {
+        assert!(s.parse::<Style>().is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn style_list_from_str() {
+    use crate::StyleList;
+
+    for s in &[
+        "fg=red,bg=colour235,bold",
+        "fg=#00ff00 noreverse",
+        "default",
+    ] {
+        let style_list: StyleList = s.parse().unwrap();
+        assert_eq!(style_list.to_string(), *s);
+    }
+    assert!("fg=red,,bold".parse::<StyleList>().is_ok());
+    assert!("fg=red,blod".parse::<StyleList>().is_err());
+}
@@ -0,0 +1,174 @@
+use crate::{Error, StdIO, Tmux, TmuxCommand, TmuxOutput, WaitFor};
+use std::borrow::Cow;
+use std::process::{self, Child};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// child process polling interval while waiting with timeout
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+// channels created by this process
+static CHANNEL_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// unique `wait-for` channel (and user option) name
+pub(crate) fn channel_name(prefix: &str) -> String {
+    format!(
+        "{}_{}_{}",
+        prefix,
+        process::id(),
+        CHANNEL_COUNT.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// `wait-for` channel shared by all clients of the tmux server (e.g. processes in panes, hosts
+/// using the same socket)
+///
+/// * [`lock()`](Channel::lock) - `wait-for -L`, the lock is released when the returned
+///   [`ChannelGuard`] is dropped (`wait-for -U`)
+/// * [`signal()`](Channel::signal) - `wait-for -S`, wakes up waiting clients
+/// * [`wait()`](Channel::wait) - `wait-for`, blocks until the channel is signaled, the
+///   waiting tmux client (child process) is woken by the signal on timeout (the other waiting
+///   clients are woken too), no waiting client is left registered
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use tmux_interface::Channel;
+///
+/// let channel = Channel::new("deploy");
+/// {
+///     let _guard = channel.lock().unwrap();
+///     // serialized work
+/// }
+///
+/// channel.wait(Some(Duration::from_secs(60))).unwrap();
+/// ```
+pub struct Channel<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// spawns the waiting tmux client (waited for on timeout)
+    pub spawner: &'a dyn Fn(TmuxCommand<'a>) -> Result<Child, Error>,
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> Channel<'a> {
+    pub fn new<S: Into<Cow<'a, str>>>(name: S) -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            spawner: &|cmd| {
+                Tmux::with_command(cmd)
+                    .stdout(Some(StdIO::Null))
+                    .stderr(Some(StdIO::Piped))
+                    .spawn()
+            },
+            name: name.into(),
+        }
+    }
+
+    pub fn with_invoker<S: Into<Cow<'a, str>>>(
+        name: S,
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    ) -> Self {
+        Self {
+            invoker,
+            ..Self::new(name)
+        }
+    }
+
+    pub fn with_invokers<S: Into<Cow<'a, str>>>(
+        name: S,
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+        spawner: &'a dyn Fn(TmuxCommand<'a>) -> Result<Child, Error>,
+    ) -> Self {
+        Self {
+            invoker,
+            spawner,
+            name: name.into(),
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Lock the channel (`wait-for -L`), blocks until the lock is acquired
+    pub fn lock(&self) -> Result<ChannelGuard<'_, 'a>, Error> {
+        let wait_for = WaitFor::new().locked().channel(self.name.clone());
+        (self.invoker)(wait_for.build())?.into_result()?;
+        Ok(ChannelGuard {
+            channel: self,
+            locked: true,
+        })
+    }
+
+    /// Unlock the channel (`wait-for -U`)
+    pub fn unlock(&self) -> Result<(), Error> {
+        let wait_for = WaitFor::new().unlocked().channel(self.name.clone());
+        (self.invoker)(wait_for.build())?.into_result()?;
+        Ok(())
+    }
+
+    /// Wake up the clients waiting for the channel (`wait-for -S`), the next wait returns
+    /// immediately if no client is waiting
+    pub fn signal(&self) -> Result<(), Error> {
+        let wait_for = WaitFor::new().woken().channel(self.name.clone());
+        (self.invoker)(wait_for.build())?.into_result()?;
+        Ok(())
+    }
+
+    /// Wait until the channel is signaled (`wait-for`), [`Error::Timeout`] if not signaled
+    /// in time, `None` waits without timeout
+    pub fn wait(&self, timeout: Option<Duration>) -> Result<(), Error> {
+        let wait_for = WaitFor::new().channel(self.name.clone());
+        let timeout = match timeout {
+            Some(timeout) => timeout,
+            None => {
+                (self.invoker)(wait_for.build())?.into_result()?;
+                return Ok(());
+            }
+        };
+
+        let deadline = Instant::now() + timeout;
+        let mut child = (self.spawner)(wait_for.build())?;
+        loop {
+            if child.try_wait()?.is_some() {
+                TmuxOutput(child.wait_with_output()?).into_result()?;
+                return Ok(());
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                // killed client would stay registered and consume the next signal, the signal
+                // is consumed by the client not waiting yet too
+                if self.signal().is_err() {
+                    child.kill()?;
+                }
+                child.wait()?;
+                return Err(Error::Timeout);
+            }
+            thread::sleep(POLL_INTERVAL.min(deadline - now));
+        }
+    }
+}
+
+/// Lock of the [`Channel`], unlocked when dropped
+pub struct ChannelGuard<'c, 'a> {
+    channel: &'c Channel<'a>,
+    locked: bool,
+}
+
+impl<'c, 'a> ChannelGuard<'c, 'a> {
+    /// Unlock the channel, unlock error is returned (ignored if dropped)
+    pub fn unlock(mut self) -> Result<(), Error> {
+        self.locked = false;
+        self.channel.unlock()
+    }
+}
+
+impl<'c, 'a> Drop for ChannelGuard<'c, 'a> {
+    fn drop(&mut self) {
+        if self.locked {
+            let _ = self.channel.unlock();
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(unix)]
+#[test]
+fn channel() {
+    use crate::{mock_output, Channel, Error, MockInvoker, TmuxCommand};
+    use std::cell::RefCell;
+    use std::process::{Child, Command, Stdio};
+    use std::time::{Duration, Instant};
+
+    let mock = MockInvoker::new();
+    let invoker = mock.invoker(|_| mock_output(0, ""));
+    // waiting tmux client replaced by the shell command
+    let shell_command = RefCell::new("");
+    let spawner = |cmd: TmuxCommand| -> Result<Child, Error> {
+        mock.push(cmd);
+        let child = Command::new("sh")
+            .args(["-c", *shell_command.borrow()])
+            .stderr(Stdio::piped())
+            .spawn()?;
+        Ok(child)
+    };
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let wait = "wait-for";
+    #[cfg(feature = "cmd_alias")]
+    let wait = "wait";
+
+    let channel = Channel::with_invokers("deploy", &invoker, &spawner);
+    {
+        let _guard = channel.lock().unwrap();
+        assert_eq!(*mock.cmds.borrow(), vec![format!("{} -L deploy", wait)]);
+    }
+    channel.lock().unwrap().unlock().unwrap();
+    channel.signal().unwrap();
+    channel.wait(None).unwrap();
+    assert_eq!(
+        *mock.cmds.borrow(),
+        vec![
+            format!("{} -L deploy", wait),
+            format!("{} -U deploy", wait),
+            format!("{} -L deploy", wait),
+            format!("{} -U deploy", wait),
+            format!("{} -S deploy", wait),
+            format!("{} deploy", wait),
+        ]
+    );
+
+    mock.clear();
+    *shell_command.borrow_mut() = "exit 0";
+    channel.wait(Some(Duration::from_secs(10))).unwrap();
+    assert_eq!(*mock.cmds.borrow(), vec![format!("{} deploy", wait)]);
+
+    *shell_command.borrow_mut() = "echo 'no server' >&2; exit 1";
+    let result = channel.wait(Some(Duration::from_secs(10)));
+    assert!(matches!(result, Err(Error::Tmux(msg)) if msg == "no server"));
+
+    // waiting client woken by the signal on timeout
+    mock.clear();
+    *shell_command.borrow_mut() = "sleep 0.3";
+    let start = Instant::now();
+    let result = channel.wait(Some(Duration::from_millis(100)));
+    assert!(matches!(result, Err(Error::Timeout)));
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(
+        *mock.cmds.borrow(),
+        vec![format!("{} deploy", wait), format!("{} -S deploy", wait)]
+    );
+}
@@ -0,0 +1,16 @@
+//! Synchronization of processes using the tmux server (`wait-for` channels)
+pub mod channel;
+#[cfg(all(unix, feature = "tmux_3_0"))]
+pub mod pane_runner;
+
+pub use channel::{Channel, ChannelGuard};
+#[cfg(all(unix, feature = "tmux_3_0"))]
+pub use pane_runner::PaneRunner;
+
+#[cfg(test)]
+#[path = "."]
+mod sync_tests {
+    pub mod channel_tests;
+    #[cfg(all(unix, feature = "tmux_3_0"))]
+    pub mod pane_runner_tests;
+}
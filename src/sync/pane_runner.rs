@@ -0,0 +1,240 @@
+use crate::sync::channel::{channel_name, Channel};
+use crate::{
+    DisplayMessage, Error, RespawnPane, SendKeys, SetOption, ShowOptions, StdIO, Tmux, TmuxCommand,
+    TmuxCommands, TmuxOutput, WaitFor,
+};
+use std::borrow::Cow;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{Child, ExitStatus};
+use std::time::Duration;
+
+const CHANNEL_PREFIX: &str = "tmux_interface_pane";
+const USER_OPTION_MARKER: char = '@';
+const REMAIN_ON_EXIT: &str = "remain-on-exit";
+const ON: &str = "on";
+const ENTER_KEY: &str = "Enter";
+const CANCEL_KEY: &str = "C-c";
+const PANE_DEAD_STATUS: &str = "#{pane_dead_status}";
+const EXIT_STATUS_UNKNOWN: &str = "exit status of the pane command is unknown";
+// pane of the shell running the sentinel, not the active pane of the session
+const CURRENT_PANE: &str = "\"$TMUX_PANE\"";
+
+/// Run shell commands in panes, await their completion and exit status
+///
+/// The shell command is followed by the sentinel storing the exit status in the pane user option
+/// (`tmux set-option -p -t "$TMUX_PANE" @channel "$?"`) and signaling the `wait-for` channel of the same name
+/// (unique for each command).
+///
+/// * [`run()`](PaneRunner::run) - the command is typed into the shell running in the pane
+///   (`send-keys`) in the group (`{ command<new line>}; sentinel`), trailing comments, background
+///   jobs and multiple lines are allowed, the shell must be POSIX-like (`sh`, `bash`, `zsh`)
+/// * [`respawn()`](PaneRunner::respawn) - the pane is respawned with the command
+///   (`respawn-pane -k`), `remain-on-exit` is set, the dead pane keeps the command output and
+///   `#{pane_dead_status}`
+///
+/// On [`timeout()`](PaneRunner::timeout) the command is cancelled (`send-keys C-c`) and
+/// [`Error::Timeout`] is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use tmux_interface::PaneRunner;
+///
+/// let status = PaneRunner::new()
+///     .timeout(Duration::from_secs(600))
+///     .run("%1", "cargo test")
+///     .unwrap();
+/// assert!(status.success());
+/// ```
+pub struct PaneRunner<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// spawns the tmux client waiting for the command with timeout
+    pub spawner: &'a dyn Fn(TmuxCommand<'a>) -> Result<Child, Error>,
+    /// The command is cancelled if not completed in time, `None` waits without timeout
+    pub timeout: Option<Duration>,
+}
+
+impl<'a> Default for PaneRunner<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+            spawner: &|cmd| {
+                Tmux::with_command(cmd)
+                    .stdout(Some(StdIO::Null))
+                    .stderr(Some(StdIO::Piped))
+                    .spawn()
+            },
+            timeout: None,
+        }
+    }
+}
+
+impl<'a> PaneRunner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_invokers(
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+        spawner: &'a dyn Fn(TmuxCommand<'a>) -> Result<Child, Error>,
+    ) -> Self {
+        Self {
+            invoker,
+            spawner,
+            timeout: None,
+        }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Type the shell command into the shell running in the pane, await its exit status
+    pub fn run<S: Into<Cow<'a, str>>>(
+        &self,
+        target_pane: S,
+        shell_command: &str,
+    ) -> Result<ExitStatus, Error> {
+        let target_pane = target_pane.into();
+        let name = channel_name(CHANNEL_PREFIX);
+        let line = format!("{{ {}\n}}; {}", shell_command, sentinel(&name, "\"$?\""));
+        let cmds = TmuxCommands::new()
+            .cmd(
+                SendKeys::new()
+                    .disable_lookup()
+                    .target_pane(target_pane.clone())
+                    .key(line),
+            )
+            .cmd(
+                SendKeys::new()
+                    .target_pane(target_pane.clone())
+                    .key(ENTER_KEY),
+            );
+        (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        self.status(target_pane, name)
+    }
+
+    /// Respawn the pane with the shell command (run in the subshell by the default shell), await
+    /// its exit status, the pane remains dead after the command exits
+    pub fn respawn<S: Into<Cow<'a, str>>>(
+        &self,
+        target_pane: S,
+        shell_command: &str,
+    ) -> Result<ExitStatus, Error> {
+        let target_pane = target_pane.into();
+        let name = channel_name(CHANNEL_PREFIX);
+        // the pane exits with the command status (`#{pane_dead_status}`)
+        let script = format!(
+            "(\n{}\n)\ns=$?\n{}\nexit \"$s\"\n",
+            shell_command,
+            sentinel(&name, "\"$s\"")
+        );
+        let cmds = TmuxCommands::new()
+            .cmd(
+                SetOption::new()
+                    .pane()
+                    .target_pane(target_pane.clone())
+                    .option(REMAIN_ON_EXIT)
+                    .value(ON),
+            )
+            .cmd(
+                RespawnPane::new()
+                    .kill()
+                    .target_pane(target_pane.clone())
+                    .shell_command(script),
+            );
+        (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        self.status(target_pane, name)
+    }
+
+    /// Cancel the command running in the pane (`send-keys C-c`)
+    pub fn cancel<S: Into<Cow<'a, str>>>(&self, target_pane: S) -> Result<(), Error> {
+        let send_keys = SendKeys::new().target_pane(target_pane).key(CANCEL_KEY);
+        (self.invoker)(send_keys.build())?.into_result()?;
+        Ok(())
+    }
+
+    /// Exit status of the dead pane (`#{pane_dead_status}`), `None` if the pane is alive or
+    /// the status was not reported
+    pub fn dead_status<S: Into<Cow<'a, str>>>(
+        &self,
+        target_pane: S,
+    ) -> Result<Option<ExitStatus>, Error> {
+        let display_message = DisplayMessage::new()
+            .print()
+            .target_pane(target_pane)
+            .message(PANE_DEAD_STATUS);
+        let output = (self.invoker)(display_message.build())?.into_result()?;
+        parse_status(&output.to_string())
+    }
+
+    // wait for the sentinel, the exit status is taken from the pane user option
+    fn status(&self, target_pane: Cow<'a, str>, name: String) -> Result<ExitStatus, Error> {
+        let channel = Channel::with_invokers(name.clone(), self.invoker, self.spawner);
+        if let Err(err) = channel.wait(self.timeout) {
+            if let Error::Timeout = err {
+                self.cancel(target_pane)?;
+            }
+            return Err(err);
+        }
+
+        let option = format!("{}{}", USER_OPTION_MARKER, name);
+        let cmds = TmuxCommands::new()
+            .cmd(
+                ShowOptions::new()
+                    .pane()
+                    .quiet()
+                    .value()
+                    .target(target_pane.clone())
+                    .option(option.clone()),
+            )
+            .cmd(
+                SetOption::new()
+                    .pane()
+                    .unset()
+                    .target_pane(target_pane.clone())
+                    .option(option),
+            );
+        let output = (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        match parse_status(&output.to_string())? {
+            Some(status) => Ok(status),
+            // sentinel of the respawned pane failed, status reported by tmux
+            None => self
+                .dead_status(target_pane)?
+                .ok_or_else(|| Error::Tmux(EXIT_STATUS_UNKNOWN.to_string())),
+        }
+    }
+}
+
+// shell commands storing the exit status in the pane user option and signaling the channel
+fn sentinel(name: &str, status: &str) -> String {
+    let option = format!("{}{}", USER_OPTION_MARKER, name);
+    let set_option = SetOption::new()
+        .pane()
+        .target_pane(CURRENT_PANE)
+        .option(option)
+        .build();
+    let wait_for = WaitFor::new().woken().channel(name.to_string()).build();
+    format!("tmux {} {}; tmux {}", set_option, status, wait_for)
+}
+
+fn parse_status(output: &str) -> Result<Option<ExitStatus>, Error> {
+    let code = output.trim();
+    match code.is_empty() {
+        true => Ok(None),
+        false => Ok(Some(ExitStatus::from_raw(code.parse::<i32>()? << 8))),
+    }
+}
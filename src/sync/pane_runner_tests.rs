@@ -0,0 +1,89 @@
+#[test]
+fn pane_runner() {
+    use crate::{mock_output, Error, MockInvoker, PaneRunner, TmuxCommand};
+    use std::process::{Child, Command, Stdio};
+    use std::time::Duration;
+
+    let mock = MockInvoker::new();
+    let invoker = mock.invoker(|cmd| match cmd.starts_with("show") {
+        true => mock_output(0, "3\n"),
+        false => mock_output(0, ""),
+    });
+    // waiting tmux client replaced by the shell command
+    let spawner = |cmd: TmuxCommand| -> Result<Child, Error> {
+        mock.push(cmd);
+        let child = Command::new("sh")
+            .args(["-c", "sleep 0.1"])
+            .stderr(Stdio::piped())
+            .spawn()?;
+        Ok(child)
+    };
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let (set, show, wait, send, respawn) = (
+        "set-option",
+        "show-options",
+        "wait-for",
+        "send-keys",
+        "respawn-pane",
+    );
+    #[cfg(feature = "cmd_alias")]
+    let (set, show, wait, send, respawn) = ("set", "show", "wait", "send", "respawnp");
+
+    let runner = PaneRunner::with_invokers(&invoker, &spawner);
+    let status = runner.run("%1", "cargo test").unwrap();
+    assert_eq!(status.code(), Some(3));
+    // channel name is unique
+    let name = mock.cmds.borrow()[1]
+        .split(' ')
+        .next_back()
+        .unwrap()
+        .to_string();
+    assert!(name.starts_with("tmux_interface_pane_"));
+    assert_eq!(
+        *mock.cmds.borrow(),
+        vec![
+            format!(
+                "{} -l -t %1 {{ cargo test\n}}; tmux {} -p -t \"$TMUX_PANE\" @{} \"$?\"; tmux {} -S {} ; {} -t %1 Enter",
+                send, set, name, wait, name, send
+            ),
+            format!("{} {}", wait, name),
+            format!(
+                "{} -p -q -v -t %1 @{} ; {} -p -u -t %1 @{}",
+                show, name, set, name
+            ),
+        ]
+    );
+
+    mock.clear();
+    let status = runner.respawn("%1", "exit 3").unwrap();
+    assert_eq!(status.code(), Some(3));
+    let name = mock.cmds.borrow()[1]
+        .split(' ')
+        .next_back()
+        .unwrap()
+        .to_string();
+    assert_eq!(
+        mock.cmds.borrow()[0],
+        format!(
+        "{} -p -t %1 remain-on-exit on ; {} -k -t %1 (\nexit 3\n)\ns=$?\ntmux {} -p -t \"$TMUX_PANE\" @{} \"$s\"; tmux {} -S {}\nexit \"$s\"\n",
+        set, respawn, set, name, wait, name
+    )
+    );
+
+    // trailing comment does not comment out the sentinel
+    mock.clear();
+    runner.run("%1", "make # all targets").unwrap();
+    assert!(mock.cmds.borrow()[0].starts_with(&format!(
+        "{} -l -t %1 {{ make # all targets\n}}; tmux",
+        send
+    )));
+
+    // not completed in time, cancelled
+    mock.clear();
+    let runner = runner.timeout(Duration::from_millis(10));
+    assert!(matches!(runner.run("%1", "sleep 60"), Err(Error::Timeout)));
+    assert_eq!(mock.cmds.borrow().len(), 4);
+    assert!(mock.cmds.borrow()[2].starts_with(&format!("{} -S ", wait)));
+    assert_eq!(mock.cmds.borrow()[3], format!("{} -t %1 C-c", send));
+}
@@ -0,0 +1,52 @@
+//! The [`target`][`crate::target`] module contains functions for building targets for tmux
+//! commands
+//!
+//! * TargetPane
+//!     * token (+, -, {...}) instead of name
+//!     * index instead of name
+//!     * id (%id) instead of name
+//!     * exact name (=name)
+//!     * start of a name
+//!     * fn_match
+//!
+//! * TargetWindow
+//!     * token (^, $, !, +, -) instead of name
+//!     * index instead of name
+//!     * id (@id) instead of name
+//!     * exact name (=name)
+//!     * start of a name
+//!     * fn_match
+//!
+//! TargetSession
+//!     * id ($id) instead of name
+//!     * exact name (=name)
+//!     * start of a name
+//!     * fn_match
+//!
+//! # See Also
+//! [Tmux Manual -> Commands](https://man7.org/linux/man-pages/man1/tmux.1.html#COMMANDS)
+//!
+//!
+pub mod target_pane;
+pub mod target_session;
+pub mod target_window;
+
+pub use target_pane::{TargetPane, TargetPaneExt, TargetPaneToken};
+pub use target_session::TargetSession;
+pub use target_window::{TargetWindow, TargetWindowExt, TargetWindowToken};
+
+/// Index, id or offset of the target string form (digits only, no sign)
+fn parse_number(s: &str) -> Option<usize> {
+    match !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        true => s.parse().ok(),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "."]
+mod target_tests {
+    pub mod target_pane_tests;
+    pub mod target_session_tests;
+    pub mod target_window_tests;
+}
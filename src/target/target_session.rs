@@ -0,0 +1,75 @@
+use super::parse_number;
+use std::fmt;
+
+const TARGET_SESSION_ID: char = '$';
+const TARGET_SESSION_EXACT: char = '=';
+
+impl<'a> TargetSession<'a> {
+    /// simple initializing as start of a name
+    pub fn new(target_name: &'a str) -> Self {
+        TargetSession::StartName(target_name)
+    }
+
+    pub fn exact_name(name: &'a str) -> Self {
+        TargetSession::ExactName(name)
+    }
+
+    pub fn start_name(name: &'a str) -> Self {
+        TargetSession::StartName(name)
+    }
+
+    pub fn fn_match(name: &'a str) -> Self {
+        TargetSession::FnMatch(name)
+    }
+
+    // XXX: draft
+    pub fn raw(name: &'a str) -> Self {
+        TargetSession::Raw(name)
+    }
+}
+
+// XXX: remove unnecessary fields
+// XXX: mb impl Into<String>, generics?
+/// Enum for possible `target-session` variants
+#[derive(Debug)]
+pub enum TargetSession<'a> {
+    /// id ($id) instead of name
+    Id(usize),
+    /// exact name (=name)
+    ExactName(&'a str),
+    /// start of a name
+    StartName(&'a str),
+    /// fn_match
+    FnMatch(&'a str),
+    // NOTE: not really needed, just to be analogous to `TargetWindow` `TargetPane`
+    /// manual define full name
+    Raw(&'a str),
+}
+
+impl<'a> fmt::Display for TargetSession<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TargetSession::Id(id) => write!(f, "{}{}", TARGET_SESSION_ID, id),
+            TargetSession::ExactName(name) => write!(f, "{}{}", TARGET_SESSION_EXACT, name),
+            TargetSession::StartName(name) => f.write_str(name),
+            TargetSession::FnMatch(name) => f.write_str(name),
+            TargetSession::Raw(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Parse the string form (`$id`, `=name`), other names are used as start of a name
+impl<'a> From<&'a str> for TargetSession<'a> {
+    fn from(s: &'a str) -> Self {
+        if let Some(id) = s.strip_prefix(TARGET_SESSION_ID).and_then(parse_number) {
+            TargetSession::Id(id)
+        } else if let Some(name) = s.strip_prefix(TARGET_SESSION_EXACT) {
+            TargetSession::ExactName(name)
+        } else {
+            TargetSession::StartName(s)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+serde_str_borrowed!(TargetSession);
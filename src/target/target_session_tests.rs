@@ -0,0 +1,33 @@
+#[test]
+fn target_session() {
+    use crate::TargetSession;
+
+    let session_type = TargetSession::Id(1);
+    assert_eq!(session_type.to_string(), "$1");
+    let session_type = TargetSession::ExactName("exact_name");
+    assert_eq!(session_type.to_string(), "=exact_name");
+    let session_type = TargetSession::StartName("start_name");
+    assert_eq!(session_type.to_string(), "start_name");
+    let session_type = TargetSession::StartName("fn_match");
+    assert_eq!(session_type.to_string(), "fn_match");
+    let session_type = TargetSession::StartName("raw");
+    assert_eq!(session_type.to_string(), "raw");
+}
+
+#[test]
+fn target_session_from_str() {
+    use crate::TargetSession;
+
+    assert!(matches!(TargetSession::from("$1"), TargetSession::Id(1)));
+    assert!(matches!(
+        TargetSession::from("=name"),
+        TargetSession::ExactName("name")
+    ));
+    assert!(matches!(
+        TargetSession::from("$name"),
+        TargetSession::StartName("$name")
+    ));
+    for s in ["$1", "=name", "name", ""].iter() {
+        assert_eq!(TargetSession::from(*s).to_string(), *s);
+    }
+}
@@ -0,0 +1,175 @@
+// command line arguments of the commands interpreted by the fake server (ref: arguments.c,
+// cmd-parse.c)
+use crate::TmuxCommand;
+
+const FLAG_PREFIX: char = '-';
+const COMMAND_SEPARATOR: char = ';';
+const ESCAPED_SEPARATOR: &str = "\\;";
+const OPTIONS_END: &str = "--";
+const ENV_SEPARATOR: char = '=';
+
+/// supported command, flags without value and flags followed by a value
+pub(crate) struct CommandSpec {
+    pub name: &'static str,
+    pub alias: &'static str,
+    pub flags: &'static str,
+    pub options: &'static str,
+}
+
+const fn spec(
+    name: &'static str,
+    alias: &'static str,
+    flags: &'static str,
+    options: &'static str,
+) -> CommandSpec {
+    CommandSpec {
+        name,
+        alias,
+        flags,
+        options,
+    }
+}
+
+pub(crate) const COMMANDS: &[CommandSpec] = &[
+    // clients and sessions
+    spec("new-session", "new", "AdDEPX", "cefFnstxy"),
+    spec("kill-session", "", "aC", "t"),
+    spec("has-session", "has", "", "t"),
+    spec("rename-session", "rename", "", "t"),
+    spec("list-sessions", "ls", "", "Ff"),
+    spec("kill-server", "", "", ""),
+    spec("start-server", "start", "", ""),
+    // windows and panes
+    spec("new-window", "neww", "abdkPS", "ceFnt"),
+    spec("kill-window", "killw", "a", "t"),
+    spec("rename-window", "renamew", "", "t"),
+    spec("select-window", "selectw", "lnpT", "t"),
+    spec("list-windows", "lsw", "a", "Fft"),
+    spec("split-window", "splitw", "bdfhIPvZ", "ceFlpt"),
+    spec("kill-pane", "killp", "a", "t"),
+    spec("select-pane", "selectp", "DdegLlMmRUZ", "Tt"),
+    spec("list-panes", "lsp", "as", "Fft"),
+    // miscellaneous
+    spec("display-message", "display", "aINpv", "cdFt"),
+    // options
+    spec("set-option", "set", "aFgopqsuUw", "t"),
+    spec("set-window-option", "setw", "aFgoqu", "t"),
+    spec("show-options", "show", "AgHpqsvw", "t"),
+    spec("show-window-options", "showw", "gv", "t"),
+    // buffers
+    spec("set-buffer", "setb", "aw", "bnt"),
+    spec("show-buffer", "showb", "", "b"),
+    spec("delete-buffer", "deleteb", "", "b"),
+    spec("list-buffers", "lsb", "", "Ff"),
+    spec("save-buffer", "saveb", "a", "b"),
+    spec("load-buffer", "loadb", "w", "bt"),
+];
+
+/// Parsed arguments of a single command
+#[derive(Default, Debug)]
+pub(crate) struct FakeArgs {
+    pub name: &'static str,
+    flags: Vec<char>,
+    options: Vec<(char, String)>,
+    pub params: Vec<String>,
+}
+
+impl FakeArgs {
+    /// Parse command (name or alias) with its arguments
+    pub fn parse(argv: &[String]) -> Result<Self, String> {
+        // environment variables of the tmux process are ignored
+        let start = argv
+            .iter()
+            .position(|arg| !arg.contains(ENV_SEPARATOR))
+            .unwrap_or(argv.len());
+        let (name, args) = match argv[start..].split_first() {
+            Some((name, args)) => (name, args),
+            None => return Err("no command".to_string()),
+        };
+        let spec = COMMANDS
+            .iter()
+            .find(|spec| spec.name == name || (!spec.alias.is_empty() && spec.alias == name))
+            .ok_or_else(|| format!("unknown command: {}", name))?;
+
+        let mut parsed = Self {
+            name: spec.name,
+            ..Default::default()
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == OPTIONS_END {
+                break;
+            }
+            let flags = match arg.strip_prefix(FLAG_PREFIX) {
+                Some(flags) if !flags.is_empty() => flags,
+                _ => {
+                    parsed.params.push(arg.clone());
+                    break;
+                }
+            };
+            for (i, flag) in flags.char_indices() {
+                if spec.flags.contains(flag) {
+                    parsed.flags.push(flag);
+                } else if spec.options.contains(flag) {
+                    // value is the rest of the argument or the next argument
+                    let rest = &flags[i + flag.len_utf8()..];
+                    let value = match rest.is_empty() {
+                        true => args
+                            .next()
+                            .cloned()
+                            .ok_or_else(|| format!("-{} expects an argument", flag))?,
+                        false => rest.to_string(),
+                    };
+                    parsed.options.push((flag, value));
+                    break;
+                } else {
+                    return Err(format!("command {}: unknown flag -{}", spec.name, flag));
+                }
+            }
+        }
+        parsed.params.extend(args.cloned());
+        Ok(parsed)
+    }
+
+    pub fn has(&self, flag: char) -> bool {
+        self.flags.contains(&flag)
+    }
+
+    /// Value of the flag, the last one if given multiple times
+    pub fn get(&self, flag: char) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(f, _)| *f == flag)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn param(&self, i: usize) -> Option<&str> {
+        self.params.get(i).map(|param| param.as_str())
+    }
+}
+
+// command sequence split at `;` arguments (`a;` ends the command, `a\;` is escaped)
+pub(crate) fn split_commands(cmd: &TmuxCommand) -> Vec<Vec<String>> {
+    let mut cmds = Vec::new();
+    let mut current = Vec::new();
+    for arg in cmd.to_vec() {
+        match arg.strip_suffix(COMMAND_SEPARATOR) {
+            Some(_) if arg.ends_with(ESCAPED_SEPARATOR) => {
+                let escaped = &arg[..arg.len() - ESCAPED_SEPARATOR.len()];
+                current.push(format!("{}{}", escaped, COMMAND_SEPARATOR));
+            }
+            Some(arg) => {
+                if !arg.is_empty() {
+                    current.push(arg.to_string());
+                }
+                cmds.push(current);
+                current = Vec::new();
+            }
+            None => current.push(arg.to_string()),
+        }
+    }
+    cmds.push(current);
+    cmds.retain(|cmd| !cmd.is_empty());
+    cmds
+}
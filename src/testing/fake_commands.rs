@@ -0,0 +1,997 @@
+// commands interpreted by the fake server (ref: cmd-*.c)
+use super::fake_args::FakeArgs;
+use super::fake_options::{
+    default_value, new_value, option_info, option_kind, show_lines, split_index, FakeOptions,
+    FakeValue,
+};
+use super::fake_state::{
+    is_true, now, string_option, FakeBuffer, FakeSession, FakeState, FormatContext, Location,
+};
+use crate::{LayoutCell, LayoutDirection, LayoutType, OptionKind};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+// default formats (ref: cmd-*.c -> *_TEMPLATE), times are printed as numbers
+const LIST_SESSIONS_FORMAT: &str = "#{session_name}: #{session_windows} windows \
+    (created #{session_created})#{?session_attached, (attached),}";
+const LIST_WINDOWS_FORMAT: &str = "#{window_index}: #{window_name}#{window_flags} \
+    (#{window_panes} panes) [#{window_width}x#{window_height}] [layout #{window_layout}] \
+    #{window_id}#{?window_active, (active),}";
+const LIST_PANES_FORMAT: &str = "#{pane_index}: [#{pane_width}x#{pane_height}] \
+    [history #{history_size}/#{history-limit}, 0 bytes] #{pane_id}#{?pane_active, (active),}";
+const LIST_BUFFERS_FORMAT: &str = "#{buffer_name}: #{buffer_size} bytes: \"#{buffer_sample}\"";
+const NEW_SESSION_FORMAT: &str = "#{session_name}:";
+const NEW_PANE_FORMAT: &str = "#{session_name}:#{window_index}.#{pane_index}";
+const DISPLAY_MESSAGE_FORMAT: &str =
+    "[#{session_name}] #{window_index}:#{window_name}, current pane #{pane_index}";
+const ALL_SESSIONS_PREFIX: &str = "#{session_name}:";
+const ALL_WINDOWS_PREFIX: &str = "#{window_index}.";
+
+const BASE_INDEX: &str = "base-index";
+const DEFAULT_SIZE: &str = "default-size";
+const AUTOMATIC_RENAME: &str = "automatic-rename";
+const EXIT_EMPTY: &str = "exit-empty";
+const BUFFER_LIMIT: &str = "buffer-limit";
+const AUTOMATIC_BUFFER_PREFIX: &str = "buffer";
+const STDIO_PATH: &str = "-";
+const PERCENT: char = '%';
+const FLAG_OFF: &str = "off";
+// characters not allowed in session names (ref: session.c -> session_check_name())
+const SESSION_NAME_INVALID: &[char] = &[':', '.'];
+const SESSION_NAME_REPLACEMENT: &str = "_";
+
+/// Options the command works with
+#[derive(Clone, Copy, Debug)]
+enum Scope {
+    Server,
+    GlobalSession,
+    Session(usize),
+    GlobalWindow,
+    Window(Location),
+    Pane(Location),
+}
+
+impl FakeState {
+    /// Execute the command, printed lines are appended to `stdout`
+    pub fn execute(
+        &mut self,
+        args: &FakeArgs,
+        input: &[u8],
+        stdout: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        match args.name {
+            "new-session" => self.running = true,
+            "start-server" => {
+                self.running = true;
+                return Ok(());
+            }
+            _ if !self.running => return Err("no server running".to_string()),
+            _ => {}
+        }
+        match args.name {
+            "new-session" => self.new_session(args, stdout),
+            "kill-session" => self.kill_session(args),
+            "has-session" => self.target_session(args.get('t')).map(|_| ()),
+            "rename-session" => self.rename_session(args),
+            "list-sessions" => self.list_sessions(args, stdout),
+            "kill-server" => {
+                self.reset();
+                Ok(())
+            }
+            "new-window" => self.new_window_command(args, stdout),
+            "kill-window" => self.kill_window_command(args),
+            "rename-window" => self.rename_window(args),
+            "select-window" => self.select_window_command(args),
+            "list-windows" => self.list_windows(args, stdout),
+            "split-window" => self.split_window(args, stdout),
+            "kill-pane" => self.kill_pane_command(args),
+            "select-pane" => self.select_pane_command(args),
+            "list-panes" => self.list_panes(args, stdout),
+            "display-message" => self.display_message(args, stdout),
+            "set-option" | "set-window-option" => self.set_option(args),
+            "show-options" | "show-window-options" => self.show_options(args, stdout),
+            "set-buffer" => self.set_buffer_command(args),
+            "show-buffer" => self.show_buffer(args, stdout),
+            "delete-buffer" => self.delete_buffer(args),
+            "list-buffers" => self.list_buffers(args, stdout),
+            "save-buffer" => self.save_buffer(args, stdout),
+            "load-buffer" => self.load_buffer(args, input),
+            _ => Err(format!("unknown command: {}", args.name)),
+        }
+    }
+
+    /// Server exits if there are no sessions left (`exit-empty`)
+    pub fn exit_if_empty(&mut self) {
+        let exit_empty = string_option(&[&self.server_options], EXIT_EMPTY) != Some(FLAG_OFF);
+        if self.running && self.sessions.is_empty() && exit_empty {
+            self.reset();
+        }
+    }
+
+    // sessions
+
+    fn new_session(&mut self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        if args.get('t').is_some() {
+            return Err("session groups are not supported".to_string());
+        }
+        let name = args.get('s').map(session_name);
+        if let Some(name) = &name {
+            if self.sessions.iter().any(|session| &session.name == name) {
+                return match args.has('A') {
+                    true => Ok(()),
+                    false => Err(format!("duplicate session: {}", name)),
+                };
+            }
+        }
+
+        let (mut width, mut height) = self.default_size();
+        if let Some(x) = args.get('x') {
+            width = x.parse().map_err(|_| format!("width {}", x))?;
+        }
+        if let Some(y) = args.get('y') {
+            height = y.parse().map_err(|_| format!("height {}", y))?;
+        }
+        let mut options = FakeOptions::new();
+        if args.get('x').is_some() || args.get('y').is_some() {
+            let size = format!("{}x{}", width, height);
+            options.insert(DEFAULT_SIZE.to_string(), FakeValue::String(size));
+        }
+
+        let pane = self.new_pane(&args.params.join(" "), args.get('c'));
+        let index = string_option(&[&self.session_options], BASE_INDEX)
+            .and_then(|index| index.parse().ok())
+            .unwrap_or_default();
+        let window = self.new_window(index, args.get('n'), pane, (width, height));
+        let id = self.next_session_id;
+        self.next_session_id += 1;
+        let session = FakeSession {
+            id,
+            name: name.unwrap_or_else(|| id.to_string()),
+            stack: vec![window.id],
+            windows: vec![window],
+            created: now(),
+            activity: self.tick(),
+            width,
+            height,
+            options,
+        };
+        self.sessions.push(session);
+
+        if args.has('P') {
+            let location = self.active_location(self.sessions.len() - 1);
+            let format = args.get('F').unwrap_or(NEW_SESSION_FORMAT);
+            print(stdout, &self.expand(format, &FormatContext::pane(location)));
+        }
+        Ok(())
+    }
+
+    fn kill_session(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let session = self.target_session(args.get('t'))?;
+        if args.has('C') {
+            return Ok(());
+        }
+        match args.has('a') {
+            true => {
+                let id = self.sessions[session].id;
+                self.sessions.retain(|session| session.id == id);
+            }
+            false => {
+                self.sessions.remove(session);
+            }
+        }
+        Ok(())
+    }
+
+    fn rename_session(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let session = self.target_session(args.get('t'))?;
+        let name = session_name(args.param(0).unwrap_or_default());
+        if self.sessions.iter().any(|session| session.name == name) {
+            return Err(format!("duplicate session: {}", name));
+        }
+        self.sessions[session].name = name;
+        Ok(())
+    }
+
+    fn list_sessions(&self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let format = args.get('F').unwrap_or(LIST_SESSIONS_FORMAT);
+        for session in 0..self.sessions.len() {
+            self.print_filtered(args, stdout, format, &FormatContext::session(session));
+        }
+        Ok(())
+    }
+
+    // windows
+
+    fn new_window_command(&mut self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let (session, index) = self.target_index(args.get('t'))?;
+        let base_index = self.base_index(session);
+        let index = if args.has('a') || args.has('b') {
+            let target = match index {
+                Some(index) => index,
+                None => {
+                    let s = &self.sessions[session];
+                    s.windows[s.active_position()].index
+                }
+            };
+            let index = match args.has('a') {
+                true => target + 1,
+                false => target,
+            };
+            self.shuffle_up(session, index);
+            index
+        } else {
+            match index {
+                Some(index) => {
+                    let s = &self.sessions[session];
+                    if let Some(existing) = s.windows.iter().position(|w| w.index == index) {
+                        if !args.has('k') {
+                            return Err(format!("index {} in use", index));
+                        }
+                        // replaced, the session is not killed with its only window
+                        let s = &mut self.sessions[session];
+                        let removed = s.windows.remove(existing);
+                        s.stack.retain(|id| *id != removed.id);
+                    }
+                    index
+                }
+                None => {
+                    let s = &self.sessions[session];
+                    (base_index..)
+                        .find(|index| s.windows.iter().all(|w| w.index != *index))
+                        .unwrap_or(base_index)
+                }
+            }
+        };
+        let pane = self.new_pane(&args.params.join(" "), args.get('c'));
+        let size = (self.sessions[session].width, self.sessions[session].height);
+        let window = self.new_window(index, args.get('n'), pane, size);
+        let id = window.id;
+        let s = &mut self.sessions[session];
+        let position = s
+            .windows
+            .iter()
+            .position(|w| w.index > index)
+            .unwrap_or(s.windows.len());
+        s.windows.insert(position, window);
+        match args.has('d') {
+            true => s.stack.push(id),
+            false => s.select_window(id),
+        }
+
+        if args.has('P') {
+            let location = self.window_location(session, position);
+            let format = args.get('F').unwrap_or(NEW_PANE_FORMAT);
+            print(stdout, &self.expand(format, &FormatContext::pane(location)));
+        }
+        Ok(())
+    }
+
+    // session and window index of `new-window -t` (window may not exist)
+    fn target_index(&self, target: Option<&str>) -> Result<(usize, Option<usize>), String> {
+        let target = match target {
+            Some(target) if !target.is_empty() => target,
+            _ => return Ok((self.current_session()?, None)),
+        };
+        let (session, window) = match target.split_once(':') {
+            Some((session, window)) => {
+                let session = match session.is_empty() {
+                    true => self.current_session()?,
+                    false => self.find_session(session)?,
+                };
+                (session, window)
+            }
+            None => {
+                if let Ok(index) = target.parse() {
+                    return Ok((self.current_session()?, Some(index)));
+                }
+                if let Ok(session) = self.find_session(target) {
+                    return Ok((session, None));
+                }
+                (self.current_session()?, target)
+            }
+        };
+        if window.is_empty() {
+            return Ok((session, None));
+        }
+        if let Ok(index) = window.parse() {
+            return Ok((session, Some(index)));
+        }
+        let window = self.find_window(session, window)?;
+        Ok((session, Some(self.sessions[session].windows[window].index)))
+    }
+
+    // free the index moving the following windows up (ref: winlink_shuffle_up())
+    fn shuffle_up(&mut self, session: usize, index: usize) {
+        let windows = &mut self.sessions[session].windows;
+        let free = (index..)
+            .find(|i| windows.iter().all(|w| w.index != *i))
+            .unwrap_or(index);
+        for window in windows.iter_mut() {
+            if window.index >= index && window.index < free {
+                window.index += 1;
+            }
+        }
+    }
+
+    fn kill_window_command(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let location = self.target_window(args.get('t'))?;
+        match args.has('a') {
+            true => {
+                let id = self.window(location).id;
+                let s = &mut self.sessions[location.session];
+                s.windows.retain(|window| window.id == id);
+                s.stack.retain(|window| *window == id);
+            }
+            false => self.kill_window(location.session, location.window),
+        }
+        Ok(())
+    }
+
+    // session is killed with its last window
+    fn kill_window(&mut self, session: usize, window: usize) {
+        let s = &mut self.sessions[session];
+        let removed = s.windows.remove(window);
+        s.stack.retain(|id| *id != removed.id);
+        if s.windows.is_empty() {
+            self.sessions.remove(session);
+        }
+    }
+
+    fn rename_window(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let location = self.target_window(args.get('t'))?;
+        let window = self.window_mut(location);
+        window.name = args.param(0).unwrap_or_default().to_string();
+        window.options.insert(
+            AUTOMATIC_RENAME.to_string(),
+            FakeValue::String(FLAG_OFF.to_string()),
+        );
+        Ok(())
+    }
+
+    fn select_window_command(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let location = self.target_window(args.get('t'))?;
+        let s = &self.sessions[location.session];
+        let active = s.active_position();
+        let window = if args.has('l') {
+            s.stack
+                .get(1)
+                .and_then(|id| s.window_position(*id))
+                .ok_or("no last window")?
+        } else if args.has('n') {
+            (active + 1) % s.windows.len()
+        } else if args.has('p') {
+            (active + s.windows.len() - 1) % s.windows.len()
+        } else if args.has('T') && location.window == active {
+            s.stack
+                .get(1)
+                .and_then(|id| s.window_position(*id))
+                .unwrap_or(active)
+        } else {
+            location.window
+        };
+        let id = s.windows[window].id;
+        self.sessions[location.session].select_window(id);
+        Ok(())
+    }
+
+    fn list_windows(&self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let sessions = match args.has('a') {
+            true => (0..self.sessions.len()).collect(),
+            false => vec![self.target_session(args.get('t'))?],
+        };
+        let format = match (args.get('F'), args.has('a')) {
+            (Some(format), _) => format.to_string(),
+            (None, true) => format!("{}{}", ALL_SESSIONS_PREFIX, LIST_WINDOWS_FORMAT),
+            (None, false) => LIST_WINDOWS_FORMAT.to_string(),
+        };
+        for session in sessions {
+            for window in 0..self.sessions[session].windows.len() {
+                let context = FormatContext::window(session, window);
+                self.print_filtered(args, stdout, &format, &context);
+            }
+        }
+        Ok(())
+    }
+
+    // panes
+
+    fn split_window(&mut self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let location = self.target_pane(args.get('t'))?;
+        let target = self.pane(location).id;
+        let style = match args.has('h') {
+            true => LayoutType::LeftRight,
+            false => LayoutType::TopBottom,
+        };
+        let cell = self
+            .window(location)
+            .layout
+            .find_pane(target)
+            .ok_or("no space for new pane")?;
+        let size = match style {
+            LayoutType::LeftRight => cell.x,
+            _ => cell.y,
+        };
+        let ratio = match (args.get('l'), args.get('p')) {
+            (Some(l), _) => match l.strip_suffix(PERCENT) {
+                Some(percent) => percentage(percent)?,
+                None => {
+                    let cells = l.parse::<usize>().map_err(|_| format!("size {}", l))?;
+                    cells as f64 / size.saturating_sub(1).max(1) as f64
+                }
+            },
+            (None, Some(p)) => percentage(p)?,
+            (None, None) => 0.5,
+        };
+
+        let pane = self.new_pane(&args.params.join(" "), args.get('c'));
+        let id = pane.id;
+        let window = self.window_mut(location);
+        window
+            .layout
+            .split(target, style, ratio, id)
+            .map_err(|_| "no space for new pane")?;
+        let position = match args.has('b') {
+            true => {
+                swap_ids(&mut window.layout, target, id);
+                location.pane
+            }
+            false => location.pane + 1,
+        };
+        window.panes.insert(position, pane);
+        if !args.has('d') {
+            window.select_pane(id);
+        }
+
+        if args.has('P') {
+            let location = Location {
+                pane: position,
+                ..location
+            };
+            let format = args.get('F').unwrap_or(NEW_PANE_FORMAT);
+            print(stdout, &self.expand(format, &FormatContext::pane(location)));
+        }
+        Ok(())
+    }
+
+    fn kill_pane_command(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let location = self.target_pane(args.get('t'))?;
+        match args.has('a') {
+            true => {
+                let id = self.pane(location).id;
+                let others: Vec<usize> = self
+                    .window(location)
+                    .panes
+                    .iter()
+                    .map(|pane| pane.id)
+                    .filter(|pane| *pane != id)
+                    .collect();
+                for pane in others {
+                    let pane = self
+                        .window(location)
+                        .pane_position(pane)
+                        .unwrap_or_default();
+                    self.kill_pane(Location { pane, ..location });
+                }
+            }
+            false => self.kill_pane(location),
+        }
+        Ok(())
+    }
+
+    // window is killed with its last pane, the rest of the panes take its space
+    fn kill_pane(&mut self, location: Location) {
+        if self.window(location).panes.len() == 1 {
+            self.kill_window(location.session, location.window);
+            return;
+        }
+        let window = self.window_mut(location);
+        let removed = window.panes.remove(location.pane);
+        remove_cell(&mut window.layout, removed.id);
+        if let Ok(layout) = window.layout.scale(window.layout.x, window.layout.y) {
+            window.layout = layout;
+        }
+        if window.last == Some(removed.id) {
+            window.last = None;
+        }
+        if window.active == removed.id {
+            // the last pane, otherwise the previous or the next one
+            let neighbour = location.pane.saturating_sub(1);
+            window.active = window.last.take().unwrap_or(window.panes[neighbour].id);
+        }
+    }
+
+    fn select_pane_command(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let location = self.target_pane(args.get('t'))?;
+        if let Some(title) = args.get('T') {
+            let title = title.to_string();
+            self.window_mut(location).panes[location.pane].title = title;
+            return Ok(());
+        }
+        if ['d', 'e', 'g', 'm', 'M', 'Z']
+            .iter()
+            .any(|flag| args.has(*flag))
+        {
+            return Ok(());
+        }
+        let window = self.window(location);
+        let target = window.panes[location.pane].id;
+        let directions = [
+            ('U', LayoutDirection::Up),
+            ('D', LayoutDirection::Down),
+            ('L', LayoutDirection::Left),
+            ('R', LayoutDirection::Right),
+        ];
+        let id = if args.has('l') {
+            window.last.ok_or("no last pane")?
+        } else if let Some((_, direction)) = directions.iter().find(|(flag, _)| args.has(*flag)) {
+            window
+                .layout
+                .neighbour(target, *direction)
+                .and_then(|cell| cell.id)
+                .unwrap_or(target)
+        } else {
+            target
+        };
+        self.window_mut(location).select_pane(id);
+        Ok(())
+    }
+
+    fn list_panes(&self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let mut windows = Vec::new();
+        if args.has('a') {
+            for (i, session) in self.sessions.iter().enumerate() {
+                windows.extend((0..session.windows.len()).map(|j| (i, j)));
+            }
+        } else if args.has('s') {
+            let session = self.target_session(args.get('t'))?;
+            windows.extend((0..self.sessions[session].windows.len()).map(|j| (session, j)));
+        } else {
+            let location = self.target_window(args.get('t'))?;
+            windows.push((location.session, location.window));
+        }
+        let format = match (args.get('F'), args.has('a'), args.has('s')) {
+            (Some(format), _, _) => format.to_string(),
+            (None, true, _) => format!(
+                "{}{}{}",
+                ALL_SESSIONS_PREFIX, ALL_WINDOWS_PREFIX, LIST_PANES_FORMAT
+            ),
+            (None, false, true) => format!("{}{}", ALL_WINDOWS_PREFIX, LIST_PANES_FORMAT),
+            (None, false, false) => LIST_PANES_FORMAT.to_string(),
+        };
+        for (session, window) in windows {
+            for pane in 0..self.sessions[session].windows[window].panes.len() {
+                let location = Location {
+                    session,
+                    window,
+                    pane,
+                };
+                self.print_filtered(args, stdout, &format, &FormatContext::pane(location));
+            }
+        }
+        Ok(())
+    }
+
+    // miscellaneous
+
+    fn display_message(&self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let context = match (args.get('t'), self.sessions.is_empty()) {
+            (None, true) => FormatContext::default(),
+            (target, _) => FormatContext::pane(self.target_pane(target)?),
+        };
+        let format = args
+            .get('F')
+            .or_else(|| args.param(0))
+            .unwrap_or(DISPLAY_MESSAGE_FORMAT);
+        if args.has('p') {
+            print(stdout, &self.expand(format, &context));
+        }
+        Ok(())
+    }
+
+    // options
+
+    // options scope given by the flags, otherwise by the option kind
+    fn option_scope(&self, args: &FakeArgs, name: Option<&str>) -> Result<Scope, String> {
+        let window_command = matches!(args.name, "set-window-option" | "show-window-options");
+        let kind = if args.has('s') {
+            OptionKind::Server
+        } else if args.has('p') {
+            OptionKind::Pane
+        } else if args.has('w') || window_command {
+            OptionKind::Window
+        } else {
+            match name {
+                // pane options are set for all panes of the window if `-p` is not given
+                Some(name) => match option_kind(name)? {
+                    OptionKind::Pane => OptionKind::Window,
+                    kind => kind,
+                },
+                None => OptionKind::Session,
+            }
+        };
+        let target = args.get('t');
+        Ok(match (kind, args.has('g')) {
+            (OptionKind::Server, _) => Scope::Server,
+            (OptionKind::Session, true) => Scope::GlobalSession,
+            (OptionKind::Session, false) => Scope::Session(self.target_session(target)?),
+            (_, true) => Scope::GlobalWindow,
+            (OptionKind::Window, false) => Scope::Window(self.target_window(target)?),
+            (OptionKind::Pane, false) => Scope::Pane(self.target_pane(target)?),
+        })
+    }
+
+    fn options(&self, scope: Scope) -> &FakeOptions {
+        match scope {
+            Scope::Server => &self.server_options,
+            Scope::GlobalSession => &self.session_options,
+            Scope::Session(session) => &self.sessions[session].options,
+            Scope::GlobalWindow => &self.window_options,
+            Scope::Window(location) => &self.window(location).options,
+            Scope::Pane(location) => &self.pane(location).options,
+        }
+    }
+
+    fn options_mut(&mut self, scope: Scope) -> &mut FakeOptions {
+        match scope {
+            Scope::Server => &mut self.server_options,
+            Scope::GlobalSession => &mut self.session_options,
+            Scope::Session(session) => &mut self.sessions[session].options,
+            Scope::GlobalWindow => &mut self.window_options,
+            Scope::Window(location) => &mut self.window_mut(location).options,
+            Scope::Pane(location) => &mut self.window_mut(location).panes[location.pane].options,
+        }
+    }
+
+    // options the scope inherits from, the nearest first
+    fn parent_options(&self, scope: Scope) -> Vec<&FakeOptions> {
+        match scope {
+            Scope::Server | Scope::GlobalSession | Scope::GlobalWindow => Vec::new(),
+            Scope::Session(_) => vec![&self.session_options],
+            Scope::Window(_) => vec![&self.window_options],
+            Scope::Pane(location) => vec![&self.window(location).options, &self.window_options],
+        }
+    }
+
+    fn format_context(&self, scope: Scope) -> FormatContext {
+        match scope {
+            Scope::Session(session) => FormatContext::session(session),
+            Scope::Window(location) | Scope::Pane(location) => FormatContext::pane(location),
+            _ => match self.current_session() {
+                Ok(session) => FormatContext::pane(self.active_location(session)),
+                Err(_) => FormatContext::default(),
+            },
+        }
+    }
+
+    fn set_option(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let (name, index) = split_index(args.param(0).ok_or("invalid option: ")?)?;
+        let scope = match self.option_scope(args, Some(name)) {
+            Ok(scope) => scope,
+            Err(_) if args.has('q') => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let value = match args.has('F') {
+            true => args
+                .param(1)
+                .map(|value| self.expand(value, &self.format_context(scope))),
+            false => args.param(1).map(|value| value.to_string()),
+        };
+        let info = option_info(name);
+
+        if args.has('u') || args.has('U') {
+            let global = matches!(
+                scope,
+                Scope::Server | Scope::GlobalSession | Scope::GlobalWindow
+            );
+            let options = self.options_mut(scope);
+            match (index, info) {
+                (Some(i), _) => {
+                    if let Some(FakeValue::Array(items)) = options.get_mut(name) {
+                        items.remove(&i);
+                    }
+                }
+                (None, Some(info)) if global => {
+                    options.insert(name.to_string(), default_value(info));
+                }
+                (None, _) => {
+                    options.remove(name);
+                }
+            }
+            return Ok(());
+        }
+        if args.has('o') && self.options(scope).contains_key(name) {
+            return Err(format!("already set: {}", name));
+        }
+
+        if info.map(|info| info.array).unwrap_or_default() {
+            let value = value.ok_or_else(|| format!("empty value: {}", name))?;
+            let options = self.options_mut(scope);
+            let option = options
+                .entry(name.to_string())
+                .or_insert_with(|| FakeValue::Array(BTreeMap::new()));
+            if let FakeValue::String(_) = option {
+                *option = FakeValue::Array(BTreeMap::new());
+            }
+            if let FakeValue::Array(items) = option {
+                match (index, args.has('a')) {
+                    (Some(i), _) => {
+                        items.insert(i, value);
+                    }
+                    (None, true) => {
+                        let next = items.keys().last().map(|i| i + 1).unwrap_or_default();
+                        items.insert(next, value);
+                    }
+                    (None, false) => {
+                        items.clear();
+                        items.insert(0, value);
+                    }
+                }
+            }
+            return Ok(());
+        }
+        if index.is_some() {
+            return Err(format!("not an array: {}", name));
+        }
+
+        // appended to the value of the scope, toggled value may be inherited
+        let mut options = vec![self.options(scope)];
+        if !args.has('a') {
+            options.extend(self.parent_options(scope));
+        }
+        let old = string_option(&options, name);
+        let new = new_value(name, old, value.as_deref(), args.has('a'))?;
+        self.options_mut(scope)
+            .insert(name.to_string(), FakeValue::String(new));
+        Ok(())
+    }
+
+    fn show_options(&self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let option = match args.param(0) {
+            Some(name) => Some(split_index(name)?),
+            None => None,
+        };
+        let scope = match self.option_scope(args, option.map(|(name, _)| name)) {
+            Ok(scope) => scope,
+            Err(_) if args.has('q') => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let values_only = args.has('v');
+        let mut chain = vec![self.options(scope)];
+        if args.has('A') {
+            chain.extend(self.parent_options(scope));
+        }
+
+        let mut lines = Vec::new();
+        match option {
+            Some((name, index)) => {
+                let found = chain
+                    .iter()
+                    .enumerate()
+                    .find_map(|(i, options)| options.get(name).map(|value| (i > 0, value)));
+                match found {
+                    Some((inherited, value)) => {
+                        lines = show_lines(name, value, index, values_only, inherited)
+                    }
+                    None if option_info(name).is_some() || args.has('q') => {}
+                    None => return Err(format!("invalid option: {}", name)),
+                }
+            }
+            None => {
+                let mut all = BTreeMap::new();
+                for (i, options) in chain.iter().enumerate() {
+                    for (name, value) in options.iter() {
+                        all.entry(name).or_insert((i > 0, value));
+                    }
+                }
+                for (name, (inherited, value)) in all {
+                    lines.extend(show_lines(name, value, None, values_only, inherited));
+                }
+            }
+        }
+        for line in lines {
+            print(stdout, &line);
+        }
+        Ok(())
+    }
+
+    // buffers
+
+    fn find_buffer(&self, name: Option<&str>) -> Result<usize, String> {
+        match name {
+            Some(name) => self
+                .buffers
+                .iter()
+                .position(|buffer| buffer.name == name)
+                .ok_or_else(|| format!("no buffer {}", name)),
+            None => match self.buffers.is_empty() {
+                true => Err("no buffers".to_string()),
+                false => Ok(0),
+            },
+        }
+    }
+
+    // buffer replaced by the new one (the most recent), automatic buffers over the limit are
+    // removed
+    fn set_buffer(&mut self, name: Option<&str>, data: Vec<u8>) {
+        let (name, automatic) = match name {
+            Some(name) => (name.to_string(), false),
+            None => {
+                let name = format!("{}{}", AUTOMATIC_BUFFER_PREFIX, self.next_buffer_index);
+                self.next_buffer_index += 1;
+                (name, true)
+            }
+        };
+        self.buffers.retain(|buffer| buffer.name != name);
+        self.buffers.insert(
+            0,
+            FakeBuffer {
+                name,
+                data,
+                created: now(),
+                automatic,
+            },
+        );
+
+        let limit = string_option(&[&self.server_options], BUFFER_LIMIT)
+            .and_then(|limit| limit.parse::<usize>().ok())
+            .unwrap_or(usize::MAX);
+        while self
+            .buffers
+            .iter()
+            .filter(|buffer| buffer.automatic)
+            .count()
+            > limit
+        {
+            if let Some(oldest) = self.buffers.iter().rposition(|buffer| buffer.automatic) {
+                self.buffers.remove(oldest);
+            }
+        }
+    }
+
+    fn set_buffer_command(&mut self, args: &FakeArgs) -> Result<(), String> {
+        if let Some(new_name) = args.get('n') {
+            let buffer = self.find_buffer(args.get('b'))?;
+            self.buffers.retain(|buffer| buffer.name != new_name);
+            let buffer = buffer.min(self.buffers.len().saturating_sub(1));
+            let buffer = &mut self.buffers[buffer];
+            buffer.name = new_name.to_string();
+            buffer.automatic = false;
+            return Ok(());
+        }
+        let data = args.param(0).ok_or("no data specified")?.as_bytes();
+        let mut new = Vec::new();
+        if args.has('a') {
+            if let Ok(buffer) = self.find_buffer(args.get('b')) {
+                new.extend_from_slice(&self.buffers[buffer].data);
+            }
+        }
+        new.extend_from_slice(data);
+        let name = match (args.get('b'), args.has('a')) {
+            (Some(name), _) => Some(name.to_string()),
+            (None, true) => self.buffers.first().map(|buffer| buffer.name.clone()),
+            (None, false) => None,
+        };
+        self.set_buffer(name.as_deref(), new);
+        Ok(())
+    }
+
+    fn show_buffer(&self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let buffer = self.find_buffer(args.get('b'))?;
+        stdout.extend_from_slice(&self.buffers[buffer].data);
+        Ok(())
+    }
+
+    fn delete_buffer(&mut self, args: &FakeArgs) -> Result<(), String> {
+        let buffer = self.find_buffer(args.get('b'))?;
+        self.buffers.remove(buffer);
+        Ok(())
+    }
+
+    fn list_buffers(&self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let format = args.get('F').unwrap_or(LIST_BUFFERS_FORMAT);
+        for buffer in 0..self.buffers.len() {
+            self.print_filtered(args, stdout, format, &FormatContext::buffer(buffer));
+        }
+        Ok(())
+    }
+
+    fn save_buffer(&self, args: &FakeArgs, stdout: &mut Vec<u8>) -> Result<(), String> {
+        let buffer = self.find_buffer(args.get('b'))?;
+        let data = &self.buffers[buffer].data;
+        match args.param(0).ok_or("no path specified")? {
+            STDIO_PATH => stdout.extend_from_slice(data),
+            path => {
+                let mut file = OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .append(args.has('a'))
+                    .truncate(!args.has('a'))
+                    .open(path)
+                    .map_err(|err| format!("{}: {}", path, err))?;
+                file.write_all(data)
+                    .map_err(|err| format!("{}: {}", path, err))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn load_buffer(&mut self, args: &FakeArgs, input: &[u8]) -> Result<(), String> {
+        let data = match args.param(0).ok_or("no path specified")? {
+            STDIO_PATH => input.to_vec(),
+            path => fs::read(path).map_err(|err| format!("{}: {}", path, err))?,
+        };
+        self.set_buffer(args.get('b'), data);
+        Ok(())
+    }
+
+    // print the expanded format if the filter (`-f`) is true
+    fn print_filtered(
+        &self,
+        args: &FakeArgs,
+        stdout: &mut Vec<u8>,
+        format: &str,
+        context: &FormatContext,
+    ) {
+        let selected = args
+            .get('f')
+            .map(|filter| is_true(&self.expand(filter, context)))
+            .unwrap_or(true);
+        if selected {
+            print(stdout, &self.expand(format, context));
+        }
+    }
+}
+
+fn print(stdout: &mut Vec<u8>, line: &str) {
+    stdout.extend_from_slice(line.as_bytes());
+    stdout.push(b'\n');
+}
+
+fn session_name(name: &str) -> String {
+    name.replace(SESSION_NAME_INVALID, SESSION_NAME_REPLACEMENT)
+}
+
+fn percentage(s: &str) -> Result<f64, String> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|percent| (0.0..=100.0).contains(percent))
+        .map(|percent| percent / 100.0)
+        .ok_or_else(|| format!("percentage {}", s))
+}
+
+// new pane placed before the target one (`split-window -b`)
+fn swap_ids(cell: &mut LayoutCell, a: usize, b: usize) {
+    match cell.id {
+        Some(id) if id == a => cell.id = Some(b),
+        Some(id) if id == b => cell.id = Some(a),
+        _ => {}
+    }
+    if let Some(cells) = cell.cells.as_mut() {
+        for cell in cells {
+            swap_ids(cell, a, b);
+        }
+    }
+}
+
+// remove the pane cell, the parent of the single cell is replaced by it
+fn remove_cell(cell: &mut LayoutCell, id: usize) -> bool {
+    let cells = match cell.cells.as_mut() {
+        Some(cells) => cells,
+        None => return false,
+    };
+    let removed = match cells.iter().position(|c| c.is_pane() && c.id == Some(id)) {
+        Some(i) => {
+            cells.remove(i);
+            true
+        }
+        None => cells.iter_mut().any(|c| remove_cell(c, id)),
+    };
+    if cells.len() == 1 {
+        let child = cells.remove(0);
+        cell.id = child.id;
+        cell.style = child.style;
+        cell.cells = child.cells;
+    }
+    removed
+}
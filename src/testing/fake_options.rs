@@ -0,0 +1,202 @@
+// options of the fake server (ref: options.c, cmd-set-option.c, cmd-show-options.c)
+use crate::{OptionInfo, OptionKind, OptionType, OptionsRegistry};
+use std::collections::BTreeMap;
+
+const USER_OPTION_MARKER: char = '@';
+const ARRAY_INDEX_START: char = '[';
+const ARRAY_INDEX_END: char = ']';
+const INHERITED_MARKER: char = '*';
+const FLAG_ON: &str = "on";
+const FLAG_OFF: &str = "off";
+const FLAG_ON_VALUES: &[&str] = &["on", "yes", "1"];
+// characters quoted by tmux (ref: arguments.c -> args_escape())
+const DOUBLE_QUOTED: &[char] = &[' ', '#', '\'', ';', '$', '{', '}', '%'];
+const SINGLE_QUOTED: &[char] = &[' ', '"'];
+
+/// Option value, array options are indexed items
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(crate) enum FakeValue {
+    String(String),
+    Array(BTreeMap<usize, String>),
+}
+
+/// Options of a single scope (server, session, window or pane)
+pub(crate) type FakeOptions = BTreeMap<String, FakeValue>;
+
+/// Default options of the kinds, as set by the latest tmux
+pub(crate) fn default_options(kinds: &[OptionKind]) -> FakeOptions {
+    OptionsRegistry::all()
+        .iter()
+        .filter(|info| info.removed.is_none() && kinds.contains(&info.kind))
+        .map(|info| (info.name.to_string(), default_value(info)))
+        .collect()
+}
+
+pub(crate) fn default_value(info: &OptionInfo) -> FakeValue {
+    match info.array {
+        true => FakeValue::Array(
+            info.default
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (i, item.to_string()))
+                .collect(),
+        ),
+        false => FakeValue::String(
+            info.default
+                .first()
+                .copied()
+                .unwrap_or_default()
+                .to_string(),
+        ),
+    }
+}
+
+/// Known option supported by the latest tmux, user options are not registered
+pub(crate) fn option_info(name: &str) -> Option<&'static OptionInfo> {
+    OptionsRegistry::find(name).find(|info| info.removed.is_none())
+}
+
+pub(crate) fn is_user_option(name: &str) -> bool {
+    name.starts_with(USER_OPTION_MARKER)
+}
+
+/// Option kind used if not given by the flags, user options are session options
+pub(crate) fn option_kind(name: &str) -> Result<OptionKind, String> {
+    match option_info(name) {
+        Some(info) => Ok(info.kind),
+        None if is_user_option(name) => Ok(OptionKind::Session),
+        None => Err(format!("invalid option: {}", name)),
+    }
+}
+
+/// Option name and array index (`name[1]`)
+pub(crate) fn split_index(name: &str) -> Result<(&str, Option<usize>), String> {
+    match name
+        .strip_suffix(ARRAY_INDEX_END)
+        .and_then(|name| name.split_once(ARRAY_INDEX_START))
+    {
+        Some((name, index)) => index
+            .parse()
+            .map(|index| (name, Some(index)))
+            .map_err(|_| format!("invalid option: {}", name)),
+        None => Ok((name, None)),
+    }
+}
+
+/// New value of the option set to `value` (`None` toggles flags and two-choice options)
+pub(crate) fn new_value(
+    name: &str,
+    old: Option<&str>,
+    value: Option<&str>,
+    append: bool,
+) -> Result<String, String> {
+    let info = match option_info(name) {
+        Some(info) => info,
+        None => {
+            return match value {
+                Some(value) if append => Ok(format!("{}{}", old.unwrap_or_default(), value)),
+                Some(value) => Ok(value.to_string()),
+                None => Err(format!("empty value: {}", name)),
+            }
+        }
+    };
+    info.validate_value(value)
+        .map_err(|_| format!("bad value: {}", value.unwrap_or_default()))?;
+    Ok(match (info.value_type, value) {
+        (OptionType::Flag, None) => match old {
+            Some(FLAG_ON) => FLAG_OFF,
+            _ => FLAG_ON,
+        }
+        .to_string(),
+        (OptionType::Flag, Some(value)) => match FLAG_ON_VALUES.contains(&value) {
+            true => FLAG_ON,
+            false => FLAG_OFF,
+        }
+        .to_string(),
+        (OptionType::Choice(choices), None) => {
+            let toggled = match choices.first().copied() == old {
+                true => choices.get(1),
+                false => choices.first(),
+            };
+            toggled.copied().unwrap_or_default().to_string()
+        }
+        (OptionType::String, Some(value)) | (OptionType::Style, Some(value)) if append => {
+            format!("{}{}", old.unwrap_or_default(), value)
+        }
+        (_, value) => value.unwrap_or_default().to_string(),
+    })
+}
+
+/// `show-options` lines of the option (`name value`, `name[i] value` for arrays), values only
+/// (`-v`) or marked as inherited from the parent (`-A`)
+pub(crate) fn show_lines(
+    name: &str,
+    value: &FakeValue,
+    index: Option<usize>,
+    values_only: bool,
+    inherited: bool,
+) -> Vec<String> {
+    let marker = match inherited {
+        true => INHERITED_MARKER.to_string(),
+        false => String::new(),
+    };
+    let line = |name: String, value: &str| match values_only {
+        true => value.to_string(),
+        false => format!("{}{} {}", name, marker, escape(value)),
+    };
+    match (value, index) {
+        (FakeValue::String(value), _) => vec![line(name.to_string(), value)],
+        (FakeValue::Array(items), Some(i)) => items
+            .get(&i)
+            .map(|item| line(format!("{}[{}]", name, i), item))
+            .into_iter()
+            .collect(),
+        (FakeValue::Array(items), None) if items.is_empty() && !values_only => {
+            vec![format!("{}{}", name, marker)]
+        }
+        (FakeValue::Array(items), None) => items
+            .iter()
+            .map(|(i, item)| line(format!("{}[{}]", name, i), item))
+            .collect(),
+    }
+}
+
+/// Value quoted and escaped as printed by `show-options` (ref: arguments.c -> args_escape())
+pub(crate) fn escape(s: &str) -> String {
+    if s.is_empty() {
+        return "''".to_string();
+    }
+    let quote = if s.contains(DOUBLE_QUOTED) {
+        Some('"')
+    } else if s.contains(SINGLE_QUOTED) {
+        Some('\'')
+    } else {
+        None
+    };
+    let mut chars = s.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c != ' ' && (quote.is_some() || c == '~') {
+            return format!("\\{}", c);
+        }
+    }
+
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\\' => escaped.push_str("\\\\"),
+            '"' | '$' | '`' if quote == Some('"') => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if c.is_control() => escaped.push_str(&format!("\\{:03o}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    match quote {
+        Some(quote) => format!("{}{}{}", quote, escaped, quote),
+        None => escaped,
+    }
+}
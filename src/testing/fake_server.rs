@@ -0,0 +1,140 @@
+use super::fake_args::{split_commands, FakeArgs};
+use super::fake_state::FakeState;
+use super::test_server::{HEIGHT, WIDTH};
+use crate::{Error, TmuxCommand, TmuxOutput};
+use std::cell::RefCell;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{ExitStatus, Output};
+
+const EXIT_FAILURE: i32 = 1;
+
+/// In-process fake tmux server interpreting commands against an in-memory model, for tests
+/// without tmux installed
+///
+/// Supported commands (names and aliases):
+/// * sessions: `new-session`, `kill-session`, `has-session`, `rename-session`, `list-sessions`,
+///   `kill-server`, `start-server`
+/// * windows: `new-window`, `kill-window`, `rename-window`, `select-window`, `list-windows`
+/// * panes: `split-window`, `kill-pane`, `select-pane`, `list-panes`
+/// * `display-message -p`
+/// * options: `set-option`, `show-options` (and their window variants)
+/// * buffers: `set-buffer`, `show-buffer`, `delete-buffer`, `list-buffers`, `save-buffer`,
+///   `load-buffer`
+///
+/// Formats (`-F`, `-f`) support variables (`#{session_name}`, `#S`), conditionals
+/// (`#{?pane_active,a,b}`) and comparisons (`#{==:a,b}`). Options are seeded with the defaults
+/// of the [`OptionsRegistry`](crate::OptionsRegistry), pane sizes are calculated by the
+/// [`LayoutCell`](crate::LayoutCell) geometry. Commands of a sequence (`;`) stop at the first
+/// error, failed commands exit with status `1` and the error message on stderr like tmux does.
+/// Unsupported commands fail with `unknown command`.
+///
+/// The server is not running until a session is created (`new-session`) and exits when the last
+/// session is killed (unless `exit-empty` is `off`).
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{FakeServer, NewSession, SessionsCtl, WindowsCtl};
+///
+/// let server = FakeServer::new();
+/// let invoker = server.invoker();
+/// invoker(NewSession::new().detached().session_name("test").build()).unwrap();
+///
+/// let sessions = SessionsCtl::with_invoker(&invoker).get_all().unwrap();
+/// assert_eq!(sessions.into_iter().count(), 1);
+/// let windows = WindowsCtl::with_invoker(&invoker).get_all().unwrap();
+/// assert_eq!(windows.into_iter().count(), 1);
+///
+/// assert_eq!(server.commands().len(), 3);
+/// ```
+#[derive(Debug)]
+pub struct FakeServer {
+    state: RefCell<FakeState>,
+    // arguments of the invoked commands
+    commands: RefCell<Vec<String>>,
+}
+
+impl Default for FakeServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeServer {
+    /// Server with the default size of the sessions ([`WIDTH`](crate::testing::test_server::WIDTH) x
+    /// [`HEIGHT`](crate::testing::test_server::HEIGHT))
+    pub fn new() -> Self {
+        Self::with_size(WIDTH, HEIGHT)
+    }
+
+    /// Server with the default size of the sessions (`default-size`)
+    pub fn with_size(width: usize, height: usize) -> Self {
+        Self {
+            state: RefCell::new(FakeState::new(width, height)),
+            commands: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Invoker of the commands on the server, used by ctls (e.g.
+    /// [`SessionsCtl::with_invoker()`](crate::SessionsCtl::with_invoker))
+    pub fn invoker(&self) -> impl Fn(TmuxCommand) -> Result<TmuxOutput, Error> + '_ {
+        move |cmd| self.output(cmd)
+    }
+
+    /// Invoker of the commands reading stdin (`load-buffer -`), used by
+    /// [`BuffersCtl::with_invokers()`](crate::BuffersCtl::with_invokers)
+    pub fn stdin_invoker(&self) -> impl Fn(TmuxCommand, &[u8]) -> Result<TmuxOutput, Error> + '_ {
+        move |cmd, input| self.output_with_stdin(cmd, input)
+    }
+
+    /// Invoke the command on the server
+    pub fn output<'a, T: Into<TmuxCommand<'a>>>(&self, cmd: T) -> Result<TmuxOutput, Error> {
+        self.output_with_stdin(cmd, &[])
+    }
+
+    /// Invoke the command on the server with the input
+    pub fn output_with_stdin<'a, T: Into<TmuxCommand<'a>>>(
+        &self,
+        cmd: T,
+        input: &[u8],
+    ) -> Result<TmuxOutput, Error> {
+        let cmd: TmuxCommand<'a> = cmd.into();
+        self.commands.borrow_mut().push(cmd.to_vec().join(" "));
+
+        let mut state = self.state.borrow_mut();
+        let mut stdout = Vec::new();
+        let mut result = Ok(());
+        for argv in split_commands(&cmd) {
+            result =
+                FakeArgs::parse(&argv).and_then(|args| state.execute(&args, input, &mut stdout));
+            if result.is_err() {
+                break;
+            }
+        }
+        state.exit_if_empty();
+
+        let (status, stderr) = match result {
+            Ok(()) => (ExitStatus::from_raw(0), Vec::new()),
+            Err(err) => (
+                ExitStatus::from_raw(EXIT_FAILURE << 8),
+                format!("{}\n", err).into_bytes(),
+            ),
+        };
+        Ok(TmuxOutput(Output {
+            status,
+            stdout,
+            stderr,
+        }))
+    }
+
+    /// Commands invoked on the server (arguments joined by spaces), like `scripts/tmux_mock.sh`
+    /// logs them
+    pub fn commands(&self) -> Vec<String> {
+        self.commands.borrow().clone()
+    }
+
+    /// The server is running (a session was created and not all were killed)
+    pub fn is_running(&self) -> bool {
+        self.state.borrow().running
+    }
+}
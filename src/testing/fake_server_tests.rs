@@ -0,0 +1,386 @@
+#[test]
+fn fake_server_sessions() {
+    use crate::{
+        Error, FakeServer, HasSession, KillSession, NewSession, RenameSession, SessionsCtl,
+    };
+
+    let server = FakeServer::new();
+    let invoker = server.invoker();
+    assert!(!server.is_running());
+
+    invoker(NewSession::new().detached().session_name("first").build()).unwrap();
+    invoker(NewSession::new().detached().session_name("second").build()).unwrap();
+    let output = invoker(NewSession::new().detached().session_name("first").build()).unwrap();
+    assert!(!output.success());
+    assert_eq!(output.stderr(), b"duplicate session: first\n");
+
+    let sessions = SessionsCtl::with_invoker(&invoker).get_all().unwrap();
+    let names: Vec<_> = sessions.into_iter().map(|s| (s.id, s.name)).collect();
+    assert_eq!(
+        names,
+        [
+            (Some(0), Some("first".to_string())),
+            (Some(1), Some("second".to_string()))
+        ]
+    );
+
+    invoker(
+        RenameSession::new()
+            .target_session("fir")
+            .new_name("renamed")
+            .build(),
+    )
+    .unwrap()
+    .into_result()
+    .unwrap();
+    assert!(
+        invoker(HasSession::new().target_session("=renamed").build())
+            .unwrap()
+            .success()
+    );
+    let result = invoker(HasSession::new().target_session("first").build())
+        .unwrap()
+        .into_result();
+    assert!(matches!(result, Err(Error::Tmux(msg)) if msg == "can't find session: first"));
+
+    // server exits with the last session
+    invoker(KillSession::new().target_session("$0").build()).unwrap();
+    assert!(server.is_running());
+    invoker(KillSession::new().build()).unwrap();
+    assert!(!server.is_running());
+    let result = invoker(HasSession::new().build()).unwrap().into_result();
+    assert!(matches!(result, Err(Error::Tmux(msg)) if msg == "no server running"));
+}
+
+#[test]
+fn fake_server_windows() {
+    use crate::{FakeServer, KillWindow, NewSession, NewWindow, SelectWindow, WindowsCtl};
+
+    let server = FakeServer::with_size(100, 30);
+    let invoker = server.invoker();
+    invoker(NewSession::new().detached().session_name("test").build()).unwrap();
+    invoker(NewWindow::new().window_name("editor").build()).unwrap();
+    invoker(NewWindow::new().detached().target_window("test:5").build()).unwrap();
+    let output = invoker(NewWindow::new().target_window("test:5").build()).unwrap();
+    assert_eq!(output.stderr(), b"index 5 in use\n");
+
+    let windows = WindowsCtl::with_invoker(&invoker)
+        .get(Some("test"))
+        .unwrap();
+    let windows: Vec<_> = windows
+        .into_iter()
+        .map(|w| (w.index, w.name, w.active, w.width, w.height))
+        .collect();
+    assert_eq!(
+        windows,
+        [
+            (
+                Some(0),
+                Some("bash".to_string()),
+                Some(false),
+                Some(100),
+                Some(30)
+            ),
+            (
+                Some(1),
+                Some("editor".to_string()),
+                Some(true),
+                Some(100),
+                Some(30)
+            ),
+            (
+                Some(5),
+                Some("bash".to_string()),
+                Some(false),
+                Some(100),
+                Some(30)
+            ),
+        ]
+    );
+
+    let output = server.output(
+        NewWindow::new()
+            .print()
+            .format("#{window_index}:#{window_id}"),
+    );
+    assert_eq!(output.unwrap().to_string(), "2:@3\n");
+    invoker(SelectWindow::new().last().build()).unwrap();
+    invoker(KillWindow::new().target_window("editor").build()).unwrap();
+    let output = server.output(crate::ListWindows::new().format("#{window_index}#{window_flags}"));
+    assert_eq!(output.unwrap().to_string(), "0-\n2*\n5\n");
+}
+
+#[test]
+fn fake_server_panes() {
+    use crate::{FakeServer, KillPane, NewSession, PanesCtl, SelectPane, SplitWindow};
+
+    let server = FakeServer::new();
+    let invoker = server.invoker();
+    invoker(NewSession::new().detached().build()).unwrap();
+    invoker(SplitWindow::new().horizontal().build()).unwrap();
+    invoker(SplitWindow::new().vertical().build()).unwrap();
+
+    let panes = PanesCtl::with_invoker(&invoker).get_all().unwrap();
+    let panes: Vec<_> = panes
+        .into_iter()
+        .map(|p| (p.id, p.index, p.width, p.height, p.left, p.active))
+        .collect();
+    assert_eq!(
+        panes,
+        [
+            (Some(0), Some(0), Some(40), Some(24), Some(0), Some(false)),
+            (Some(1), Some(1), Some(39), Some(12), Some(41), Some(false)),
+            (Some(2), Some(2), Some(39), Some(11), Some(41), Some(true)),
+        ]
+    );
+
+    let format = "#{pane_id}:#{pane_active}";
+    invoker(SelectPane::new().left().build()).unwrap();
+    let output = server
+        .output(crate::ListPanes::new().format(format))
+        .unwrap();
+    assert_eq!(output.to_string(), "%0:1\n%1:0\n%2:0\n");
+    invoker(SelectPane::new().last().build()).unwrap();
+    invoker(SelectPane::new().target_pane("%1").title("title").build()).unwrap();
+
+    // remaining panes take the space
+    invoker(KillPane::new().target_pane("0.0").build()).unwrap();
+    let output = server
+        .output(
+            crate::ListPanes::new().format("#{pane_id} #{pane_title} #{pane_width}x#{pane_height}"),
+        )
+        .unwrap();
+    assert_eq!(output.to_string(), "%1 title 80x12\n%2  80x11\n");
+    invoker(KillPane::new().all().target_pane("%1").build()).unwrap();
+    let output = server.output(crate::ListWindows::new().format("#{window_layout}"));
+    assert_eq!(output.unwrap().to_string(), "b25e,80x24,0,0,1\n");
+}
+
+#[test]
+fn fake_server_targets() {
+    use crate::{DisplayMessage, Error, FakeServer, NewSession, NewWindow, SplitWindow};
+
+    let server = FakeServer::new();
+    let invoker = server.invoker();
+    invoker(NewSession::new().detached().session_name("alpha").build()).unwrap();
+    invoker(NewWindow::new().window_name("logs").build()).unwrap();
+    invoker(SplitWindow::new().build()).unwrap();
+    invoker(NewSession::new().detached().session_name("beta").build()).unwrap();
+
+    let display = |target: &'static str| {
+        let cmd = DisplayMessage::new()
+            .print()
+            .target_pane(target)
+            .message("#S:#I.#P #D");
+        invoker(cmd.build()).unwrap().into_result()
+    };
+    assert_eq!(display("%2").unwrap().to_string(), "alpha:1.1 %2\n");
+    assert_eq!(
+        display("alpha:logs.0").unwrap().to_string(),
+        "alpha:1.0 %1\n"
+    );
+    assert_eq!(display("alp:").unwrap().to_string(), "alpha:1.1 %2\n");
+    assert_eq!(display("@0").unwrap().to_string(), "alpha:0.0 %0\n");
+    assert_eq!(display("beta").unwrap().to_string(), "beta:0.0 %3\n");
+    assert_eq!(display(":.").unwrap().to_string(), "beta:0.0 %3\n");
+    assert_eq!(display("alpha:!").unwrap().to_string(), "alpha:0.0 %0\n");
+    assert!(matches!(display("alpha:1.5"), Err(Error::Tmux(msg)) if msg == "can't find pane: 5"));
+    assert!(display("gamma:0").is_err());
+}
+
+#[test]
+fn fake_server_formats() {
+    use crate::{DisplayMessage, FakeServer, NewSession, SetOption};
+
+    let server = FakeServer::new();
+    let invoker = server.invoker();
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name("test")
+            .window_name("main")
+            .build(),
+    )
+    .unwrap();
+    invoker(SetOption::new().option("@user").value("value").build()).unwrap();
+
+    let display = |format: &'static str| {
+        let output = invoker(DisplayMessage::new().print().message(format).build());
+        output.unwrap().to_string()
+    };
+    assert_eq!(display("#S ## #W"), "test # main\n");
+    assert_eq!(display("#{?pane_active,active,inactive}"), "active\n");
+    assert_eq!(display("#{?#{==:#S,other},yes,no}"), "no\n");
+    assert_eq!(
+        display("#{?window_zoomed_flag,Z,#{?pane_active,A,}}"),
+        "A\n"
+    );
+    assert_eq!(display("#{!=:#{window_name},main}"), "0\n");
+    assert_eq!(display("#{&&:1,#{pane_active}}"), "1\n");
+    assert_eq!(display("#{window_width}x#{window_height}"), "80x24\n");
+    assert_eq!(display("#{@user} #{base-index} #{unknown}"), "value 0 \n");
+}
+
+#[test]
+fn fake_server_options() {
+    use crate::{
+        Error, FakeServer, NewSession, OptionScope, OptionsCtl, SetOption, ShowOptions,
+        UserOptionsCtl,
+    };
+
+    let server = FakeServer::new();
+    let invoker = server.invoker();
+    invoker(NewSession::new().detached().build()).unwrap();
+    let show = |cmd: ShowOptions<'static>| server.output(cmd).unwrap().to_string();
+
+    assert_eq!(
+        show(ShowOptions::new().global().option("status-left")),
+        "status-left \"[#{session_name}] \"\n"
+    );
+    assert_eq!(
+        show(ShowOptions::new().global().value().option("status-left")),
+        "[#{session_name}] \n"
+    );
+
+    // local option, inherited from the global one if not set
+    assert_eq!(
+        show(ShowOptions::new().include_inherited().option("base-index")),
+        "base-index* 0\n"
+    );
+    invoker(SetOption::new().option("base-index").value("1").build()).unwrap();
+    assert_eq!(
+        show(ShowOptions::new().option("base-index")),
+        "base-index 1\n"
+    );
+    invoker(SetOption::new().unset().option("base-index").build()).unwrap();
+    assert_eq!(show(ShowOptions::new().option("base-index")), "");
+
+    // flags are toggled without value, values are validated
+    invoker(SetOption::new().global().option("mouse").build()).unwrap();
+    assert_eq!(
+        show(ShowOptions::new().global().option("mouse")),
+        "mouse on\n"
+    );
+    let output = invoker(
+        SetOption::new()
+            .global()
+            .option("mouse")
+            .value("maybe")
+            .build(),
+    );
+    assert_eq!(output.unwrap().stderr(), b"bad value: maybe\n");
+    let output = invoker(
+        SetOption::new()
+            .global()
+            .option("no-such-option")
+            .value("1")
+            .build(),
+    );
+    assert_eq!(
+        output.unwrap().stderr(),
+        b"invalid option: no-such-option\n"
+    );
+
+    // array items
+    invoker(
+        SetOption::new()
+            .server()
+            .option("command-alias[10]")
+            .value("sp=split-window")
+            .build(),
+    )
+    .unwrap();
+    assert_eq!(
+        show(ShowOptions::new().server().option("command-alias[10]")),
+        "command-alias[10] sp=split-window\n"
+    );
+
+    let options_ctl = OptionsCtl::with_invoker(&invoker).target_pane("%0");
+    let resolved = options_ctl.resolve("status-keys").unwrap();
+    assert_eq!(resolved.value.as_deref(), Some("emacs"));
+    assert_eq!(resolved.source, Some(OptionScope::GlobalSession));
+
+    let user_options = UserOptionsCtl::with_invoker(&invoker, OptionScope::Session(None));
+    user_options.set("ticket-id", 42).unwrap();
+    user_options.set("ticket-owner", "bob smith").unwrap();
+    assert_eq!(user_options.get::<usize>("ticket-id").unwrap(), Some(42));
+    assert_eq!(
+        user_options.list::<String>("ticket-").unwrap()["ticket-owner"],
+        "bob smith"
+    );
+    user_options.delete_all("ticket-").unwrap();
+    assert_eq!(user_options.get::<usize>("ticket-id").unwrap(), None);
+    let result = invoker(ShowOptions::new().option("@ticket-id").build())
+        .unwrap()
+        .into_result();
+    assert!(matches!(result, Err(Error::Tmux(msg)) if msg == "invalid option: @ticket-id"));
+}
+
+#[test]
+fn fake_server_buffers() {
+    use crate::{BuffersCtl, FakeServer, ListBuffers, NewSession, SetBuffer};
+
+    let server = FakeServer::new();
+    let invoker = server.invoker();
+    let stdin_invoker = server.stdin_invoker();
+    invoker(NewSession::new().detached().build()).unwrap();
+    invoker(SetBuffer::new().data("a b").build()).unwrap();
+
+    let data = b"a\0b\xff\n".to_vec();
+    let buffers_ctl = BuffersCtl::with_invokers(&invoker, &stdin_invoker);
+    buffers_ctl.write("binary", &data).unwrap();
+    assert_eq!(buffers_ctl.read(Some("binary")).unwrap(), data);
+    buffers_ctl.rename("binary", "renamed").unwrap();
+
+    let output = server.output(ListBuffers::new()).unwrap();
+    assert_eq!(
+        output.to_string(),
+        "renamed: 5 bytes: \"a\\000b\u{fffd}\\n\"\nbuffer0: 3 bytes: \"a b\"\n"
+    );
+    let buffer = buffers_ctl.get("buffer0").unwrap().unwrap();
+    assert_eq!(buffer.size, Some(3));
+    assert_eq!(buffer.sample.as_deref(), Some("a b"));
+
+    buffers_ctl.delete("renamed").unwrap();
+    assert!(buffers_ctl.delete("renamed").is_err());
+    assert_eq!(buffers_ctl.read(None::<&str>).unwrap(), b"a b");
+}
+
+#[test]
+fn fake_server_commands() {
+    use crate::{
+        DisplayMessage, FakeServer, KillServer, NewSession, NewWindow, TmuxCommand, TmuxCommands,
+    };
+
+    let server = FakeServer::new();
+    let output = server.output(NewWindow::new()).unwrap();
+    assert_eq!(output.stderr(), b"no server running\n");
+
+    // sequence stops at the first error
+    let cmds = TmuxCommands::new()
+        .cmd(NewSession::new().detached().session_name("a").build())
+        .cmd(DisplayMessage::new().print().message("#S").build())
+        .cmd(TmuxCommand::new().name("attach-session").to_owned())
+        .cmd(DisplayMessage::new().print().message("not printed").build());
+    let output = server.output(TmuxCommand::with_cmds(cmds)).unwrap();
+    assert_eq!(output.code(), Some(1));
+    assert_eq!(output.to_string(), "a\n");
+    assert_eq!(output.stderr(), b"unknown command: attach-session\n");
+
+    server.output(KillServer::new()).unwrap();
+    assert!(!server.is_running());
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let expected = [
+        "new-window",
+        "new-session -d -s a ; display-message -p #S ; attach-session ; display-message -p not printed",
+        "kill-server",
+    ];
+    #[cfg(feature = "cmd_alias")]
+    let expected = [
+        "neww",
+        "new -d -s a ; display -p #S ; attach-session ; display -p not printed",
+        "kill-server",
+    ];
+    assert_eq!(server.commands(), expected);
+}
@@ -0,0 +1,911 @@
+// in-memory model of the fake server: sessions, windows, panes, buffers and options, targets
+// (ref: cmd-find.c) and formats (ref: format.c)
+use super::fake_options::{default_options, FakeOptions, FakeValue};
+use crate::{Layout, LayoutCell, OptionKind};
+use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SESSION_ID_PREFIX: char = '$';
+const WINDOW_ID_PREFIX: char = '@';
+const PANE_ID_PREFIX: char = '%';
+const EXACT_PREFIX: char = '=';
+const SESSION_SEPARATOR: char = ':';
+const PANE_SEPARATOR: char = '.';
+const LAST: &str = "!";
+const FIRST_WINDOW: &str = "^";
+const LAST_WINDOW: &str = "$";
+const NEXT: &str = "+";
+const PREVIOUS: &str = "-";
+const BASE_INDEX: &str = "base-index";
+const PANE_BASE_INDEX: &str = "pane-base-index";
+const DEFAULT_SHELL: &str = "default-shell";
+const DEFAULT_SIZE: &str = "default-size";
+const DEFAULT_SIZE_SEPARATOR: char = 'x';
+const TRUE: &str = "1";
+const FALSE: &str = "0";
+// buffer sample length (ref: paste.c -> paste_make_sample())
+const SAMPLE_WIDTH: usize = 200;
+const SAMPLE_ELLIPSIS: &str = "...";
+// single character aliases of the variables (ref: format.c -> format_upper, format_lower)
+const ALIASES: &[(char, &str)] = &[
+    ('D', "pane_id"),
+    ('F', "window_flags"),
+    ('I', "window_index"),
+    ('P', "pane_index"),
+    ('S', "session_name"),
+    ('T', "pane_title"),
+    ('W', "window_name"),
+];
+const COMPARISONS: &[&str] = &["==:", "!=:", "||:", "&&:"];
+
+#[derive(Debug)]
+pub(crate) struct FakePane {
+    pub id: usize,
+    pub title: String,
+    pub start_command: String,
+    pub current_command: String,
+    pub current_path: String,
+    pub options: FakeOptions,
+}
+
+#[derive(Debug)]
+pub(crate) struct FakeWindow {
+    pub id: usize,
+    pub index: usize,
+    pub name: String,
+    pub panes: Vec<FakePane>,
+    // pane ids
+    pub active: usize,
+    pub last: Option<usize>,
+    pub layout: LayoutCell,
+    pub options: FakeOptions,
+}
+
+impl FakeWindow {
+    pub fn pane_position(&self, id: usize) -> Option<usize> {
+        self.panes.iter().position(|pane| pane.id == id)
+    }
+
+    pub fn active_position(&self) -> usize {
+        self.pane_position(self.active).unwrap_or_default()
+    }
+
+    /// Make the pane active, the previously active pane is the last one
+    pub fn select_pane(&mut self, id: usize) {
+        if self.active != id {
+            self.last = Some(self.active);
+            self.active = id;
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FakeSession {
+    pub id: usize,
+    pub name: String,
+    // sorted by index
+    pub windows: Vec<FakeWindow>,
+    // window ids, the most recently selected first (active, last, ...)
+    pub stack: Vec<usize>,
+    pub created: u64,
+    // order of the session activity, the most recent is the current session
+    pub activity: u64,
+    pub width: usize,
+    pub height: usize,
+    pub options: FakeOptions,
+}
+
+impl FakeSession {
+    pub fn window_position(&self, id: usize) -> Option<usize> {
+        self.windows.iter().position(|window| window.id == id)
+    }
+
+    pub fn active_position(&self) -> usize {
+        self.stack
+            .first()
+            .and_then(|id| self.window_position(*id))
+            .unwrap_or_default()
+    }
+
+    /// Make the window active, the previously active window is the last one
+    pub fn select_window(&mut self, id: usize) {
+        self.stack.retain(|window| *window != id);
+        self.stack.insert(0, id);
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FakeBuffer {
+    pub name: String,
+    pub data: Vec<u8>,
+    pub created: u64,
+    // named automatically (`buffer0`), removed first if over the `buffer-limit`
+    pub automatic: bool,
+}
+
+/// Pane position (session, window and pane indexes in the model)
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub(crate) struct Location {
+    pub session: usize,
+    pub window: usize,
+    pub pane: usize,
+}
+
+/// Objects the format is expanded for
+#[derive(Clone, Copy, Default, Debug)]
+pub(crate) struct FormatContext {
+    pub session: Option<usize>,
+    pub window: Option<usize>,
+    pub pane: Option<usize>,
+    pub buffer: Option<usize>,
+}
+
+impl FormatContext {
+    pub fn session(session: usize) -> Self {
+        Self {
+            session: Some(session),
+            ..Default::default()
+        }
+    }
+
+    pub fn window(session: usize, window: usize) -> Self {
+        Self {
+            session: Some(session),
+            window: Some(window),
+            ..Default::default()
+        }
+    }
+
+    pub fn pane(location: Location) -> Self {
+        Self {
+            session: Some(location.session),
+            window: Some(location.window),
+            pane: Some(location.pane),
+            buffer: None,
+        }
+    }
+
+    pub fn buffer(buffer: usize) -> Self {
+        Self {
+            buffer: Some(buffer),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FakeState {
+    pub running: bool,
+    // default size of the sessions
+    pub width: usize,
+    pub height: usize,
+    pub sessions: Vec<FakeSession>,
+    // the most recent first
+    pub buffers: Vec<FakeBuffer>,
+    pub server_options: FakeOptions,
+    pub session_options: FakeOptions,
+    pub window_options: FakeOptions,
+    pub next_session_id: usize,
+    pub next_window_id: usize,
+    pub next_pane_id: usize,
+    pub next_buffer_index: usize,
+    pub activity: u64,
+}
+
+impl FakeState {
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut session_options = default_options(&[OptionKind::Session]);
+        session_options.insert(
+            DEFAULT_SIZE.to_string(),
+            FakeValue::String(format!("{}{}{}", width, DEFAULT_SIZE_SEPARATOR, height)),
+        );
+        Self {
+            running: false,
+            width,
+            height,
+            sessions: Vec::new(),
+            buffers: Vec::new(),
+            server_options: default_options(&[OptionKind::Server]),
+            session_options,
+            window_options: default_options(&[OptionKind::Window, OptionKind::Pane]),
+            next_session_id: 0,
+            next_window_id: 0,
+            next_pane_id: 0,
+            next_buffer_index: 0,
+            activity: 0,
+        }
+    }
+
+    /// Server exited, sessions, buffers and options are lost
+    pub fn reset(&mut self) {
+        *self = Self::new(self.width, self.height);
+    }
+
+    pub fn window(&self, location: Location) -> &FakeWindow {
+        &self.sessions[location.session].windows[location.window]
+    }
+
+    pub fn window_mut(&mut self, location: Location) -> &mut FakeWindow {
+        &mut self.sessions[location.session].windows[location.window]
+    }
+
+    pub fn pane(&self, location: Location) -> &FakePane {
+        &self.window(location).panes[location.pane]
+    }
+
+    /// Active pane of the active window of the session
+    pub fn active_location(&self, session: usize) -> Location {
+        let window = self.sessions[session].active_position();
+        self.window_location(session, window)
+    }
+
+    /// Active pane of the window
+    pub fn window_location(&self, session: usize, window: usize) -> Location {
+        let pane = self.sessions[session].windows[window].active_position();
+        Location {
+            session,
+            window,
+            pane,
+        }
+    }
+
+    pub fn tick(&mut self) -> u64 {
+        self.activity += 1;
+        self.activity
+    }
+
+    // session option, the global one if not set
+    pub fn session_option(&self, session: usize, name: &str) -> Option<&str> {
+        let options = [&self.sessions[session].options, &self.session_options];
+        string_option(&options, name)
+    }
+
+    // window option, the global one if not set
+    pub fn window_option(&self, session: usize, window: usize, name: &str) -> Option<&str> {
+        let options = [
+            &self.sessions[session].windows[window].options,
+            &self.window_options,
+        ];
+        string_option(&options, name)
+    }
+
+    pub fn base_index(&self, session: usize) -> usize {
+        number(self.session_option(session, BASE_INDEX))
+    }
+
+    pub fn pane_base_index(&self, session: usize, window: usize) -> usize {
+        number(self.window_option(session, window, PANE_BASE_INDEX))
+    }
+
+    /// Size of the new session (`default-size`)
+    pub fn default_size(&self) -> (usize, usize) {
+        string_option(&[&self.session_options], DEFAULT_SIZE)
+            .and_then(|size| size.split_once(DEFAULT_SIZE_SEPARATOR))
+            .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)))
+            .unwrap_or((self.width, self.height))
+    }
+
+    /// Pane running the shell command (`default-shell` if not given)
+    pub fn new_pane(&mut self, command: &str, path: Option<&str>) -> FakePane {
+        let id = self.next_pane_id;
+        self.next_pane_id += 1;
+        let shell = string_option(&[&self.session_options], DEFAULT_SHELL).unwrap_or_default();
+        let program = match command.split_whitespace().next() {
+            Some(program) => program,
+            None => shell,
+        };
+        let current_path = match path {
+            Some(path) => path.to_string(),
+            None => env::current_dir()
+                .map(|dir| dir.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        };
+        FakePane {
+            id,
+            title: String::new(),
+            start_command: command.to_string(),
+            current_command: program.rsplit('/').next().unwrap_or_default().to_string(),
+            current_path,
+            options: FakeOptions::new(),
+        }
+    }
+
+    /// Window of the single pane, named by `name` or its command
+    pub fn new_window(
+        &mut self,
+        index: usize,
+        name: Option<&str>,
+        pane: FakePane,
+        (width, height): (usize, usize),
+    ) -> FakeWindow {
+        let id = self.next_window_id;
+        self.next_window_id += 1;
+        FakeWindow {
+            id,
+            index,
+            name: name.unwrap_or(&pane.current_command).to_string(),
+            active: pane.id,
+            last: None,
+            layout: LayoutCell::pane(width, height, 0, 0, pane.id),
+            panes: vec![pane],
+            options: FakeOptions::new(),
+        }
+    }
+
+    // targets (ref: cmd-find.c)
+
+    /// The most recently active session
+    pub fn current_session(&self) -> Result<usize, String> {
+        self.sessions
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, session)| session.activity)
+            .map(|(i, _)| i)
+            .ok_or_else(|| "no current session".to_string())
+    }
+
+    /// Session by id (`$1`), exact name (`=name`), name or unique name prefix
+    pub fn find_session(&self, target: &str) -> Result<usize, String> {
+        let error = || format!("can't find session: {}", target);
+        if let Some(id) = target.strip_prefix(SESSION_ID_PREFIX) {
+            let id = id.parse::<usize>().map_err(|_| error())?;
+            return self
+                .sessions
+                .iter()
+                .position(|session| session.id == id)
+                .ok_or_else(error);
+        }
+        if let Some(name) = target.strip_prefix(EXACT_PREFIX) {
+            return self
+                .sessions
+                .iter()
+                .position(|session| session.name == name)
+                .ok_or_else(error);
+        }
+        if let Some(i) = self.sessions.iter().position(|s| s.name == target) {
+            return Ok(i);
+        }
+        unique(
+            self.sessions.iter().map(|session| session.name.as_str()),
+            target,
+        )
+        .ok_or_else(error)
+    }
+
+    /// Window of the session by id (`@1`), index, special token (`!`, `^`, `$`, `+`, `-`),
+    /// exact name (`=name`), name or unique name prefix
+    pub fn find_window(&self, session: usize, target: &str) -> Result<usize, String> {
+        let error = || format!("can't find window: {}", target);
+        let s = &self.sessions[session];
+        let active = s.active_position();
+        let position = match target {
+            LAST => s.stack.get(1).and_then(|id| s.window_position(*id)),
+            FIRST_WINDOW => Some(0),
+            LAST_WINDOW => s.windows.len().checked_sub(1),
+            NEXT => Some((active + 1) % s.windows.len()),
+            PREVIOUS => Some((active + s.windows.len() - 1) % s.windows.len()),
+            _ => None,
+        };
+        if let Some(position) = position {
+            return Ok(position);
+        }
+        if let Some(id) = target.strip_prefix(WINDOW_ID_PREFIX) {
+            let id = id.parse::<usize>().map_err(|_| error())?;
+            return s.window_position(id).ok_or_else(error);
+        }
+        if let Ok(index) = target.parse::<usize>() {
+            if let Some(i) = s.windows.iter().position(|w| w.index == index) {
+                return Ok(i);
+            }
+        }
+        if let Some(name) = target.strip_prefix(EXACT_PREFIX) {
+            return s
+                .windows
+                .iter()
+                .position(|w| w.name == name)
+                .ok_or_else(error);
+        }
+        if let Some(i) = s.windows.iter().position(|w| w.name == target) {
+            return Ok(i);
+        }
+        unique(s.windows.iter().map(|window| window.name.as_str()), target).ok_or_else(error)
+    }
+
+    /// Pane of the window by index or special token (`!`, `+`, `-`)
+    pub fn find_pane(&self, session: usize, window: usize, target: &str) -> Result<usize, String> {
+        let error = || format!("can't find pane: {}", target);
+        let w = &self.sessions[session].windows[window];
+        let active = w.active_position();
+        match target {
+            LAST => w.last.and_then(|id| w.pane_position(id)).ok_or_else(error),
+            NEXT => Ok((active + 1) % w.panes.len()),
+            PREVIOUS => Ok((active + w.panes.len() - 1) % w.panes.len()),
+            _ => target
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| index.checked_sub(self.pane_base_index(session, window)))
+                .filter(|i| *i < w.panes.len())
+                .ok_or_else(error),
+        }
+    }
+
+    /// Pane by id (`%1`) anywhere
+    pub fn find_pane_id(&self, target: &str) -> Result<Location, String> {
+        let id = target
+            .strip_prefix(PANE_ID_PREFIX)
+            .and_then(|id| id.parse::<usize>().ok());
+        for (i, session) in self.sessions.iter().enumerate() {
+            for (j, window) in session.windows.iter().enumerate() {
+                if let Some(k) = id.and_then(|id| window.pane_position(id)) {
+                    return Ok(Location {
+                        session: i,
+                        window: j,
+                        pane: k,
+                    });
+                }
+            }
+        }
+        Err(format!("can't find pane: {}", target))
+    }
+
+    // window by id anywhere
+    fn find_window_id(&self, target: &str) -> Option<(usize, usize)> {
+        let id = target
+            .strip_prefix(WINDOW_ID_PREFIX)?
+            .parse::<usize>()
+            .ok()?;
+        self.sessions
+            .iter()
+            .enumerate()
+            .find_map(|(i, session)| session.window_position(id).map(|j| (i, j)))
+    }
+
+    /// Target session (`-t`), the current session if not given
+    pub fn target_session(&self, target: Option<&str>) -> Result<usize, String> {
+        let target = match target {
+            Some(target) if !target.is_empty() => target,
+            _ => return self.current_session(),
+        };
+        if target.starts_with(PANE_ID_PREFIX) {
+            return Ok(self.find_pane_id(target)?.session);
+        }
+        if let Some((session, _)) = self.find_window_id(target) {
+            return Ok(session);
+        }
+        match target.split_once(SESSION_SEPARATOR) {
+            Some(("", _)) => self.current_session(),
+            Some((session, _)) => self.find_session(session),
+            None => self.find_session(target),
+        }
+    }
+
+    /// Target window (`-t`, `session:window`), the active window if not given
+    pub fn target_window(&self, target: Option<&str>) -> Result<Location, String> {
+        let target = match target {
+            Some(target) if !target.is_empty() => target,
+            _ => return Ok(self.active_location(self.current_session()?)),
+        };
+        if target.starts_with(PANE_ID_PREFIX) {
+            let location = self.find_pane_id(target)?;
+            return Ok(self.window_location(location.session, location.window));
+        }
+        if let Some((session, window)) = self.find_window_id(target) {
+            return Ok(self.window_location(session, window));
+        }
+        let (session, window) = match target.split_once(SESSION_SEPARATOR) {
+            Some((session, window)) => {
+                let session = match session.is_empty() {
+                    true => self.current_session()?,
+                    false => self.find_session(session)?,
+                };
+                (session, window)
+            }
+            // window of the current session, otherwise session
+            None => {
+                let current = self.current_session()?;
+                match self.find_window(current, target) {
+                    Ok(window) => return Ok(self.window_location(current, window)),
+                    Err(err) => match self.find_session(target) {
+                        Ok(session) => return Ok(self.active_location(session)),
+                        Err(_) => return Err(err),
+                    },
+                }
+            }
+        };
+        let window = match window.split_once(PANE_SEPARATOR) {
+            Some((window, _)) => window,
+            None => window,
+        };
+        let window = match window.is_empty() {
+            true => self.sessions[session].active_position(),
+            false => self.find_window(session, window)?,
+        };
+        Ok(self.window_location(session, window))
+    }
+
+    /// Target pane (`-t`, `session:window.pane`), the active pane if not given
+    pub fn target_pane(&self, target: Option<&str>) -> Result<Location, String> {
+        let target = match target {
+            Some(target) if !target.is_empty() => target,
+            _ => return Ok(self.active_location(self.current_session()?)),
+        };
+        if target.starts_with(PANE_ID_PREFIX) {
+            return self.find_pane_id(target);
+        }
+        let (window, pane) = match target.rfind(PANE_SEPARATOR) {
+            Some(i) if i > target.find(SESSION_SEPARATOR).unwrap_or_default() => {
+                (&target[..i], &target[i + PANE_SEPARATOR.len_utf8()..])
+            }
+            _ => (target, ""),
+        };
+        let mut location = match window.is_empty() {
+            true => self.active_location(self.current_session()?),
+            false => self.target_window(Some(window))?,
+        };
+        if !pane.is_empty() {
+            location.pane = self.find_pane(location.session, location.window, pane)?;
+        }
+        Ok(location)
+    }
+
+    // formats (ref: format.c)
+
+    /// Expand the format (`#{variable}`, `#{?condition,true,false}`, `#{==:a,b}`, `#S`)
+    pub fn expand(&self, format: &str, context: &FormatContext) -> String {
+        let mut expanded = String::with_capacity(format.len());
+        let mut rest = format;
+        while let Some(i) = rest.find('#') {
+            expanded.push_str(&rest[..i]);
+            let after = &rest[i + 1..];
+            let c = match after.chars().next() {
+                Some(c) => c,
+                None => {
+                    expanded.push('#');
+                    return expanded;
+                }
+            };
+            match c {
+                '{' => match closing_brace(after) {
+                    Some(end) => {
+                        expanded.push_str(&self.expand_braces(&after[1..end], context));
+                        rest = &after[end + 1..];
+                        continue;
+                    }
+                    None => {
+                        expanded.push_str(&rest[i..]);
+                        return expanded;
+                    }
+                },
+                '#' | ',' | '}' => expanded.push(c),
+                _ => match ALIASES.iter().find(|(alias, _)| *alias == c) {
+                    Some((_, name)) => expanded.push_str(&self.value(name, context)),
+                    None => {
+                        expanded.push('#');
+                        expanded.push(c);
+                    }
+                },
+            }
+            rest = &after[c.len_utf8()..];
+        }
+        expanded.push_str(rest);
+        expanded
+    }
+
+    // content of `#{...}`
+    fn expand_braces(&self, s: &str, context: &FormatContext) -> String {
+        if let Some(conditions) = s.strip_prefix('?') {
+            // `condition,true[,condition,true...][,false]`
+            let parts = split_top(conditions);
+            let mut i = 0;
+            while i + 1 < parts.len() {
+                if is_true(&self.condition(parts[i], context)) {
+                    return self.expand(parts[i + 1], context);
+                }
+                i += 2;
+            }
+            return parts
+                .get(i)
+                .map(|part| self.expand(part, context))
+                .unwrap_or_default();
+        }
+        for comparison in COMPARISONS {
+            if let Some(operands) = s.strip_prefix(comparison) {
+                let parts = split_top(operands);
+                let a = self.expand(parts.first().copied().unwrap_or_default(), context);
+                let b = self.expand(parts.get(1).copied().unwrap_or_default(), context);
+                let result = match *comparison {
+                    "==:" => a == b,
+                    "!=:" => a != b,
+                    "||:" => is_true(&a) || is_true(&b),
+                    _ => is_true(&a) && is_true(&b),
+                };
+                return bool_value(result).to_string();
+            }
+        }
+        self.value(s, context)
+    }
+
+    // condition is a format or a variable name
+    fn condition(&self, s: &str, context: &FormatContext) -> String {
+        match s.contains('#') {
+            true => self.expand(s, context),
+            false => self.value(s, context),
+        }
+    }
+
+    // variable, option if not known, empty if neither
+    fn value(&self, name: &str, context: &FormatContext) -> String {
+        self.variable(name, context)
+            .or_else(|| {
+                self.option_value(name, context)
+                    .map(|value| value.to_string())
+            })
+            .unwrap_or_default()
+    }
+
+    fn option_value(&self, name: &str, context: &FormatContext) -> Option<&str> {
+        let mut options = Vec::new();
+        if let (Some(session), Some(window)) = (context.session, context.window) {
+            let w = &self.sessions[session].windows[window];
+            if let Some(pane) = context.pane {
+                options.push(&w.panes[pane].options);
+            }
+            options.push(&w.options);
+            options.push(&self.window_options);
+        }
+        if let Some(session) = context.session {
+            options.push(&self.sessions[session].options);
+        }
+        options.extend([&self.session_options, &self.server_options]);
+        string_option(&options, name)
+    }
+
+    fn variable(&self, name: &str, context: &FormatContext) -> Option<String> {
+        if let Some(buffer) = context.buffer.and_then(|i| self.buffers.get(i)) {
+            if let Some(value) = buffer_variable(buffer, name) {
+                return Some(value);
+            }
+        }
+        if let (Some(session), Some(window), Some(pane)) =
+            (context.session, context.window, context.pane)
+        {
+            let location = Location {
+                session,
+                window,
+                pane,
+            };
+            if let Some(value) = self.pane_variable(location, name) {
+                return Some(value);
+            }
+        }
+        if let (Some(session), Some(window)) = (context.session, context.window) {
+            if let Some(value) = self.window_variable(session, window, name) {
+                return Some(value);
+            }
+        }
+        if let Some(session) = context.session {
+            if let Some(value) = self.session_variable(session, name) {
+                return Some(value);
+            }
+        }
+        match name {
+            "pid" => Some(std::process::id().to_string()),
+            "server_sessions" => Some(self.sessions.len().to_string()),
+            _ => None,
+        }
+    }
+
+    fn session_variable(&self, session: usize, name: &str) -> Option<String> {
+        let s = &self.sessions[session];
+        let value = match name {
+            "session_id" => format!("{}{}", SESSION_ID_PREFIX, s.id),
+            "session_name" => s.name.clone(),
+            "session_windows" => s.windows.len().to_string(),
+            "session_created" | "session_activity" => s.created.to_string(),
+            "session_width" => s.width.to_string(),
+            "session_height" => s.height.to_string(),
+            "session_stack" => s
+                .stack
+                .iter()
+                .filter_map(|id| s.window_position(*id))
+                .map(|i| s.windows[i].index.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            "session_attached"
+            | "session_many_attached"
+            | "session_grouped"
+            | "session_alerts"
+            | "session_marked" => FALSE.to_string(),
+            "session_format" => TRUE.to_string(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn window_variable(&self, session: usize, window: usize, name: &str) -> Option<String> {
+        let s = &self.sessions[session];
+        let w = &s.windows[window];
+        let active = s.stack.first() == Some(&w.id);
+        let last = s.stack.get(1) == Some(&w.id);
+        let value = match name {
+            "window_id" => format!("{}{}", WINDOW_ID_PREFIX, w.id),
+            "window_index" => w.index.to_string(),
+            "window_name" => w.name.clone(),
+            "window_active" => bool_value(active).to_string(),
+            "window_last_flag" => bool_value(last).to_string(),
+            "window_start_flag" => bool_value(window == 0).to_string(),
+            "window_end_flag" => bool_value(window + 1 == s.windows.len()).to_string(),
+            "window_panes" => w.panes.len().to_string(),
+            "window_width" => w.layout.x.to_string(),
+            "window_height" => w.layout.y.to_string(),
+            "window_layout" | "window_visible_layout" => Layout::from(w.layout.clone()).to_string(),
+            "window_flags" | "window_raw_flags" => match (active, last) {
+                (true, _) => "*",
+                (_, true) => "-",
+                _ => "",
+            }
+            .to_string(),
+            "window_zoomed_flag"
+            | "window_activity_flag"
+            | "window_bell_flag"
+            | "window_silence_flag"
+            | "window_marked_flag"
+            | "window_linked" => FALSE.to_string(),
+            "window_format" => TRUE.to_string(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn pane_variable(&self, location: Location, name: &str) -> Option<String> {
+        let w = self.window(location);
+        let p = self.pane(location);
+        let cell = w.layout.find_pane(p.id)?;
+        let right = cell.x_off + cell.x;
+        let bottom = cell.y_off + cell.y;
+        let value = match name {
+            "pane_id" => format!("{}{}", PANE_ID_PREFIX, p.id),
+            "pane_index" => (location.pane
+                + self.pane_base_index(location.session, location.window))
+            .to_string(),
+            "pane_active" => bool_value(w.active == p.id).to_string(),
+            "pane_last" => bool_value(w.last == Some(p.id)).to_string(),
+            "pane_width" => cell.x.to_string(),
+            "pane_height" => cell.y.to_string(),
+            "pane_left" => cell.x_off.to_string(),
+            "pane_top" => cell.y_off.to_string(),
+            "pane_right" => (right - 1).to_string(),
+            "pane_bottom" => (bottom - 1).to_string(),
+            "pane_at_left" => bool_value(cell.x_off == 0).to_string(),
+            "pane_at_top" => bool_value(cell.y_off == 0).to_string(),
+            "pane_at_right" => bool_value(right == w.layout.x).to_string(),
+            "pane_at_bottom" => bool_value(bottom == w.layout.y).to_string(),
+            "pane_title" => p.title.clone(),
+            "pane_current_command" => p.current_command.clone(),
+            "pane_current_path" | "pane_start_path" => p.current_path.clone(),
+            "pane_start_command" => p.start_command.clone(),
+            "pane_dead" | "pane_in_mode" | "pane_synchronized" | "pane_marked"
+            | "pane_input_off" | "pane_pipe" | "cursor_x" | "cursor_y" | "history_size" => {
+                FALSE.to_string()
+            }
+            "pane_format" => TRUE.to_string(),
+            _ => return None,
+        };
+        Some(value)
+    }
+}
+
+fn buffer_variable(buffer: &FakeBuffer, name: &str) -> Option<String> {
+    let value = match name {
+        "buffer_name" => buffer.name.clone(),
+        "buffer_size" => buffer.data.len().to_string(),
+        "buffer_created" => buffer.created.to_string(),
+        "buffer_sample" => sample(&buffer.data),
+        _ => return None,
+    };
+    Some(value)
+}
+
+// first characters of the buffer, non-printable ones escaped (ref: paste.c ->
+// paste_make_sample())
+fn sample(data: &[u8]) -> String {
+    let data = String::from_utf8_lossy(data);
+    let mut sample = String::new();
+    for (i, c) in data.chars().enumerate() {
+        if i == SAMPLE_WIDTH {
+            sample.push_str(SAMPLE_ELLIPSIS);
+            break;
+        }
+        match c {
+            '\n' => sample.push_str("\\n"),
+            '\r' => sample.push_str("\\r"),
+            '\t' => sample.push_str("\\t"),
+            '\\' => sample.push_str("\\\\"),
+            c if c.is_control() => sample.push_str(&format!("\\{:03o}", c as u32)),
+            c => sample.push(c),
+        }
+    }
+    sample
+}
+
+// the first string value of the option found in the options
+pub(crate) fn string_option<'a>(options: &[&'a FakeOptions], name: &str) -> Option<&'a str> {
+    options.iter().find_map(|options| match options.get(name) {
+        Some(FakeValue::String(value)) => Some(value.as_str()),
+        _ => None,
+    })
+}
+
+fn number(value: Option<&str>) -> usize {
+    value
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+// the only name starting with the prefix
+fn unique<'a, I: Iterator<Item = &'a str>>(names: I, prefix: &str) -> Option<usize> {
+    let mut found = names
+        .enumerate()
+        .filter(|(_, name)| name.starts_with(prefix))
+        .map(|(i, _)| i);
+    match (found.next(), found.next()) {
+        (Some(i), None) => Some(i),
+        _ => None,
+    }
+}
+
+// index of the `}` closing the `{` the string starts with
+fn closing_brace(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// split at `,` not nested in `#{...}`
+fn split_top(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+pub(crate) fn is_true(value: &str) -> bool {
+    !value.is_empty() && value != FALSE
+}
+
+fn bool_value(value: bool) -> &'static str {
+    match value {
+        true => TRUE,
+        false => FALSE,
+    }
+}
+
+/// Seconds since the epoch
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,66 @@
+//! Invokers for unit tests, commands are recorded and answered by the closure instead of being run
+use crate::{Error, TmuxCommand, TmuxOutput};
+use std::cell::RefCell;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{ExitStatus, Output};
+
+/// Output of the command exited with the `code`, nothing printed to the standard error
+pub(crate) fn mock_output<S: Into<Vec<u8>>>(code: i32, stdout: S) -> Result<TmuxOutput, Error> {
+    Ok(TmuxOutput(Output {
+        status: ExitStatus::from_raw(code << 8),
+        stdout: stdout.into(),
+        stderr: Vec::new(),
+    }))
+}
+
+/// Commands (as displayed, arguments not quoted) invoked by the [`invoker()`](MockInvoker::invoker)
+/// and [`stdin_invoker()`](MockInvoker::stdin_invoker), in the invocation order
+#[derive(Default, Debug)]
+pub(crate) struct MockInvoker {
+    pub cmds: RefCell<Vec<String>>,
+}
+
+impl MockInvoker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Invoker recording the command, the output is returned by `reply` called with the command
+    pub fn invoker<'m, F>(
+        &'m self,
+        reply: F,
+    ) -> impl Fn(TmuxCommand) -> Result<TmuxOutput, Error> + 'm
+    where
+        F: Fn(&str) -> Result<TmuxOutput, Error> + 'm,
+    {
+        move |cmd| {
+            let cmd = cmd.to_string();
+            self.cmds.borrow_mut().push(cmd.clone());
+            reply(&cmd)
+        }
+    }
+
+    /// Invoker writing to the standard input, `reply` is called with the command and the input
+    pub fn stdin_invoker<'m, F>(
+        &'m self,
+        reply: F,
+    ) -> impl Fn(TmuxCommand, &[u8]) -> Result<TmuxOutput, Error> + 'm
+    where
+        F: Fn(&str, &[u8]) -> Result<TmuxOutput, Error> + 'm,
+    {
+        move |cmd, stdin| {
+            let cmd = cmd.to_string();
+            self.cmds.borrow_mut().push(cmd.clone());
+            reply(&cmd, stdin)
+        }
+    }
+
+    /// Record the command invoked by other means (spawned waiting client)
+    pub fn push(&self, cmd: TmuxCommand) {
+        self.cmds.borrow_mut().push(cmd.to_string());
+    }
+
+    pub fn clear(&self) {
+        self.cmds.borrow_mut().clear();
+    }
+}
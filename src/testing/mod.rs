@@ -0,0 +1,22 @@
+//! Fixtures for tests running commands on a real tmux server or an in-process fake one
+pub mod fake_server;
+#[cfg(test)]
+pub(crate) mod mock_invoker;
+pub mod test_server;
+
+mod fake_args;
+mod fake_commands;
+mod fake_options;
+mod fake_state;
+
+pub use fake_server::FakeServer;
+#[cfg(test)]
+pub(crate) use mock_invoker::{mock_output, MockInvoker};
+pub use test_server::TestServer;
+
+#[cfg(test)]
+#[path = "."]
+mod testing_tests {
+    #[cfg(feature = "tmux_3_0")]
+    pub mod fake_server_tests;
+}
@@ -0,0 +1,188 @@
+#[cfg(feature = "tmux_2_9")]
+use crate::SetOption;
+use crate::{
+    Error, HasSession, KillServer, ListSessions, NewSession, Tmux, TmuxCommand, TmuxCommands,
+    TmuxOutput,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// default width of the sessions
+pub const WIDTH: usize = 80;
+/// default height of the sessions
+pub const HEIGHT: usize = 24;
+/// server start timeout
+pub const START_TIMEOUT: Duration = Duration::from_secs(5);
+/// condition polling interval while waiting with timeout
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+const SOCKET_PREFIX: &str = "tmux_interface_test";
+// user and system configuration files are not loaded
+const CONFIG_FILE: &str = "/dev/null";
+const SOCKET_PATH: &str = "#{socket_path}";
+#[cfg(feature = "tmux_2_9")]
+const DEFAULT_SIZE: &str = "default-size";
+
+// servers started by this process
+static SERVER_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Isolated tmux server for tests, started on the unique socket (`-L`) without configuration
+/// (`-f /dev/null`)
+///
+/// The server is started with a detached session of the fixed size (`new-session -x -y`), used as
+/// the default size of the new sessions and windows too (`default-size`, tmux ^2.9). Tests using
+/// their own servers can run in parallel, commands are invoked using
+/// [`invoker()`](TestServer::invoker) or [`tmux()`](TestServer::tmux).
+///
+/// The server is killed (`kill-server`) and its socket file removed when dropped (tests failed
+/// by panic included).
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{NewSession, SessionsCtl, TestServer};
+///
+/// let server = TestServer::new().unwrap();
+/// let invoker = server.invoker();
+/// invoker(NewSession::new().detached().session_name("test").build()).unwrap();
+///
+/// let sessions = SessionsCtl::with_invoker(&invoker).get_all().unwrap();
+/// assert_eq!(sessions.into_iter().count(), 2);
+/// ```
+#[derive(Debug)]
+pub struct TestServer {
+    socket_name: String,
+    width: usize,
+    height: usize,
+    // removed when dropped, `None` if not known
+    socket_path: Option<PathBuf>,
+}
+
+impl TestServer {
+    /// Start the server with the default size ([`WIDTH`] x [`HEIGHT`])
+    pub fn new() -> Result<Self, Error> {
+        Self::with_size(WIDTH, HEIGHT)
+    }
+
+    /// Start the server with the size of the sessions
+    pub fn with_size(width: usize, height: usize) -> Result<Self, Error> {
+        let socket_name = format!(
+            "{}_{}_{}",
+            SOCKET_PREFIX,
+            process::id(),
+            SERVER_COUNT.fetch_add(1, Ordering::Relaxed)
+        );
+        // killed when dropped if not started completely
+        let mut server = Self {
+            socket_name,
+            width,
+            height,
+            socket_path: None,
+        };
+
+        let cmds = TmuxCommands::new().cmd(
+            NewSession::new()
+                .detached()
+                .width(width)
+                .height(height)
+                .build(),
+        );
+        #[cfg(feature = "tmux_2_9")]
+        let cmds = cmds.cmd(
+            SetOption::new()
+                .global()
+                .option(DEFAULT_SIZE)
+                .value(format!("{}x{}", width, height))
+                .build(),
+        );
+        server.output(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        server.wait_ready(START_TIMEOUT)?;
+
+        let output = server
+            .output(ListSessions::new().format(SOCKET_PATH))?
+            .into_result()?;
+        server.socket_path = output
+            .to_string()
+            .lines()
+            .next()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from);
+        Ok(server)
+    }
+
+    /// Socket name of the server (`-L`)
+    pub fn socket_name(&self) -> &str {
+        &self.socket_name
+    }
+
+    /// Socket file of the server (`#{socket_path}`)
+    pub fn socket_path(&self) -> Option<&Path> {
+        self.socket_path.as_deref()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Tmux binary connected to the server (e.g. for control mode or stdin input)
+    pub fn tmux(&self) -> Tmux<'_> {
+        Tmux::new()
+            .file(CONFIG_FILE)
+            .socket_name(self.socket_name.as_str())
+    }
+
+    /// Invoker of the commands on the server, used by ctls (e.g.
+    /// [`SessionsCtl::with_invoker()`](crate::SessionsCtl::with_invoker))
+    pub fn invoker(&self) -> impl Fn(TmuxCommand) -> Result<TmuxOutput, Error> + '_ {
+        move |cmd| self.tmux().command(cmd).output()
+    }
+
+    /// Invoke the command on the server
+    pub fn output<'a, T: Into<TmuxCommand<'a>>>(&self, cmd: T) -> Result<TmuxOutput, Error> {
+        let cmd: TmuxCommand<'a> = cmd.into();
+        self.tmux().command(cmd).output()
+    }
+
+    /// Wait until the server accepts the commands (`has-session` succeeds), [`Error::Timeout`]
+    /// if not ready in time
+    pub fn wait_ready(&self, timeout: Duration) -> Result<(), Error> {
+        self.wait_until(timeout, |server| {
+            Ok(server.output(HasSession::new())?.success())
+        })
+    }
+
+    /// Wait until the condition is met (polled), [`Error::Timeout`] if not met in time
+    pub fn wait_until<F>(&self, timeout: Duration, mut condition: F) -> Result<(), Error>
+    where
+        F: FnMut(&Self) -> Result<bool, Error>,
+    {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if condition(self)? {
+                return Ok(());
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::Timeout);
+            }
+            thread::sleep(POLL_INTERVAL.min(deadline - now));
+        }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        // server may be killed already, the socket file is left by tmux
+        let _ = self.output(KillServer::new());
+        if let Some(socket_path) = &self.socket_path {
+            let _ = fs::remove_file(socket_path);
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use crate::Error;
 use crate::FormatsOutput;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 // XXX: 1.9 processed
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, PartialEq, Clone, Debug)]
 pub struct Buffer {
     /// buffer_created - Time buffer created
@@ -32,9 +35,10 @@ impl FromStr for Buffer {
         #[cfg(feature = "tmux_2_3")]
         format.buffer_name(&mut buffer.name);
         #[cfg(feature = "tmux_1_7")]
-        format.buffer_sample(&mut buffer.sample);
-        #[cfg(feature = "tmux_1_7")]
         format.buffer_size(&mut buffer.size);
+        // last, sample may contain separator
+        #[cfg(feature = "tmux_1_7")]
+        format.buffer_sample(&mut buffer.sample);
 
         FormatsOutput::from_string_ext(s, &mut format);
         Ok(buffer)
@@ -32,12 +32,12 @@ fn buffer_parse() {
         // buffer_name - Name of buffer
         #[cfg(feature = "tmux_2_3")]
         "foo",
-        // buffer_sample - First 50 characters from the specified buffer
-        #[cfg(feature = "tmux_1_7")]
-        "bar",
         // buffer_size - Size of the specified buffer in bytes
         #[cfg(feature = "tmux_1_7")]
-        "3",
+        "7",
+        // buffer_sample - First 50 characters from the specified buffer
+        #[cfg(feature = "tmux_1_7")]
+        "bar:baz",
     ];
     let buffer_str = buffer_vec.join(":");
     let buffer = Buffer::from_str(&buffer_str).unwrap();
@@ -48,9 +48,9 @@ fn buffer_parse() {
         #[cfg(feature = "tmux_2_3")]
         name: Some("foo".to_string()),
         #[cfg(feature = "tmux_1_7")]
-        sample: Some("bar".to_string()),
+        sample: Some("bar:baz".to_string()),
         #[cfg(feature = "tmux_1_7")]
-        size: Some(3),
+        size: Some(7),
     };
 
     assert_eq!(buffer_orig, buffer);
@@ -1,6 +1,20 @@
+#[cfg(feature = "tmux_2_3")]
+use crate::Buffer;
 use crate::{Buffers, Error, Formats, ListBuffers, Tmux, TmuxCommand, TmuxOutput};
+#[cfg(feature = "tmux_2_0")]
+use crate::{DeleteBuffer, LoadBuffer, SaveBuffer, SetBuffer};
+#[cfg(feature = "tmux_2_0")]
+use std::borrow::Cow;
 use std::str::FromStr;
 
+/// path for `load-buffer` and `save-buffer` to use stdin and stdout
+#[cfg(feature = "tmux_2_0")]
+const STDIO_PATH: &str = "-";
+
+/// Invoker writing the given data to stdin of the tmux process, e.g.
+/// `|cmd, input| Tmux::new().socket_name("s").command(cmd).output_with_stdin(input)`
+pub type StdinInvoker<'a> = &'a dyn Fn(TmuxCommand<'a>, &[u8]) -> Result<TmuxOutput, Error>;
+
 // trait top level options, then server buffer window pane
 pub struct BuffersCtl<'a> {
     // TODO: comment/doc
@@ -9,12 +23,15 @@ pub struct BuffersCtl<'a> {
     // let tmux = Tmux::new();
     // ```
     pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+    /// invoker for commands reading the given data from stdin (`load-buffer -`)
+    pub stdin_invoker: StdinInvoker<'a>,
 }
 
 impl<'a> Default for BuffersCtl<'a> {
     fn default() -> Self {
         Self {
             invoker: &|cmd| Tmux::with_command(cmd).output(),
+            stdin_invoker: &|cmd, input| Tmux::with_command(cmd).output_with_stdin(input),
         }
     }
 }
@@ -24,8 +41,23 @@ impl<'a> BuffersCtl<'a> {
         Self::default()
     }
 
+    /// Default stdin invoker is used (default tmux server), see [`BuffersCtl::with_invokers`]
     pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
-        Self { invoker }
+        Self {
+            invoker,
+            ..Default::default()
+        }
+    }
+
+    /// Invoker and invoker writing the data to stdin of the tmux process ([`StdinInvoker`])
+    pub fn with_invokers(
+        invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+        stdin_invoker: StdinInvoker<'a>,
+    ) -> Self {
+        Self {
+            invoker,
+            stdin_invoker,
+        }
     }
 
     pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
@@ -47,9 +79,10 @@ impl<'a> BuffersCtl<'a> {
         #[cfg(feature = "tmux_2_3")]
         format.buffer_name();
         #[cfg(feature = "tmux_1_7")]
-        format.buffer_sample();
-        #[cfg(feature = "tmux_1_7")]
         format.buffer_size();
+        // last, sample may contain separator
+        #[cfg(feature = "tmux_1_7")]
+        format.buffer_sample();
 
         let ls_format = format.to_string();
 
@@ -57,4 +90,71 @@ impl<'a> BuffersCtl<'a> {
         let output = (invoker)(cmd)?.to_string();
         Buffers::from_str(&output)
     }
+
+    /// Buffer metadata by name, `None` if the buffer does not exist
+    #[cfg(feature = "tmux_2_3")]
+    pub fn get(&self, name: &str) -> Result<Option<Buffer>, Error> {
+        let buffers = self.get_all()?;
+        let buffer = buffers
+            .into_iter()
+            .find(|buffer| buffer.name.as_deref() == Some(name));
+        Ok(buffer)
+    }
+
+    /// Exact buffer content (`save-buffer -`), the most recent buffer if no name given
+    #[cfg(feature = "tmux_2_0")]
+    pub fn read<S: Into<Cow<'a, str>>>(&self, name: Option<S>) -> Result<Vec<u8>, Error> {
+        let cmd = SaveBuffer::new().path(STDIO_PATH);
+        let cmd = match name {
+            Some(name) => cmd.buffer_name(name),
+            None => cmd,
+        };
+        let output = (self.invoker)(cmd.build())?.into_result()?;
+        Ok(output.stdout())
+    }
+
+    /// Set buffer content to the bytes as is (`load-buffer -`), NUL and non UTF-8 data included
+    #[cfg(feature = "tmux_2_0")]
+    pub fn write<S: Into<Cow<'a, str>>>(&self, name: S, data: &[u8]) -> Result<(), Error> {
+        let cmd = LoadBuffer::new().buffer_name(name).path(STDIO_PATH);
+        (self.stdin_invoker)(cmd.build(), data)?.into_result()?;
+        Ok(())
+    }
+
+    /// Set buffer content and send it to the clipboard of the client (`load-buffer -w -`)
+    /// using the terminal `Ms` capability, the most recent client if no client given
+    #[cfg(feature = "tmux_3_2")]
+    pub fn write_to_clipboard<S: Into<Cow<'a, str>>, T: Into<Cow<'a, str>>>(
+        &self,
+        name: S,
+        data: &[u8],
+        target_client: Option<T>,
+    ) -> Result<(), Error> {
+        let cmd = LoadBuffer::new()
+            .send_to_clipboard()
+            .buffer_name(name)
+            .path(STDIO_PATH);
+        let cmd = match target_client {
+            Some(target_client) => cmd.target_client(target_client),
+            None => cmd,
+        };
+        (self.stdin_invoker)(cmd.build(), data)?.into_result()?;
+        Ok(())
+    }
+
+    /// Rename buffer (`set-buffer -b name -n new-name`)
+    #[cfg(feature = "tmux_2_0")]
+    pub fn rename<S: Into<Cow<'a, str>>>(&self, name: S, new_name: S) -> Result<(), Error> {
+        let cmd = SetBuffer::new().buffer_name(name).new_buffer_name(new_name);
+        (self.invoker)(cmd.build())?.into_result()?;
+        Ok(())
+    }
+
+    /// Delete buffer (`delete-buffer -b name`)
+    #[cfg(feature = "tmux_2_0")]
+    pub fn delete<S: Into<Cow<'a, str>>>(&self, name: S) -> Result<(), Error> {
+        let cmd = DeleteBuffer::new().buffer_name(name);
+        (self.invoker)(cmd.build())?.into_result()?;
+        Ok(())
+    }
 }
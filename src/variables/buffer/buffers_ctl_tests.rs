@@ -5,3 +5,73 @@ fn clients_ctl_test() {
     let clients = ClientsCtl::default().get_all().unwrap();
     dbg!(clients);
 }
+
+#[cfg(all(unix, feature = "tmux_2_0"))]
+#[test]
+fn buffers_ctl_data() {
+    use crate::{mock_output, BuffersCtl, MockInvoker};
+    use std::cell::RefCell;
+
+    let data = b"a\0b\xff\n".to_vec();
+    let mock = MockInvoker::new();
+    let input = RefCell::new(Vec::new());
+    let invoker = mock.invoker(|_| mock_output(0, data.clone()));
+    let stdin_invoker = mock.stdin_invoker(|_, stdin| {
+        input.borrow_mut().extend_from_slice(stdin);
+        mock_output(0, "")
+    });
+    let buffers_ctl = BuffersCtl::with_invokers(&invoker, &stdin_invoker);
+
+    buffers_ctl.write("my_buffer", &data).unwrap();
+    assert_eq!(buffers_ctl.read(Some("my_buffer")).unwrap(), data);
+    buffers_ctl.rename("my_buffer", "renamed").unwrap();
+    buffers_ctl.delete("renamed").unwrap();
+
+    assert_eq!(*input.borrow(), data);
+    #[cfg(not(feature = "cmd_alias"))]
+    let expected = [
+        "load-buffer -b my_buffer -",
+        "save-buffer -b my_buffer -",
+        "set-buffer -b my_buffer -n renamed",
+        "delete-buffer -b renamed",
+    ];
+    #[cfg(feature = "cmd_alias")]
+    let expected = [
+        "loadb -b my_buffer -",
+        "saveb -b my_buffer -",
+        "setb -b my_buffer -n renamed",
+        "deleteb -b renamed",
+    ];
+    assert_eq!(*mock.cmds.borrow(), expected);
+}
+
+#[cfg(all(unix, feature = "tmux_3_2"))]
+#[test]
+fn buffers_ctl_write_to_clipboard() {
+    use crate::{mock_output, BuffersCtl, MockInvoker};
+
+    let mock = MockInvoker::new();
+    let invoker = mock.invoker(|_| unreachable!());
+    let stdin_invoker = mock.stdin_invoker(|_, _| mock_output(0, ""));
+    let buffers_ctl = BuffersCtl::with_invokers(&invoker, &stdin_invoker);
+
+    let name = String::from("clip");
+    buffers_ctl
+        .write_to_clipboard(name, b"data", Some("/dev/pts/1"))
+        .unwrap();
+    buffers_ctl
+        .write_to_clipboard("clip", b"data", None::<&str>)
+        .unwrap();
+
+    #[cfg(not(feature = "cmd_alias"))]
+    let cmd = "load-buffer";
+    #[cfg(feature = "cmd_alias")]
+    let cmd = "loadb";
+    assert_eq!(
+        *mock.cmds.borrow(),
+        [
+            format!("{} -w -b clip -t /dev/pts/1 -", cmd),
+            format!("{} -w -b clip -", cmd)
+        ]
+    );
+}
@@ -9,9 +9,9 @@ fn buffers_parse() {
         #[cfg(feature = "tmux_2_3")]
         "foo1",
         #[cfg(feature = "tmux_1_7")]
-        "bar",
-        #[cfg(feature = "tmux_1_7")]
         "3",
+        #[cfg(feature = "tmux_1_7")]
+        "bar",
     ];
     let buffer1_vec = vec![
         #[cfg(feature = "tmux_2_6")]
@@ -19,9 +19,9 @@ fn buffers_parse() {
         #[cfg(feature = "tmux_2_3")]
         "foo2",
         #[cfg(feature = "tmux_1_7")]
-        "bar",
-        #[cfg(feature = "tmux_1_7")]
         "3",
+        #[cfg(feature = "tmux_1_7")]
+        "bar",
     ];
 
     let buffer0_str = buffer0_vec.join(":");
@@ -7,7 +7,7 @@ pub use buffer::Buffer;
 #[cfg(feature = "tmux_1_7")]
 pub use buffers::Buffers;
 #[cfg(feature = "tmux_1_7")]
-pub use buffers_ctl::BuffersCtl;
+pub use buffers_ctl::{BuffersCtl, StdinInvoker};
 
 #[cfg(test)]
 #[path = "."]
@@ -1,8 +1,11 @@
 use crate::Error;
 use crate::FormatsOutput;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 // XXX: 1.9 processed
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, PartialEq, Clone, Debug)]
 pub struct Client {
     /// client_activity - Integer time client last had activity
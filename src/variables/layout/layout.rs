@@ -1,5 +1,6 @@
 use crate::Error;
-use crate::LayoutCell;
+use crate::{LayoutCell, LayoutChecksum};
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Default, PartialEq, Clone, Debug)]
@@ -15,15 +16,35 @@ impl FromStr for Layout {
 
     fn from_str(s: &str) -> Result<Layout, Error> {
         let mut layout = Layout::new();
-        let ls: Vec<&str> = s.split(',').collect();
-        layout.checksum = usize::from_str_radix(ls[0], 16)?;
-        layout.cell = ls[1].parse()?;
+        let (checksum, cell) = s.split_once(',').ok_or(Error::ParseLayout)?;
+        layout.checksum = usize::from_str_radix(checksum, 16)?;
+        layout.cell = cell.parse()?;
         Ok(layout)
     }
 }
 
+// NOTE: checksum is calculated from the cell, not taken from the `checksum` field
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cell = self.cell.to_string();
+        write!(f, "{:04x},{}", LayoutChecksum::calc(&cell), cell)
+    }
+}
+
+impl From<LayoutCell> for Layout {
+    fn from(cell: LayoutCell) -> Self {
+        Layout {
+            checksum: LayoutChecksum::calc(&cell.to_string()) as usize,
+            cell,
+        }
+    }
+}
+
 impl Layout {
     pub fn new() -> Self {
         Default::default()
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(Layout);
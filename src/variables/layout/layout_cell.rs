@@ -1,4 +1,5 @@
 use crate::Error;
+use std::fmt;
 use std::str::Chars;
 use std::str::FromStr;
 
@@ -18,11 +19,37 @@ impl FromStr for LayoutCell {
         let state = LayoutFSMState::X;
 
         let mut layout: LayoutCell = Default::default();
-        layout.fsm(&mut chars, state)?;
-        Ok(layout)
+        // trailing `,`, `]` or `}` are not allowed
+        match layout.fsm(&mut chars, state)? {
+            LayoutFSMState::EOL => Ok(layout),
+            _ => Err(Error::ParseLayout),
+        }
+    }
+}
+
+// NOTE: tmux source: layout_custom.c -> layout_append()
+impl fmt::Display for LayoutCell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{},{},{}", self.x, self.y, self.x_off, self.y_off)?;
+        match (&self.style, &self.cells) {
+            (LayoutType::LeftRight, Some(cells)) => write!(f, "{{{}}}", join(cells)),
+            (LayoutType::TopBottom, Some(cells)) => write!(f, "[{}]", join(cells)),
+            _ => match self.id {
+                Some(id) => write!(f, ",{}", id),
+                None => Ok(()),
+            },
+        }
     }
 }
 
+fn join(cells: &[LayoutCell]) -> String {
+    cells
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
 // NOTE: tmux source: layout_custom.c
 // XXX: checksum can be improved using hex crate
 // XXX: implement trait parse FromStr?
@@ -118,6 +145,10 @@ impl LayoutCell {
                         state = LayoutFSMState::X;
                         break;
                     }
+                    // end of {} or [] group, nested in another group
+                    (']', LayoutFSMState::EndNested) | ('}', LayoutFSMState::EndNested) => {
+                        break;
+                    }
                     // end of id element inside [] group
                     (']', LayoutFSMState::Id) => {
                         self.id = buff.parse().ok();
@@ -139,13 +170,15 @@ impl LayoutCell {
                         self.cells = Some(Vec::new());
                         loop {
                             child = Default::default();
-                            // TODO: remove unwrap
-                            state = child.fsm(chars, LayoutFSMState::X).unwrap();
+                            state = child.fsm(chars, LayoutFSMState::X)?;
                             if let Some(c) = self.cells.as_mut() {
                                 c.push(child)
                             }
-                            if state == LayoutFSMState::EndNested || state == LayoutFSMState::EOL {
-                                break;
+                            match state {
+                                LayoutFSMState::EndNested => break,
+                                // group is not closed
+                                LayoutFSMState::EOL => return Err(Error::ParseLayout),
+                                _ => {}
                             }
                         }
                     }
@@ -157,13 +190,15 @@ impl LayoutCell {
                         self.cells = Some(Vec::new());
                         loop {
                             child = Default::default();
-                            // TODO: remove unwrap
-                            state = child.fsm(chars, LayoutFSMState::X).unwrap();
+                            state = child.fsm(chars, LayoutFSMState::X)?;
                             if let Some(c) = self.cells.as_mut() {
                                 c.push(child)
                             }
-                            if state == LayoutFSMState::EndNested || state == LayoutFSMState::EOL {
-                                break;
+                            match state {
+                                LayoutFSMState::EndNested => break,
+                                // group is not closed
+                                LayoutFSMState::EOL => return Err(Error::ParseLayout),
+                                _ => {}
                             }
                         }
                     }
@@ -173,8 +208,11 @@ impl LayoutCell {
                 }
             } else {
                 // end of line and id element
-                if state == LayoutFSMState::Id {
-                    self.id = buff.parse().ok();
+                match state {
+                    LayoutFSMState::Id => self.id = buff.parse().ok(),
+                    LayoutFSMState::EndNested | LayoutFSMState::EOL => {}
+                    // truncated cell
+                    _ => return Err(Error::ParseLayout),
                 }
                 state = LayoutFSMState::EOL;
                 break;
@@ -184,3 +222,6 @@ impl LayoutCell {
         Ok(state)
     }
 }
+
+#[cfg(feature = "serde")]
+serde_str!(LayoutCell);
@@ -98,3 +98,19 @@ fn parse() {
     //layout_cell.parse("177x64,1,3{");
     //assert_eq!(layout_cell, layout_orig);
 }
+
+#[test]
+fn to_string() {
+    use crate::LayoutCell;
+
+    let cells = [
+        "176x64,1,3,2",
+        "178x64,1,2[176x32,3,4,2,177x31,5,6,3]",
+        "178x64,1,2{177x32,3,4,2,177x31,5,6,3}",
+        "178x64,1,2[177x32,3,4{88x32,5,6,1,44x32,89,7,4,43x32,134,8,5},177x31,1,33{88x31,0,33,2,88x31,89,33,3}]",
+    ];
+    for s in cells {
+        let layout_cell: LayoutCell = s.parse().unwrap();
+        assert_eq!(layout_cell.to_string(), s);
+    }
+}
@@ -9,7 +9,7 @@ impl LayoutChecksum {
         let chars = s.chars();
         for chr in chars {
             csum = (csum >> 1) + ((csum & 1) << 15);
-            csum += chr as u16;
+            csum = csum.wrapping_add(chr as u16);
         }
         csum
     }
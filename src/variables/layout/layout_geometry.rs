@@ -0,0 +1,453 @@
+use crate::{Error, LayoutCell, LayoutType};
+
+// NOTE: tmux source: layout.c, layout-set.c
+// border between two neighbour cells
+const BORDER: usize = 1;
+
+/// Direction of a neighbour pane (`select-pane -U`, `-D`, `-L`, `-R`)
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum LayoutDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+// split `size` into `n` parts separated by borders, remainder goes to the last part
+fn spread(size: usize, n: usize) -> Result<Vec<usize>, Error> {
+    let available = size
+        .checked_sub(n.saturating_sub(1) * BORDER)
+        .filter(|available| n > 0 && *available >= n)
+        .ok_or(Error::Layout)?;
+    let each = available / n;
+    let mut v = vec![each; n];
+    if let Some(last) = v.last_mut() {
+        *last = available - each * (n - 1);
+    }
+    Ok(v)
+}
+
+// split `size` proportionally to `sizes`, remainder goes to the last part
+fn scale_sizes(sizes: &[usize], size: usize) -> Result<Vec<usize>, Error> {
+    let n = sizes.len();
+    let available = size
+        .checked_sub(n.saturating_sub(1) * BORDER)
+        .filter(|available| n > 0 && *available >= n)
+        .ok_or(Error::Layout)?;
+    let total: u128 = sizes.iter().map(|s| *s as u128).sum::<u128>().max(1);
+    let mut v = Vec::with_capacity(n);
+    let mut used = 0;
+    for (i, old) in sizes.iter().enumerate() {
+        // keep at least one cell for each of the following parts
+        let rest = n - i - 1;
+        let new = if rest == 0 {
+            available - used
+        } else {
+            ((*old as u128 * available as u128 / total) as usize).clamp(1, available - used - rest)
+        };
+        used += new;
+        v.push(new);
+    }
+    Ok(v)
+}
+
+// end position of a cell, saturating for cells parsed from untrusted layout strings
+fn end(off: usize, size: usize) -> usize {
+    off.saturating_add(size)
+}
+
+impl LayoutCell {
+    /// Create a cell containing pane `id`
+    pub fn pane(x: usize, y: usize, x_off: usize, y_off: usize, id: usize) -> Self {
+        LayoutCell::new(x, y, x_off, y_off, Some(id), LayoutType::WindowPane, None)
+    }
+
+    /// `true` if cell contains a pane (has no nested cells)
+    pub fn is_pane(&self) -> bool {
+        self.cells.is_none()
+    }
+
+    /// All pane cells in layout order
+    pub fn panes(&self) -> Vec<&LayoutCell> {
+        match &self.cells {
+            Some(cells) => cells.iter().flat_map(|cell| cell.panes()).collect(),
+            None => vec![self],
+        }
+    }
+
+    /// Find pane cell by pane id
+    pub fn find_pane(&self, id: usize) -> Option<&LayoutCell> {
+        self.panes().into_iter().find(|cell| cell.id == Some(id))
+    }
+
+    /// Pane cell at position (`x`, `y`), `None` for borders and positions outside
+    pub fn pane_at(&self, x: usize, y: usize) -> Option<&LayoutCell> {
+        self.panes().into_iter().find(|cell| {
+            (cell.x_off..end(cell.x_off, cell.x)).contains(&x)
+                && (cell.y_off..end(cell.y_off, cell.y)).contains(&y)
+        })
+    }
+
+    /// Neighbour pane of pane `id` in the given direction
+    pub fn neighbour(&self, id: usize, direction: LayoutDirection) -> Option<&LayoutCell> {
+        let pane = self.find_pane(id)?;
+        let overlaps = |a_off: usize, a: usize, b_off: usize, b: usize| {
+            a_off < end(b_off, b) && b_off < end(a_off, a)
+        };
+        self.panes().into_iter().find(|cell| {
+            cell.id != pane.id
+                && match direction {
+                    LayoutDirection::Up => {
+                        end(cell.y_off, cell.y.saturating_add(BORDER)) == pane.y_off
+                            && overlaps(cell.x_off, cell.x, pane.x_off, pane.x)
+                    }
+                    LayoutDirection::Down => {
+                        end(pane.y_off, pane.y.saturating_add(BORDER)) == cell.y_off
+                            && overlaps(cell.x_off, cell.x, pane.x_off, pane.x)
+                    }
+                    LayoutDirection::Left => {
+                        end(cell.x_off, cell.x.saturating_add(BORDER)) == pane.x_off
+                            && overlaps(cell.y_off, cell.y, pane.y_off, pane.y)
+                    }
+                    LayoutDirection::Right => {
+                        end(pane.x_off, pane.x.saturating_add(BORDER)) == cell.x_off
+                            && overlaps(cell.y_off, cell.y, pane.y_off, pane.y)
+                    }
+                }
+        })
+    }
+
+    /// Compare cell trees ignoring sizes, offsets and pane ids
+    pub fn structural_eq(&self, other: &LayoutCell) -> bool {
+        match (&self.cells, &other.cells) {
+            (None, None) => true,
+            (Some(a), Some(b)) => {
+                self.style == other.style
+                    && a.len() == b.len()
+                    && a.iter().zip(b.iter()).all(|(a, b)| a.structural_eq(b))
+            }
+            _ => false,
+        }
+    }
+
+    /// Split pane `id`, new pane `new_id` is placed right of (`LayoutType::LeftRight`) or
+    /// below (`LayoutType::TopBottom`) it and takes `ratio` (0.0 - 1.0) of the space
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Layout`] if the pane was not found or is too small to be split
+    pub fn split(
+        &mut self,
+        id: usize,
+        style: LayoutType,
+        ratio: f64,
+        new_id: usize,
+    ) -> Result<&mut Self, Error> {
+        if style == LayoutType::WindowPane {
+            return Err(Error::Layout);
+        }
+        if self.is_pane() {
+            if self.id != Some(id) {
+                return Err(Error::Layout);
+            }
+            let cells = self.split_pane(&style, ratio, new_id)?;
+            self.id = None;
+            self.style = style;
+            self.cells = Some(cells);
+            return Ok(self);
+        }
+        if self.split_nested(id, &style, ratio, new_id)? {
+            Ok(self)
+        } else {
+            Err(Error::Layout)
+        }
+    }
+
+    // returns `false` if pane was not found
+    fn split_nested(
+        &mut self,
+        id: usize,
+        style: &LayoutType,
+        ratio: f64,
+        new_id: usize,
+    ) -> Result<bool, Error> {
+        let same_style = &self.style == style;
+        if let Some(cells) = self.cells.as_mut() {
+            for i in 0..cells.len() {
+                if cells[i].is_pane() {
+                    if cells[i].id == Some(id) {
+                        let split = cells[i].split_pane(style, ratio, new_id)?;
+                        if same_style {
+                            cells.splice(i..=i, split);
+                        } else {
+                            let cell = &mut cells[i];
+                            cell.id = None;
+                            cell.style = style.clone();
+                            cell.cells = Some(split);
+                        }
+                        return Ok(true);
+                    }
+                } else if cells[i].split_nested(id, style, ratio, new_id)? {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    // split pane cell into two pane cells
+    fn split_pane(
+        &self,
+        style: &LayoutType,
+        ratio: f64,
+        new_id: usize,
+    ) -> Result<Vec<LayoutCell>, Error> {
+        let size = match style {
+            LayoutType::LeftRight => self.x,
+            _ => self.y,
+        };
+        let available = size
+            .checked_sub(BORDER)
+            .filter(|available| *available >= 2)
+            .ok_or(Error::Layout)?;
+        let second = ((available as f64 * ratio) as usize).clamp(1, available - 1);
+        let first = available - second;
+        let id = self.id.unwrap_or_default();
+        Ok(match style {
+            LayoutType::LeftRight => vec![
+                LayoutCell::pane(first, self.y, self.x_off, self.y_off, id),
+                LayoutCell::pane(
+                    second,
+                    self.y,
+                    end(self.x_off, first + BORDER),
+                    self.y_off,
+                    new_id,
+                ),
+            ],
+            _ => vec![
+                LayoutCell::pane(self.x, first, self.x_off, self.y_off, id),
+                LayoutCell::pane(
+                    self.x,
+                    second,
+                    self.x_off,
+                    end(self.y_off, first + BORDER),
+                    new_id,
+                ),
+            ],
+        })
+    }
+
+    // panes side by side (`LeftRight`) or stacked (`TopBottom`), single pane is not nested
+    fn sequence(
+        style: LayoutType,
+        x: usize,
+        y: usize,
+        x_off: usize,
+        y_off: usize,
+        ids: &[usize],
+    ) -> Result<Self, Error> {
+        if let [id] = ids {
+            return Ok(LayoutCell::pane(x, y, x_off, y_off, *id));
+        }
+        let (mut x_pos, mut y_pos) = (x_off, y_off);
+        let mut cells = Vec::new();
+        match style {
+            LayoutType::LeftRight => {
+                for (size, id) in spread(x, ids.len())?.into_iter().zip(ids) {
+                    cells.push(LayoutCell::pane(size, y, x_pos, y_off, *id));
+                    x_pos += size + BORDER;
+                }
+            }
+            _ => {
+                for (size, id) in spread(y, ids.len())?.into_iter().zip(ids) {
+                    cells.push(LayoutCell::pane(x, size, x_off, y_pos, *id));
+                    y_pos += size + BORDER;
+                }
+            }
+        }
+        Ok(LayoutCell::new(
+            x,
+            y,
+            x_off,
+            y_off,
+            None,
+            style,
+            Some(cells),
+        ))
+    }
+
+    /// `tiled` layout of panes `ids` for a `width` x `height` window
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Layout`] if `ids` is empty or the window is too small
+    pub fn tiled(width: usize, height: usize, ids: &[usize]) -> Result<Self, Error> {
+        let n = ids.len();
+        let (mut rows, mut columns) = (1, 1);
+        while rows * columns < n {
+            rows += 1;
+            if rows * columns < n {
+                columns += 1;
+            }
+        }
+        let heights = spread(height, rows)?;
+        let widths = spread(width, columns)?;
+        let mut cells = Vec::new();
+        let mut y_off = 0;
+        for (row, h) in ids.chunks(columns).zip(heights) {
+            if let [id] = row {
+                cells.push(LayoutCell::pane(width, h, 0, y_off, *id));
+            } else {
+                let mut panes = Vec::new();
+                let mut x_off = 0;
+                for (i, (id, w)) in row.iter().zip(widths.iter()).enumerate() {
+                    // last pane of the row fills the remaining space
+                    let w = if i == row.len() - 1 {
+                        width - x_off
+                    } else {
+                        *w
+                    };
+                    panes.push(LayoutCell::pane(w, h, x_off, y_off, *id));
+                    x_off += w + BORDER;
+                }
+                cells.push(LayoutCell::new(
+                    width,
+                    h,
+                    0,
+                    y_off,
+                    None,
+                    LayoutType::LeftRight,
+                    Some(panes),
+                ));
+            }
+            y_off += h + BORDER;
+        }
+        match cells.len() {
+            0 => Err(Error::Layout),
+            1 => Ok(cells.remove(0)),
+            _ => Ok(LayoutCell::new(
+                width,
+                height,
+                0,
+                0,
+                None,
+                LayoutType::TopBottom,
+                Some(cells),
+            )),
+        }
+    }
+
+    /// `main-vertical` layout, first pane of `ids` is the main pane of `main_width` on the left
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Layout`] if `ids` is empty or the window is too small
+    pub fn main_vertical(
+        width: usize,
+        height: usize,
+        main_width: usize,
+        ids: &[usize],
+    ) -> Result<Self, Error> {
+        match ids {
+            [] => Err(Error::Layout),
+            [id] => Ok(LayoutCell::pane(width, height, 0, 0, *id)),
+            [main, others @ ..] => {
+                let main_width = main_width.min(width.saturating_sub(2 * BORDER)).max(1);
+                let x_off = main_width + BORDER;
+                let others = LayoutCell::sequence(
+                    LayoutType::TopBottom,
+                    width.checked_sub(x_off).ok_or(Error::Layout)?,
+                    height,
+                    x_off,
+                    0,
+                    others,
+                )?;
+                let main = LayoutCell::pane(main_width, height, 0, 0, *main);
+                Ok(LayoutCell::new(
+                    width,
+                    height,
+                    0,
+                    0,
+                    None,
+                    LayoutType::LeftRight,
+                    Some(vec![main, others]),
+                ))
+            }
+        }
+    }
+
+    /// `main-horizontal` layout, first pane of `ids` is the main pane of `main_height` on the
+    /// top
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Layout`] if `ids` is empty or the window is too small
+    pub fn main_horizontal(
+        width: usize,
+        height: usize,
+        main_height: usize,
+        ids: &[usize],
+    ) -> Result<Self, Error> {
+        match ids {
+            [] => Err(Error::Layout),
+            [id] => Ok(LayoutCell::pane(width, height, 0, 0, *id)),
+            [main, others @ ..] => {
+                let main_height = main_height.min(height.saturating_sub(2 * BORDER)).max(1);
+                let y_off = main_height + BORDER;
+                let others = LayoutCell::sequence(
+                    LayoutType::LeftRight,
+                    width,
+                    height.checked_sub(y_off).ok_or(Error::Layout)?,
+                    0,
+                    y_off,
+                    others,
+                )?;
+                let main = LayoutCell::pane(width, main_height, 0, 0, *main);
+                Ok(LayoutCell::new(
+                    width,
+                    height,
+                    0,
+                    0,
+                    None,
+                    LayoutType::TopBottom,
+                    Some(vec![main, others]),
+                ))
+            }
+        }
+    }
+
+    /// Scale layout proportionally to a `width` x `height` window
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Layout`] if the window is too small for the layout
+    pub fn scale(&self, width: usize, height: usize) -> Result<Self, Error> {
+        self.scale_to(width, height, 0, 0)
+    }
+
+    fn scale_to(&self, x: usize, y: usize, x_off: usize, y_off: usize) -> Result<Self, Error> {
+        let mut cell = LayoutCell::new(x, y, x_off, y_off, self.id, self.style.clone(), None);
+        if let Some(cells) = &self.cells {
+            let mut scaled = Vec::with_capacity(cells.len());
+            let (mut x_pos, mut y_pos) = (x_off, y_off);
+            match self.style {
+                LayoutType::LeftRight => {
+                    let sizes: Vec<usize> = cells.iter().map(|c| c.x).collect();
+                    for (c, size) in cells.iter().zip(scale_sizes(&sizes, x)?) {
+                        scaled.push(c.scale_to(size, y, x_pos, y_off)?);
+                        x_pos += size + BORDER;
+                    }
+                }
+                _ => {
+                    let sizes: Vec<usize> = cells.iter().map(|c| c.y).collect();
+                    for (c, size) in cells.iter().zip(scale_sizes(&sizes, y)?) {
+                        scaled.push(c.scale_to(x, size, x_off, y_pos)?);
+                        y_pos += size + BORDER;
+                    }
+                }
+            }
+            cell.cells = Some(scaled);
+        }
+        Ok(cell)
+    }
+}
@@ -0,0 +1,116 @@
+#[test]
+fn tiled() {
+    use crate::{Layout, LayoutCell};
+
+    // layouts emitted by tmux (`select-layout tiled`, 200x50 window)
+    let layout = LayoutCell::tiled(200, 50, &[0, 1, 2]).unwrap();
+    assert_eq!(
+        Layout::from(layout).to_string(),
+        "4efd,200x50,0,0[200x24,0,0{99x24,0,0,0,100x24,100,0,1},200x25,0,25,2]"
+    );
+
+    let layout = LayoutCell::tiled(200, 50, &[0, 1, 2, 3, 4, 5, 6, 7]).unwrap();
+    assert_eq!(
+        Layout::from(layout).to_string(),
+        "54d7,200x50,0,0[200x16,0,0{66x16,0,0,0,66x16,67,0,1,66x16,134,0,2},200x16,0,17{66x16,0,17,3,66x16,67,17,4,66x16,134,17,5},200x16,0,34{66x16,0,34,6,133x16,67,34,7}]"
+    );
+
+    let layout = LayoutCell::tiled(200, 50, &[4]).unwrap();
+    assert_eq!(layout.to_string(), "200x50,0,0,4");
+
+    assert!(LayoutCell::tiled(200, 50, &[]).is_err());
+    assert!(LayoutCell::tiled(2, 50, &[0, 1, 2, 3]).is_err());
+}
+
+#[test]
+fn main_vertical() {
+    use crate::{Layout, LayoutCell};
+
+    // layouts emitted by tmux (`select-layout main-vertical`, 200x50 window)
+    let layout = LayoutCell::main_vertical(200, 50, 80, &[0, 1, 2, 3, 4]).unwrap();
+    assert_eq!(
+        Layout::from(layout).to_string(),
+        "099e,200x50,0,0{80x50,0,0,0,119x50,81,0[119x11,81,0,1,119x11,81,12,2,119x11,81,24,3,119x14,81,36,4]}"
+    );
+
+    let layout = LayoutCell::main_vertical(200, 50, 80, &[0, 1, 2]).unwrap();
+    assert_eq!(
+        Layout::from(layout).to_string(),
+        "5b8a,200x50,0,0{80x50,0,0,0,119x50,81,0[119x24,81,0,1,119x25,81,25,2]}"
+    );
+}
+
+#[test]
+fn main_horizontal() {
+    use crate::{Layout, LayoutCell};
+
+    // layout emitted by tmux (`select-layout main-horizontal`, 200x50 window)
+    let layout = LayoutCell::main_horizontal(200, 50, 24, &[0, 1, 2, 3, 4]).unwrap();
+    assert_eq!(
+        Layout::from(layout).to_string(),
+        "47cc,200x50,0,0[200x24,0,0,0,200x25,0,25{49x25,0,25,1,49x25,50,25,2,49x25,100,25,3,50x25,150,25,4}]"
+    );
+}
+
+#[test]
+fn split() {
+    use crate::{LayoutCell, LayoutType};
+
+    let mut layout = LayoutCell::pane(200, 50, 0, 0, 0);
+    layout.split(0, LayoutType::LeftRight, 0.5, 1).unwrap();
+    assert_eq!(layout.to_string(), "200x50,0,0{100x50,0,0,0,99x50,101,0,1}");
+
+    // same direction adds a sibling, other direction nests
+    layout.split(1, LayoutType::LeftRight, 0.5, 2).unwrap();
+    layout.split(0, LayoutType::TopBottom, 0.25, 3).unwrap();
+    assert_eq!(
+        layout.to_string(),
+        "200x50,0,0{100x50,0,0[100x37,0,0,0,100x12,0,38,3],49x50,101,0,1,49x50,151,0,2}"
+    );
+
+    assert!(layout.split(9, LayoutType::LeftRight, 0.5, 4).is_err());
+    let mut layout = LayoutCell::pane(2, 50, 0, 0, 0);
+    assert!(layout.split(0, LayoutType::LeftRight, 0.5, 1).is_err());
+}
+
+#[test]
+fn scale() {
+    use crate::LayoutCell;
+
+    let layout: LayoutCell = "200x50,0,0{100x50,0,0,0,99x50,101,0[99x25,101,0,1,99x24,101,26,2]}"
+        .parse()
+        .unwrap();
+    let scaled = layout.scale(100, 30).unwrap();
+    assert_eq!(
+        scaled.to_string(),
+        "100x30,0,0{49x30,0,0,0,50x30,50,0[50x14,50,0,1,50x15,50,15,2]}"
+    );
+    assert!(scaled.structural_eq(&layout));
+    assert!(layout.scale(2, 30).is_err());
+}
+
+#[test]
+fn geometry() {
+    use crate::{LayoutCell, LayoutDirection};
+
+    let layout: LayoutCell = "200x50,0,0{100x50,0,0,0,99x50,101,0[99x25,101,0,1,99x24,101,26,2]}"
+        .parse()
+        .unwrap();
+
+    assert_eq!(layout.panes().len(), 3);
+    assert_eq!(layout.pane_at(0, 0).and_then(|p| p.id), Some(0));
+    assert_eq!(layout.pane_at(150, 30).and_then(|p| p.id), Some(2));
+    // border
+    assert_eq!(layout.pane_at(100, 10), None);
+    assert_eq!(layout.pane_at(200, 10), None);
+
+    let neighbour = |id, direction| layout.neighbour(id, direction).and_then(|p| p.id);
+    assert_eq!(neighbour(0, LayoutDirection::Right), Some(1));
+    assert_eq!(neighbour(0, LayoutDirection::Left), None);
+    assert_eq!(neighbour(2, LayoutDirection::Left), Some(0));
+    assert_eq!(neighbour(1, LayoutDirection::Down), Some(2));
+    assert_eq!(neighbour(2, LayoutDirection::Up), Some(1));
+
+    let other = LayoutCell::tiled(200, 50, &[0, 1, 2]).unwrap();
+    assert!(!layout.structural_eq(&other));
+}
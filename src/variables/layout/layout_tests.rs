@@ -62,3 +62,64 @@ fn parse() {
 //let l = Layouts::new();
 //parse2(&self, layouts_str).unwrap();
 //}
+
+#[test]
+fn to_string() {
+    use crate::Layout;
+
+    // layouts emitted by tmux
+    let layouts = [
+        "aca1,200x50,0,0,4",
+        "8247,200x50,0,0{100x50,0,0,0,99x50,101,0[99x25,101,0,1,99x24,101,26{49x24,101,26,2,49x24,151,26,3}]}",
+        "543c,200x50,0,0[200x24,0,0{99x24,0,0,0,100x24,100,0,1},200x25,0,25{99x25,0,25,2,100x25,100,25,3}]",
+    ];
+    for s in layouts {
+        let layout: Layout = s.parse().unwrap();
+        assert_eq!(layout.checksum, usize::from_str_radix(&s[..4], 16).unwrap());
+        assert_eq!(layout.to_string(), s);
+    }
+}
+
+#[test]
+fn from_layout_cell() {
+    use crate::{Layout, LayoutCell, LayoutType};
+
+    let pane1 = LayoutCell::new(177, 48, 0, 0, Some(1), LayoutType::WindowPane, None);
+    let pane2 = LayoutCell::new(177, 15, 0, 49, Some(2), LayoutType::WindowPane, None);
+    let cell = LayoutCell::new(
+        177,
+        64,
+        0,
+        0,
+        None,
+        LayoutType::TopBottom,
+        Some(vec![pane1, pane2]),
+    );
+    let layout = Layout::from(cell);
+    assert_eq!(layout.checksum, 0xd964);
+    assert_eq!(
+        layout.to_string(),
+        "d964,177x64,0,0[177x48,0,0,1,177x15,0,49,2]"
+    );
+}
+
+#[test]
+fn parse_error() {
+    use crate::Layout;
+
+    let examples = &[
+        "",
+        "8b65",
+        "zz,177x64,0,0,1",
+        "8b65,177x64",
+        "8b65,177x64,0",
+        "8b65,177x64,0,0[",
+        "8b65,177x64,0,0[]",
+        "8b65,177x64,0,0[177x46,0,0,1,177x17,0,47,4",
+        "8b65,177x64,0,0,1]",
+        "8b65,177x64,0,0,1,",
+    ];
+    for example in examples {
+        assert!(example.parse::<Layout>().is_err(), "{}", example);
+    }
+}
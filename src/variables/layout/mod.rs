@@ -1,6 +1,7 @@
 pub mod layout;
 pub mod layout_cell;
 pub mod layout_checksum;
+pub mod layout_geometry;
 
 #[cfg(feature = "tmux_1_6")]
 pub use layout::Layout;
@@ -8,11 +9,14 @@ pub use layout::Layout;
 pub use layout_cell::{LayoutCell, LayoutType};
 #[cfg(feature = "tmux_1_6")]
 pub use layout_checksum::LayoutChecksum;
+#[cfg(feature = "tmux_1_6")]
+pub use layout_geometry::LayoutDirection;
 
 #[cfg(test)]
 #[path = "."]
 mod variables_layout_tests {
     mod layout_cell_tests;
     mod layout_checksum_tests;
+    mod layout_geometry_tests;
     mod layout_tests;
 }
@@ -2,6 +2,8 @@ use crate::Error;
 use crate::FormatsOutput;
 #[cfg(feature = "tmux_1_8")]
 use crate::PaneTabs;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 pub const PANE_VARS_SEPARATOR: char = '\'';
@@ -19,6 +21,7 @@ pub const PANE_VARS_SEPARATOR: char = '\'';
 // XXX: mb macro with custom struct fields
 // accordingly to tmux.h: Formats
 // XXX: check all types
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct Pane {
     /// pane_active - 1 if active pane
@@ -98,10 +101,10 @@ pub struct Pane {
     pub search_string: Option<usize>,
     /// pane_start_command - Command pane started with
     #[cfg(feature = "tmux_1_6")]
-    pub start_command: Option<usize>,
+    pub start_command: Option<String>,
     /// pane_start_path - Path pane started with
     #[cfg(all(feature = "tmux_1_6", not(feature = "tmux_2_0")))]
-    pub start_path: Option<usize>,
+    pub start_path: Option<String>,
     /// pane_synchronized - 1 if pane is synchronized
     #[cfg(feature = "tmux_1_9")]
     pub synchronized: Option<bool>,
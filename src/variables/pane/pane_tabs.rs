@@ -1,4 +1,5 @@
 use crate::Error;
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Default, PartialEq, Clone, Debug)]
@@ -16,3 +17,13 @@ impl FromStr for PaneTabs {
         Ok(Self(tabs))
     }
 }
+
+impl fmt::Display for PaneTabs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tabs: Vec<String> = self.0.iter().map(|tab| tab.to_string()).collect();
+        f.write_str(&tabs.join(","))
+    }
+}
+
+#[cfg(feature = "serde")]
+serde_str!(PaneTabs);
@@ -1,8 +1,11 @@
 use crate::{Error, Pane};
 //use std::borrow::Cow;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::ops::Index;
 use std::str::FromStr;
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct Panes(pub Vec<Pane>);
 
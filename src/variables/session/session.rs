@@ -2,12 +2,15 @@ use crate::Error;
 use crate::FormatsOutput;
 #[cfg(feature = "tmux_2_5")]
 use crate::SessionStack;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 // XXX: number of all flags, needed for array init
 // NOTE: variables were first intoduced in tmux 1.6
 // accordingly to tmux.h: Formats
 // XXX: check all types
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, PartialEq, Clone, Debug)]
 pub struct Session {
     // NOTE: u64
@@ -1,4 +1,5 @@
 use crate::Error;
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Default, PartialEq, Clone, Debug)]
@@ -18,3 +19,13 @@ impl FromStr for SessionStack {
         Ok(Self(sv))
     }
 }
+
+impl fmt::Display for SessionStack {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ids: Vec<String> = self.0.iter().map(|id| id.to_string()).collect();
+        f.write_str(&ids.join(&SESSION_STACK_SEPARATOR.to_string()))
+    }
+}
+
+#[cfg(feature = "serde")]
+serde_str!(SessionStack);
@@ -7,3 +7,11 @@ fn parse() {
     let session_stack_origin = SessionStack(vec![3, 2, 1]);
     assert_eq!(session_stack, session_stack_origin);
 }
+
+#[test]
+fn parse_error() {
+    use crate::SessionStack;
+
+    assert!("".parse::<SessionStack>().is_err());
+    assert!("3,x,1".parse::<SessionStack>().is_err());
+}
@@ -1,7 +1,10 @@
 use crate::{Error, Session};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::ops::Index;
 use std::str::FromStr;
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct Sessions(pub Vec<Session>);
 
@@ -1,4 +1,6 @@
 use crate::{Error, FormatsOutput, Layout, WindowFlags};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 // NOTE: variables were first intoduced in tmux 1.6
@@ -7,6 +9,7 @@ pub const WINDOW_VARS_SEPARATOR: char = '\'';
 
 // accordingly to tmux.h: Formats
 // XXX: check all types, optionality
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct Window {
     /// window_active - 1 if window active
@@ -1,4 +1,5 @@
 use crate::Error;
+use std::fmt;
 use std::str::FromStr;
 
 const WINDOW_FLAG_DEFAULT: usize = 0b0000_0000;
@@ -27,13 +28,13 @@ impl FromStr for WindowFlags {
         let chrs = s.chars();
         for c in chrs {
             match c {
-                '*' => wf.0 += WINDOW_FLAG_CURRENT,
-                '-' => wf.0 += WINDOW_FLAG_LAST,
-                '#' => wf.0 += WINDOW_FLAG_ACTIVITY,
-                '!' => wf.0 += WINDOW_FLAG_BELL,
-                '~' => wf.0 += WINDOW_FLAG_SILENCED,
-                'M' => wf.0 += WINDOW_FLAG_MARKED,
-                'Z' => wf.0 += WINDOW_FLAG_ZOOMED,
+                '*' => wf.0 |= WINDOW_FLAG_CURRENT,
+                '-' => wf.0 |= WINDOW_FLAG_LAST,
+                '#' => wf.0 |= WINDOW_FLAG_ACTIVITY,
+                '!' => wf.0 |= WINDOW_FLAG_BELL,
+                '~' => wf.0 |= WINDOW_FLAG_SILENCED,
+                'M' => wf.0 |= WINDOW_FLAG_MARKED,
+                'Z' => wf.0 |= WINDOW_FLAG_ZOOMED,
                 // XXX: Error description
                 _ => return Err(Error::ParseWindowFlags),
             }
@@ -41,3 +42,27 @@ impl FromStr for WindowFlags {
         Ok(wf)
     }
 }
+
+// NOTE: flags order as in tmux (ref: window.c -> window_printable_flags())
+impl fmt::Display for WindowFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let flags = [
+            (WINDOW_FLAG_ACTIVITY, '#'),
+            (WINDOW_FLAG_BELL, '!'),
+            (WINDOW_FLAG_SILENCED, '~'),
+            (WINDOW_FLAG_CURRENT, '*'),
+            (WINDOW_FLAG_LAST, '-'),
+            (WINDOW_FLAG_MARKED, 'M'),
+            (WINDOW_FLAG_ZOOMED, 'Z'),
+        ];
+        let s: String = flags
+            .iter()
+            .filter(|(flag, _)| self.0 & flag != 0)
+            .map(|(_, c)| c)
+            .collect();
+        f.write_str(&s)
+    }
+}
+
+#[cfg(feature = "serde")]
+serde_str!(WindowFlags);
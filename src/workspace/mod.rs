@@ -0,0 +1,13 @@
+//! Declarative workspace definitions (session, windows, panes) and their idempotent
+//! application
+pub mod workspace;
+pub mod workspace_ctl;
+
+pub use workspace::{Workspace, WorkspacePane, WorkspaceWindow};
+pub use workspace_ctl::{WorkspaceCtl, WorkspaceDrift, WorkspaceReport};
+
+#[cfg(test)]
+#[path = "."]
+mod workspace_tests {
+    pub mod workspace_tests;
+}
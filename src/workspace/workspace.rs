@@ -0,0 +1,196 @@
+use crate::Error;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Declarative session definition (tmuxinator-style): session name, root directory,
+/// environment, windows with layouts and panes with commands
+///
+/// # Examples
+///
+/// ```
+/// use tmux_interface::{Workspace, WorkspacePane, WorkspaceWindow};
+///
+/// let workspace = Workspace::new("project")
+///     .root("/tmp")
+///     .env("RUST_LOG", "debug")
+///     .window(
+///         WorkspaceWindow::new("editor")
+///             .layout("main-vertical")
+///             .pane(WorkspacePane::new().command("vim").focus())
+///             .pane(WorkspacePane::new().command("cargo watch")),
+///     )
+///     .window(WorkspaceWindow::new("shell"));
+///
+/// assert_eq!(workspace.windows[0].panes_len(), 2);
+/// assert_eq!(workspace.windows[1].panes_len(), 1);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Workspace {
+    /// session name
+    pub name: String,
+    /// default start directory for all windows and panes
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub root: Option<String>,
+    /// session environment variables
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub env: BTreeMap<String, String>,
+    /// windows in order of creation
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub windows: Vec<WorkspaceWindow>,
+}
+
+/// Window of the [`Workspace`], windows are matched by name
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct WorkspaceWindow {
+    /// window name
+    pub name: String,
+    /// start directory, overrides [`Workspace::root`]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub root: Option<String>,
+    /// layout name (e.g. `tiled`, `main-vertical`) or layout string
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub layout: Option<String>,
+    /// panes in order of creation, a window without panes has one empty pane
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub panes: Vec<WorkspacePane>,
+    /// select window after the session is created
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub focus: bool,
+}
+
+/// Pane of the [`WorkspaceWindow`], panes are matched by position
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct WorkspacePane {
+    /// start directory, overrides [`WorkspaceWindow::root`]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub root: Option<String>,
+    /// commands sent to the pane after creation
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub commands: Vec<String>,
+    /// select pane after the session is created
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub focus: bool,
+}
+
+impl Workspace {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn root<S: Into<String>>(mut self, root: S) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    pub fn env<S: Into<String>, T: Into<String>>(mut self, name: S, value: T) -> Self {
+        self.env.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn window(mut self, window: WorkspaceWindow) -> Self {
+        self.windows.push(window);
+        self
+    }
+
+    /// Parse workspace definition in TOML format
+    #[cfg(feature = "toml")]
+    pub fn from_toml(s: &str) -> Result<Self, Error> {
+        Ok(toml::from_str(s)?)
+    }
+
+    /// Parse workspace definition in YAML format
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(s: &str) -> Result<Self, Error> {
+        Ok(serde_yaml::from_str(s)?)
+    }
+
+    /// Load workspace definition from file, format is chosen by file extension
+    /// (`.toml`, `.yml`, `.yaml`), only enabled formats are supported
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        #[allow(unused_variables)]
+        let s = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&s),
+            #[cfg(feature = "yaml")]
+            Some("yml") | Some("yaml") => Self::from_yaml(&s),
+            _ => Err(Error::Workspace),
+        }
+    }
+
+    /// Start directory of the pane, the most specific root is used
+    pub fn pane_root<'a>(
+        &'a self,
+        window: &'a WorkspaceWindow,
+        pane: Option<&'a WorkspacePane>,
+    ) -> Option<&'a str> {
+        pane.and_then(|pane| pane.root.as_deref())
+            .or(window.root.as_deref())
+            .or(self.root.as_deref())
+    }
+}
+
+impl WorkspaceWindow {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn root<S: Into<String>>(mut self, root: S) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    pub fn layout<S: Into<String>>(mut self, layout: S) -> Self {
+        self.layout = Some(layout.into());
+        self
+    }
+
+    pub fn pane(mut self, pane: WorkspacePane) -> Self {
+        self.panes.push(pane);
+        self
+    }
+
+    pub fn focus(mut self) -> Self {
+        self.focus = true;
+        self
+    }
+
+    /// Number of panes expected in the window (at least one)
+    pub fn panes_len(&self) -> usize {
+        self.panes.len().max(1)
+    }
+}
+
+impl WorkspacePane {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn root<S: Into<String>>(mut self, root: S) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    pub fn command<S: Into<String>>(mut self, command: S) -> Self {
+        self.commands.push(command.into());
+        self
+    }
+
+    pub fn focus(mut self) -> Self {
+        self.focus = true;
+        self
+    }
+}
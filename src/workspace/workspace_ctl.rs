@@ -0,0 +1,399 @@
+#[cfg(not(feature = "tmux_3_2"))]
+use crate::SetEnvironment;
+use crate::{
+    Error, HasSession, NewSession, NewWindow, Panes, PanesCtl, SelectLayout, SelectPane,
+    SelectWindow, SendKeys, SplitWindow, Tmux, TmuxCommand, TmuxCommands, TmuxOutput, Window,
+    WindowsCtl, Workspace, WorkspaceWindow,
+};
+use std::fmt;
+
+const ENTER_KEY: &str = "Enter";
+const WINDOW_ID_FORMAT: &str = "#{window_id}";
+const PANE_ID_FORMAT: &str = "#{pane_id}";
+
+/// Difference between a [`Workspace`] and the running tmux session
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum WorkspaceDrift {
+    /// session does not exist
+    MissingSession(String),
+    /// window from the spec does not exist
+    MissingWindow(String),
+    /// window has fewer panes than the spec
+    MissingPanes {
+        window: String,
+        expected: usize,
+        actual: usize,
+    },
+    /// window exists, but is not in the spec
+    ExtraWindow(String),
+    /// window has more panes than the spec
+    ExtraPanes {
+        window: String,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for WorkspaceDrift {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingSession(name) => write!(f, "missing session: {}", name),
+            Self::MissingWindow(name) => write!(f, "missing window: {}", name),
+            Self::MissingPanes {
+                window,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "missing panes in window {}: expected {}, actual {}",
+                window, expected, actual
+            ),
+            Self::ExtraWindow(name) => write!(f, "extra window: {}", name),
+            Self::ExtraPanes {
+                window,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "extra panes in window {}: expected {}, actual {}",
+                window, expected, actual
+            ),
+        }
+    }
+}
+
+/// Result of [`WorkspaceCtl::apply`]
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct WorkspaceReport {
+    /// missing objects created by apply
+    pub created: Vec<WorkspaceDrift>,
+    /// remaining differences, which are not changed by apply (extra windows and panes)
+    pub drift: Vec<WorkspaceDrift>,
+}
+
+impl WorkspaceReport {
+    /// Running session matches the spec
+    pub fn is_clean(&self) -> bool {
+        self.created.is_empty() && self.drift.is_empty()
+    }
+}
+
+/// Apply [`Workspace`] definitions using `new-session`, `new-window`, `split-window`,
+/// `select-layout` and `send-keys` commands
+///
+/// Apply is idempotent: only missing session, windows and panes are created, commands
+/// are sent only to the created panes, existing objects are never modified or killed
+///
+/// # Examples
+///
+/// ```no_run
+/// use tmux_interface::{Workspace, WorkspaceCtl, WorkspacePane, WorkspaceWindow};
+///
+/// let workspace = Workspace::new("project").window(
+///     WorkspaceWindow::new("editor")
+///         .pane(WorkspacePane::new().command("vim"))
+///         .pane(WorkspacePane::new()),
+/// );
+/// let report = WorkspaceCtl::new().apply(&workspace).unwrap();
+/// for drift in report.drift {
+///     println!("{}", drift);
+/// }
+/// ```
+pub struct WorkspaceCtl<'a> {
+    pub invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>,
+}
+
+impl<'a> Default for WorkspaceCtl<'a> {
+    fn default() -> Self {
+        Self {
+            invoker: &|cmd| Tmux::with_command(cmd).output(),
+        }
+    }
+}
+
+impl<'a> WorkspaceCtl<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_invoker(invoker: &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error>) -> Self {
+        Self { invoker }
+    }
+
+    pub fn invoker(&self) -> &'a dyn Fn(TmuxCommand<'a>) -> Result<TmuxOutput, Error> {
+        self.invoker
+    }
+
+    /// Compare the spec with the running session without changing anything
+    pub fn drift(&self, workspace: &Workspace) -> Result<Vec<WorkspaceDrift>, Error> {
+        let mut drift = Vec::new();
+
+        if !self.has_session(workspace)? {
+            drift.push(WorkspaceDrift::MissingSession(workspace.name.clone()));
+            drift.extend(
+                workspace
+                    .windows
+                    .iter()
+                    .map(|w| WorkspaceDrift::MissingWindow(w.name.clone())),
+            );
+            return Ok(drift);
+        }
+
+        let (matched, extra) = self.match_windows(workspace)?;
+        for (spec, window) in workspace.windows.iter().zip(matched) {
+            match window {
+                Some(window_id) => {
+                    let actual = self.panes(&window_id)?.0.len();
+                    drift.extend(Self::panes_drift(spec, actual));
+                }
+                None => drift.push(WorkspaceDrift::MissingWindow(spec.name.clone())),
+            }
+        }
+        drift.extend(extra);
+        Ok(drift)
+    }
+
+    /// Create missing session, windows and panes
+    pub fn apply(&self, workspace: &Workspace) -> Result<WorkspaceReport, Error> {
+        let mut report = WorkspaceReport::default();
+
+        let session_created = !self.has_session(workspace)?;
+        if session_created {
+            self.new_session(workspace)?;
+            report
+                .created
+                .push(WorkspaceDrift::MissingSession(workspace.name.clone()));
+        }
+
+        let (matched, extra) = self.match_windows(workspace)?;
+        // window created together with the session
+        let mut initial = session_created;
+        let mut focus = (None, None);
+
+        for (spec, window) in workspace.windows.iter().zip(matched) {
+            let (window_id, window_created) = match window {
+                Some(window_id) => (window_id, initial),
+                None => {
+                    report
+                        .created
+                        .push(WorkspaceDrift::MissingWindow(spec.name.clone()));
+                    (self.new_window(workspace, spec)?, true)
+                }
+            };
+            initial = false;
+
+            let mut pane_ids = self
+                .panes(&window_id)?
+                .into_iter()
+                .map(|pane| pane.id.map(|id| format!("%{}", id)).ok_or(Error::Workspace))
+                .collect::<Result<Vec<String>, Error>>()?;
+
+            let actual = pane_ids.len();
+            let expected = spec.panes_len();
+            // panes existing before apply are left untouched
+            let first_new = if window_created { 0 } else { actual };
+
+            match Self::panes_drift(spec, actual) {
+                Some(drift @ WorkspaceDrift::MissingPanes { .. }) => {
+                    if !window_created {
+                        report.created.push(drift);
+                    }
+                    for i in actual..expected {
+                        let pane_id = self.split_window(workspace, spec, i, &window_id)?;
+                        pane_ids.push(pane_id);
+                    }
+                }
+                Some(drift) => report.drift.push(drift),
+                None => {}
+            }
+
+            for (pane, pane_id) in spec.panes.iter().zip(&pane_ids).skip(first_new) {
+                for command in &pane.commands {
+                    self.send_command(pane_id, command)?;
+                }
+            }
+
+            if first_new < pane_ids.len() {
+                if let Some(layout) = &spec.layout {
+                    let select_layout = SelectLayout::new()
+                        .target_pane(window_id.clone())
+                        .layout_name(layout.clone());
+                    (self.invoker)(select_layout.build())?.into_result()?;
+                }
+            }
+
+            if spec.focus {
+                focus.0 = Some(window_id.clone());
+            }
+            if let Some((_, pane_id)) = spec.panes.iter().zip(&pane_ids).find(|(p, _)| p.focus) {
+                focus.1 = Some(pane_id.clone());
+            }
+        }
+        report.drift.extend(extra);
+
+        // focus is applied only to a new session, to not disturb attached clients
+        if session_created {
+            if let Some(window_id) = focus.0 {
+                let select_window = SelectWindow::new().target_window(window_id);
+                (self.invoker)(select_window.build())?.into_result()?;
+            }
+            if let Some(pane_id) = focus.1 {
+                let select_pane = SelectPane::new().target_pane(pane_id);
+                (self.invoker)(select_pane.build())?.into_result()?;
+            }
+        }
+
+        Ok(report)
+    }
+
+    fn session_target(workspace: &Workspace) -> String {
+        format!("={}", workspace.name)
+    }
+
+    fn has_session(&self, workspace: &Workspace) -> Result<bool, Error> {
+        let cmd = HasSession::new()
+            .target_session(Self::session_target(workspace))
+            .build();
+        Ok((self.invoker)(cmd)?.success())
+    }
+
+    fn new_session(&self, workspace: &Workspace) -> Result<(), Error> {
+        let mut cmd = NewSession::new()
+            .detached()
+            .session_name(workspace.name.clone());
+        if let Some(window) = workspace.windows.first() {
+            cmd = cmd.window_name(window.name.clone());
+            if let Some(root) = workspace.pane_root(window, window.panes.first()) {
+                cmd = cmd.start_directory(root.to_string());
+            }
+        } else if let Some(root) = &workspace.root {
+            cmd = cmd.start_directory(root.clone());
+        }
+        #[cfg(feature = "tmux_3_2")]
+        for (name, value) in &workspace.env {
+            cmd = cmd.environment(name.clone(), value.clone());
+        }
+        (self.invoker)(cmd.build())?.into_result()?;
+
+        #[cfg(not(feature = "tmux_3_2"))]
+        for (name, value) in &workspace.env {
+            let set_environment = SetEnvironment::new()
+                .target_session(Self::session_target(workspace))
+                .name(name.clone())
+                .value(value.clone());
+            (self.invoker)(set_environment.build())?.into_result()?;
+        }
+        Ok(())
+    }
+
+    fn new_window(&self, workspace: &Workspace, window: &WorkspaceWindow) -> Result<String, Error> {
+        let mut cmd = NewWindow::new()
+            .detached()
+            .print()
+            .format(WINDOW_ID_FORMAT)
+            .window_name(window.name.clone())
+            .target_window(format!("{}:", Self::session_target(workspace)));
+        if let Some(root) = workspace.pane_root(window, window.panes.first()) {
+            cmd = cmd.start_directory(root.to_string());
+        }
+        let output = (self.invoker)(cmd.build())?.into_result()?;
+        Ok(output.to_string().trim().to_string())
+    }
+
+    fn split_window(
+        &self,
+        workspace: &Workspace,
+        window: &WorkspaceWindow,
+        pane: usize,
+        window_id: &str,
+    ) -> Result<String, Error> {
+        let mut cmd = SplitWindow::new()
+            .detached()
+            .print()
+            .format(PANE_ID_FORMAT)
+            .target_pane(window_id.to_string());
+        if let Some(root) = workspace.pane_root(window, window.panes.get(pane)) {
+            cmd = cmd.start_directory(root.to_string());
+        }
+        let output = (self.invoker)(cmd.build())?.into_result()?;
+        Ok(output.to_string().trim().to_string())
+    }
+
+    /// Send the command as literal text (`send-keys -l`) followed by `Enter`
+    fn send_command(&self, pane_id: &str, command: &str) -> Result<(), Error> {
+        let cmds = TmuxCommands::new()
+            .cmd(
+                SendKeys::new()
+                    .disable_lookup()
+                    .target_pane(pane_id.to_string())
+                    .key(command.to_string()),
+            )
+            .cmd(
+                SendKeys::new()
+                    .target_pane(pane_id.to_string())
+                    .key(ENTER_KEY),
+            );
+        (self.invoker)(TmuxCommand::with_cmds(cmds))?.into_result()?;
+        Ok(())
+    }
+
+    fn panes(&self, window_id: &str) -> Result<Panes, Error> {
+        PanesCtl::get_all_ext(Some(window_id.to_string()), self.invoker)
+    }
+
+    fn window_id(window: &Window) -> Result<String, Error> {
+        window
+            .id
+            .map(|id| format!("@{}", id))
+            .ok_or(Error::Workspace)
+    }
+
+    /// Assign running windows to the spec windows by name (in order), remaining windows
+    /// are reported as extra
+    fn match_windows(
+        &self,
+        workspace: &Workspace,
+    ) -> Result<(Vec<Option<String>>, Vec<WorkspaceDrift>), Error> {
+        let mut windows: Vec<Option<Window>> =
+            WindowsCtl::get_all_ext(Some(Self::session_target(workspace)), self.invoker)?
+                .into_iter()
+                .map(Some)
+                .collect();
+
+        let mut matched = Vec::new();
+        for spec in &workspace.windows {
+            let window = windows
+                .iter_mut()
+                .find(|w| w.as_ref().and_then(|w| w.name.as_deref()) == Some(spec.name.as_str()))
+                .and_then(|w| w.take());
+            matched.push(window.as_ref().map(Self::window_id).transpose()?);
+        }
+
+        let extra = windows
+            .into_iter()
+            .flatten()
+            .map(|w| WorkspaceDrift::ExtraWindow(w.name.unwrap_or_default()))
+            .collect();
+        Ok((matched, extra))
+    }
+
+    fn panes_drift(window: &WorkspaceWindow, actual: usize) -> Option<WorkspaceDrift> {
+        let expected = window.panes_len();
+        let name = window.name.clone();
+        if actual < expected {
+            Some(WorkspaceDrift::MissingPanes {
+                window: name,
+                expected,
+                actual,
+            })
+        } else if actual > expected {
+            Some(WorkspaceDrift::ExtraPanes {
+                window: name,
+                expected,
+                actual,
+            })
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,190 @@
+#[test]
+fn builder() {
+    use crate::{Workspace, WorkspacePane, WorkspaceWindow};
+    use std::collections::BTreeMap;
+
+    let workspace = Workspace::new("project")
+        .root("/tmp")
+        .env("RUST_LOG", "debug")
+        .window(
+            WorkspaceWindow::new("editor")
+                .layout("main-vertical")
+                .focus()
+                .pane(WorkspacePane::new().command("vim").focus())
+                .pane(WorkspacePane::new().root("/var").command("ls")),
+        )
+        .window(WorkspaceWindow::new("shell"));
+
+    let mut env = BTreeMap::new();
+    env.insert("RUST_LOG".to_string(), "debug".to_string());
+    let expected = Workspace {
+        name: "project".to_string(),
+        root: Some("/tmp".to_string()),
+        env,
+        windows: vec![
+            WorkspaceWindow {
+                name: "editor".to_string(),
+                root: None,
+                layout: Some("main-vertical".to_string()),
+                panes: vec![
+                    WorkspacePane {
+                        root: None,
+                        commands: vec!["vim".to_string()],
+                        focus: true,
+                    },
+                    WorkspacePane {
+                        root: Some("/var".to_string()),
+                        commands: vec!["ls".to_string()],
+                        focus: false,
+                    },
+                ],
+                focus: true,
+            },
+            WorkspaceWindow {
+                name: "shell".to_string(),
+                ..Default::default()
+            },
+        ],
+    };
+    assert_eq!(workspace, expected);
+}
+
+#[test]
+fn pane_root() {
+    use crate::{Workspace, WorkspacePane, WorkspaceWindow};
+
+    let pane = WorkspacePane::new().root("/pane");
+    let window = WorkspaceWindow::new("window").root("/window");
+    let workspace = Workspace::new("session").root("/session");
+
+    assert_eq!(workspace.pane_root(&window, Some(&pane)), Some("/pane"));
+    assert_eq!(workspace.pane_root(&window, None), Some("/window"));
+    let window = WorkspaceWindow::new("window");
+    assert_eq!(
+        workspace.pane_root(&window, Some(&WorkspacePane::new())),
+        Some("/session")
+    );
+    assert_eq!(Workspace::new("session").pane_root(&window, None), None);
+}
+
+#[test]
+fn panes_len() {
+    use crate::{WorkspacePane, WorkspaceWindow};
+
+    assert_eq!(WorkspaceWindow::new("window").panes_len(), 1);
+    let window = WorkspaceWindow::new("window")
+        .pane(WorkspacePane::new())
+        .pane(WorkspacePane::new());
+    assert_eq!(window.panes_len(), 2);
+}
+
+#[test]
+fn drift_display() {
+    use crate::WorkspaceDrift;
+
+    assert_eq!(
+        WorkspaceDrift::MissingSession("project".to_string()).to_string(),
+        "missing session: project"
+    );
+    assert_eq!(
+        WorkspaceDrift::ExtraPanes {
+            window: "editor".to_string(),
+            expected: 2,
+            actual: 3
+        }
+        .to_string(),
+        "extra panes in window editor: expected 2, actual 3"
+    );
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn from_toml() {
+    use crate::{Workspace, WorkspacePane, WorkspaceWindow};
+
+    let s = r#"
+        name = "project"
+        root = "/tmp"
+
+        [env]
+        RUST_LOG = "debug"
+
+        [[windows]]
+        name = "editor"
+        layout = "main-vertical"
+        focus = true
+
+        [[windows.panes]]
+        commands = ["vim"]
+        focus = true
+
+        [[windows.panes]]
+        root = "/var"
+        commands = ["ls"]
+
+        [[windows]]
+        name = "shell"
+    "#;
+    let workspace = Workspace::from_toml(s).unwrap();
+    let expected = Workspace::new("project")
+        .root("/tmp")
+        .env("RUST_LOG", "debug")
+        .window(
+            WorkspaceWindow::new("editor")
+                .layout("main-vertical")
+                .focus()
+                .pane(WorkspacePane::new().command("vim").focus())
+                .pane(WorkspacePane::new().root("/var").command("ls")),
+        )
+        .window(WorkspaceWindow::new("shell"));
+    assert_eq!(workspace, expected);
+
+    assert!(Workspace::from_toml("root = \"/tmp\"").is_err());
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn from_yaml() {
+    use crate::{Workspace, WorkspacePane, WorkspaceWindow};
+
+    let s = r#"
+name: project
+env:
+  RUST_LOG: debug
+windows:
+  - name: editor
+    layout: tiled
+    panes:
+      - commands: [vim]
+        focus: true
+      - {}
+  - name: shell
+"#;
+    let workspace = Workspace::from_yaml(s).unwrap();
+    let expected = Workspace::new("project")
+        .env("RUST_LOG", "debug")
+        .window(
+            WorkspaceWindow::new("editor")
+                .layout("tiled")
+                .pane(WorkspacePane::new().command("vim").focus())
+                .pane(WorkspacePane::new()),
+        )
+        .window(WorkspaceWindow::new("shell"));
+    assert_eq!(workspace, expected);
+
+    assert!(Workspace::from_yaml("windows: []").is_err());
+}
+
+#[test]
+fn from_file_unsupported() {
+    use crate::{Error, Workspace};
+
+    assert!(matches!(
+        Workspace::from_file("not_existing_workspace.toml"),
+        Err(Error::IO(_))
+    ));
+    assert!(matches!(
+        Workspace::from_file("LICENSE.md"),
+        Err(Error::Workspace)
+    ));
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "tmux_3_2")]
+#[test]
+fn tmux_conf_source_file() {
+    use tmux_interface::{
+        BindKey, ListKeys, ServerOptions, SessionOptions, SetEnvironment, SetHook, SetOption,
+        ShowEnvironment, ShowHooks, ShowOptions, SourceFile, TestServer, TmuxCommand, TmuxConf,
+        WindowOptions,
+    };
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+    let output = |cmd: TmuxCommand| invoker(cmd).unwrap().into_result().unwrap().to_string();
+    let show = |cmd: ShowOptions| output(cmd.value().build());
+
+    let status_left = "#[fg=red] it's \"$HOME\" \\ ;";
+    let status_right = "a\nit's b";
+    let status_format = ["#[align=left]#S", "#{pane_title}"];
+    let window_status_format = "#I:#W\t~";
+    let path = std::env::temp_dir().join(format!("{}.conf", server.socket_name()));
+    TmuxConf::new()
+        .server_options(&ServerOptions {
+            escape_time: Some(10),
+            ..Default::default()
+        })
+        .session_options(&SessionOptions {
+            status_left: Some(status_left.into()),
+            status_right: Some(status_right.into()),
+            status_format: Some(status_format.iter().map(|s| (*s).into()).collect()),
+            ..Default::default()
+        })
+        .window_options(&WindowOptions {
+            window_status_format: Some(window_status_format.into()),
+            ..Default::default()
+        })
+        .bind_key(BindKey::new().key("|").command("split-window -h"))
+        .set_hook(
+            SetHook::new()
+                .global()
+                .hook_name("after-new-window")
+                .command("display-message 'new window'"),
+        )
+        .set_environment(SetEnvironment::new().global().name("MY_VAR").value("a b"))
+        .command_alias("info2", "show-messages -JT")
+        .since(
+            (3, 2),
+            TmuxConf::new().command(SetOption::new().global().option("@since").value("3.2")),
+        )
+        .since(
+            (99, 0),
+            TmuxConf::new().command(SetOption::new().global().option("@since").value("99.0")),
+        )
+        .write(&path)
+        .unwrap();
+
+    let source_file = SourceFile::new().path(path.to_string_lossy().to_string());
+    let result = invoker(source_file.build()).unwrap().into_result();
+    std::fs::remove_file(&path).unwrap();
+    result.unwrap();
+
+    let escape_time = show(ShowOptions::new().server().option("escape-time"));
+    assert_eq!(escape_time, "10\n");
+    let status_left_actual = show(ShowOptions::new().global().option("status-left"));
+    assert_eq!(status_left_actual, format!("{}\n", status_left));
+    let status_right_actual = show(ShowOptions::new().global().option("status-right"));
+    assert_eq!(status_right_actual, format!("{}\n", status_right));
+    let status_format_actual = show(ShowOptions::new().global().option("status-format"));
+    assert_eq!(
+        status_format_actual,
+        format!("{}\n", status_format.join("\n"))
+    );
+    let window_status_format_actual = show(
+        ShowOptions::new()
+            .global()
+            .window()
+            .option("window-status-format"),
+    );
+    assert_eq!(
+        window_status_format_actual,
+        format!("{}\n", window_status_format)
+    );
+    let since = show(ShowOptions::new().global().option("@since"));
+    assert_eq!(since, "3.2\n");
+
+    let keys = output(ListKeys::new().key_table("prefix").build());
+    assert!(keys.lines().any(|line| line.ends_with("split-window -h")));
+    let hooks = output(ShowHooks::new().global().build());
+    assert!(hooks.contains("after-new-window[0] display-message \"new window\""));
+    let env = output(ShowEnvironment::new().global().variable("MY_VAR").build());
+    assert_eq!(env, "MY_VAR=a b\n");
+    let command_alias = output(ShowOptions::new().server().option("command-alias").build());
+    assert!(command_alias.contains("\"info2=show-messages -JT\""));
+}
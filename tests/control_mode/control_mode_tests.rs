@@ -16,7 +16,7 @@
 fn control_mode() {
     use std::io::{BufRead, BufReader};
     use tmux_interface::control_mode::control_mode::{ControlModeOutput, Response};
-    use tmux_interface::{AttachSession, NewWindow, StdIO, Tmux};
+    use tmux_interface::{AttachSession, NewWindow, StdIO, TestServer};
 
     //let tmux = Command::new("tmux")
     //.args(&["-C", "attach", "-t", "3"])
@@ -26,15 +26,19 @@ fn control_mode() {
     //.spawn()
     //.unwrap();
 
-    let tmux = Tmux::with_command(AttachSession::new().target_session("0"))
+    let server = TestServer::new().unwrap();
+    let mut tmux = server
+        .tmux()
+        .command(AttachSession::new())
         .control_mode()
         .stdout(Some(StdIO::Piped))
         .stdin(Some(StdIO::Piped))
         .spawn()
         .unwrap();
 
-    let stdout = tmux.stdout.unwrap();
-    let mut stdin = tmux.stdin.unwrap();
+    let stdout = tmux.stdout.take().unwrap();
+    // client exits when stdin is closed
+    let mut stdin = tmux.stdin.take();
 
     let reader = BufReader::new(stdout);
 
@@ -50,21 +54,27 @@ fn control_mode() {
 
     // working example of check return of the sent command
     //
+    let mut window_added = false;
     while let Some(cm_line) = cm_lines.next() {
         dbg!(&cm_line);
 
         match &cm_line {
-            #[cfg(feature = "tmux_2_5")]
-            Response::SessionWindowChanged {
-                session_id,
-                window_id,
-            } => {
-                let cmd = NewWindow::new().detached();
-                ControlModeOutput::send(&mut stdin, cmd, &mut cm_lines).unwrap();
+            // attached
+            Response::SessionChanged { .. } => {
+                if let Some(stdin) = stdin.as_mut() {
+                    let cmd = NewWindow::new().detached();
+                    ControlModeOutput::send(stdin, cmd, &mut cm_lines).unwrap();
+                }
+            }
+            Response::WindowAdd(_) => {
+                window_added = true;
+                stdin.take();
             }
             _ => {}
         }
     }
+    assert!(window_added);
+    assert!(tmux.wait().unwrap().success());
 
     // event loop
     //for cm_line in cm_lines {
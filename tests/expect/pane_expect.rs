@@ -0,0 +1,62 @@
+#[cfg(all(feature = "regex", feature = "tmux_2_9a"))]
+#[test]
+fn pane_expect_scrolled_wrapped_output() {
+    use regex::Regex;
+    use std::time::Duration;
+    use tmux_interface::{Error, NewSession, PaneExpect, TestServer};
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+    // small pane, shell without user profile
+    invoker(
+        NewSession::new()
+            .detached()
+            .width(20)
+            .height(5)
+            .shell_command("sh")
+            .build(),
+    )
+    .unwrap();
+
+    let timeout = Duration::from_secs(10);
+    let mut pane = PaneExpect::with_invoker("%1", &invoker);
+
+    // captures
+    pane.send_line("printf 'v=%s.%s\\n' 1 22").unwrap();
+    let version = Regex::new(r"v=(\d+)\.(\d+)").unwrap();
+    let found = pane.expect(&version, timeout).unwrap();
+    assert_eq!(found.as_str(), "v=1.22");
+    assert_eq!(found.get(2), Some("22"));
+
+    // output scrolled off the screen, command line wrapped
+    pane.send_line("for i in $(seq 1 30); do echo line$i; done")
+        .unwrap();
+    pane.expect_text("do echo line$i; done", timeout).unwrap();
+    let line = Regex::new(r"(?m)^line(\d+)$").unwrap();
+    for i in 1..=30 {
+        let found = pane.expect(&line, timeout).unwrap();
+        assert_eq!(found.get(1), Some(i.to_string().as_str()));
+    }
+
+    // wrapped and wide characters output
+    pane.send_line("printf '%030d\\n一二三四五六七八九十一二\\nend\\n' 7")
+        .unwrap();
+    let zeros = Regex::new("0{29}7").unwrap();
+    let wide = Regex::new("一二三四五六七八九十一二").unwrap();
+    let end = Regex::new("(?m)^end$").unwrap();
+    let (i, _) = pane.expect_any(&[end.clone(), zeros], timeout).unwrap();
+    assert_eq!(i, 1);
+    pane.expect(&wide, timeout).unwrap();
+    pane.expect(&end, timeout).unwrap();
+
+    // consumed output is not matched again
+    assert!(matches!(
+        pane.expect(&end, Duration::from_millis(200)),
+        Err(Error::Timeout)
+    ));
+    pane.send_line("echo next").unwrap();
+    pane.skip().unwrap();
+    pane.send_line("echo end").unwrap();
+    let found = pane.expect(&end, timeout).unwrap();
+    assert!(found.before.contains("echo end"));
+}
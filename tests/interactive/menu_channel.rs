@@ -0,0 +1,24 @@
+#[cfg(feature = "tmux_3_0")]
+#[test]
+fn menu_channel_recv() {
+    use std::cell::Cell;
+    use tmux_interface::{MenuChannel, SourceFile, TestServer};
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    let called = Cell::new(false);
+    let mut channel = MenuChannel::with_invoker(&invoker);
+    let item = channel.callback_item("Item", "i", || called.set(true));
+
+    // choose the item: run its command as the menu would
+    let path = std::env::temp_dir().join(format!("{}.conf", server.socket_name()));
+    std::fs::write(&path, item.to_vec()[2].as_bytes()).unwrap();
+    let source_file = SourceFile::new().path(path.to_string_lossy().to_string());
+    let result = invoker(source_file.build()).unwrap().into_result();
+    std::fs::remove_file(&path).unwrap();
+    result.unwrap();
+
+    assert_eq!(channel.recv().unwrap(), "callback-0");
+    assert!(called.get());
+}
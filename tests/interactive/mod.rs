@@ -0,0 +1,3 @@
+mod menu_channel;
+mod popup_runner;
+mod prompt_channel;
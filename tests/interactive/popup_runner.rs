@@ -0,0 +1,62 @@
+#[cfg(feature = "tmux_3_2")]
+#[test]
+fn popup_runner_run_in_popup() {
+    use std::time::Duration;
+    use tmux_interface::{
+        AttachSession, DisplayPopup, KillServer, ListClients, PopupOutcome, PopupRunner, Size,
+        StdIO, TestServer,
+    };
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    // popups are displayed on the client (control mode client is enough)
+    let mut client = server
+        .tmux()
+        .command(AttachSession::new())
+        .control_mode()
+        .stdin(Some(StdIO::Piped))
+        .stdout(Some(StdIO::Null))
+        .spawn()
+        .unwrap();
+    let list_clients = || ListClients::new().format("#{client_name}");
+    server
+        .wait_until(Duration::from_secs(5), |server| {
+            Ok(!server.output(list_clients())?.to_string().is_empty())
+        })
+        .unwrap();
+    let clients = server.output(list_clients()).unwrap().to_string();
+    let client_name = clients.lines().next().unwrap().to_string();
+
+    let runner = PopupRunner::with_invoker(&invoker);
+    let outcome = runner.run_in_popup(client_name.clone(), "exit 3").unwrap();
+    assert_eq!(
+        outcome,
+        PopupOutcome {
+            code: Some(3),
+            output: None
+        }
+    );
+
+    let runner = PopupRunner::with_invoker(&invoker).capture_output();
+    let popup = DisplayPopup::new()
+        .target_client(client_name)
+        .close_on_exit()
+        .width(Size::Percentage(50))
+        .height(10)
+        .x(0)
+        .y(10);
+    #[cfg(feature = "tmux_3_3")]
+    let popup = popup.environment("A", "a").environment("B", "b");
+    #[cfg(feature = "tmux_3_3")]
+    let shell_command = "echo \"$A$B\"";
+    #[cfg(not(feature = "tmux_3_3"))]
+    let shell_command = "echo ab";
+    let outcome = runner.run(popup, shell_command).unwrap();
+    assert!(outcome.success());
+    assert_eq!(outcome.output, Some("ab\n".to_string()));
+
+    // client exits with the server
+    server.output(KillServer::new()).unwrap();
+    client.wait().unwrap();
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "tmux_3_0")]
+#[test]
+fn prompt_channel_choose_tree() {
+    use std::thread;
+    use std::time::Duration;
+    use tmux_interface::{
+        AttachSession, ChooseTree, KillServer, ListClients, NewSession, PromptChannel, SendKeys,
+        StdIO, TestServer, Tmux,
+    };
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+    invoker(NewSession::new().detached().session_name("prompt").build()).unwrap();
+
+    // tree mode runs the template for the client (control mode client is enough)
+    let mut client = server
+        .tmux()
+        .command(AttachSession::new().target_session("prompt"))
+        .control_mode()
+        .stdin(Some(StdIO::Piped))
+        .stdout(Some(StdIO::Null))
+        .spawn()
+        .unwrap();
+    server
+        .wait_until(Duration::from_secs(5), |server| {
+            Ok(!server.output(ListClients::new())?.to_string().is_empty())
+        })
+        .unwrap();
+
+    // press the key in the tree mode while the choice is waited for
+    let send_key = |key: &'static str| {
+        let socket = server.socket_name().to_string();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(300));
+            Tmux::new()
+                .socket_name(socket)
+                .command(SendKeys::new().target_pane("prompt:0").key(key))
+                .output()
+                .unwrap();
+        })
+    };
+    let prompts = PromptChannel::with_invoker(&invoker).timeout(Duration::from_secs(10));
+    let choose_tree = || {
+        ChooseTree::new()
+            .target_pane("prompt:0")
+            .collapsed_sessions()
+    };
+
+    let sender = send_key("Enter");
+    let chosen = prompts.choose_tree(choose_tree()).unwrap();
+    sender.join().unwrap();
+    assert_eq!(chosen, Some("=prompt:".to_string()));
+
+    let sender = send_key("q");
+    let chosen = prompts.choose_tree(choose_tree()).unwrap();
+    sender.join().unwrap();
+    assert_eq!(chosen, None);
+
+    // client exits with the server
+    server.output(KillServer::new()).unwrap();
+    client.wait().unwrap();
+}
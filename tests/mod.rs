@@ -1,6 +1,15 @@
+mod config;
 mod control_mode;
 #[cfg(feature = "tmux_stable")]
 mod examples;
+mod expect;
+mod interactive;
 mod issues;
 mod options_ctl;
+mod options_registry;
+mod pipe;
+mod snapshot;
+mod sync;
+mod testing;
 mod variables_ctl;
+mod workspace;
@@ -3,25 +3,306 @@
 fn get() {
     use tmux_interface::{
         GlobalSessionOptionsCtl, GlobalWindowOptionsCtl, LocalSessionOptionsCtl,
-        LocalWindowOptionsCtl, ServerOptionsCtl, SessionOptionsCtl, WindowOptionsCtl,
+        LocalWindowOptionsCtl, ServerOptionsCtl, SessionOptionsCtl, TestServer, WindowOptionsCtl,
     };
 
     #[cfg(feature = "tmux_3_1")]
     use tmux_interface::PaneOptionsCtl;
 
-    let server_options = ServerOptionsCtl::default().get_all().unwrap();
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    let server_options = ServerOptionsCtl::with_invoker(&invoker).get_all().unwrap();
     dbg!(server_options);
-    let global_session_options = GlobalSessionOptionsCtl::default().get_all().unwrap();
+    let global_session_options = GlobalSessionOptionsCtl::with_invoker(&invoker)
+        .get_all()
+        .unwrap();
     dbg!(global_session_options);
-    let local_session_options = LocalSessionOptionsCtl::default().get_all().unwrap();
+    let local_session_options = LocalSessionOptionsCtl::with_invoker(&invoker)
+        .get_all()
+        .unwrap();
     dbg!(local_session_options);
-    let global_window_options = GlobalWindowOptionsCtl::default().get_all().unwrap();
+    let global_window_options = GlobalWindowOptionsCtl::with_invoker(&invoker)
+        .get_all()
+        .unwrap();
     dbg!(global_window_options);
-    let local_window_options = LocalWindowOptionsCtl::default().get_all().unwrap();
+    let local_window_options = LocalWindowOptionsCtl::with_invoker(&invoker)
+        .get_all()
+        .unwrap();
     dbg!(local_window_options);
     #[cfg(feature = "tmux_3_1")]
     {
-        let pane_options = PaneOptionsCtl::default().get_all().unwrap();
+        let pane_options = PaneOptionsCtl::with_invoker(&invoker).get_all().unwrap();
         dbg!(pane_options);
     }
 }
+
+#[cfg(feature = "tmux_1_6")]
+#[test]
+fn options_ctl_get_set_all() {
+    use tmux_interface::{Error, NewSession, OptionsCtl, Switch, TestServer};
+
+    const TARGET_SESSION: &str = "options_ctl_get_set_all_test";
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
+
+    let options_ctl = OptionsCtl::with_invoker(&invoker)
+        .target_session(format!("={}:", TARGET_SESSION))
+        .target_window(format!("={}:", TARGET_SESSION))
+        .target_pane(format!("={}:", TARGET_SESSION));
+
+    let mut options = options_ctl.get_all().unwrap();
+    assert!(options.global_session_options.base_index.is_some());
+    assert!(options.global_window_options.mode_keys.is_some());
+
+    options.local_session_options.base_index = Some(1);
+    options.local_window_options.aggressive_resize = Some(Switch::On);
+    options_ctl.set_all(options.clone()).unwrap();
+
+    let actual = options_ctl.get_all().unwrap();
+    assert_eq!(actual.local_session_options.base_index, Some(1));
+    assert_eq!(
+        actual.local_window_options.aggressive_resize,
+        Some(Switch::On)
+    );
+    assert_eq!(
+        actual.server_options.escape_time,
+        options.server_options.escape_time
+    );
+    assert_eq!(
+        actual.global_session_options.base_index,
+        options.global_session_options.base_index
+    );
+
+    // tmux error
+    let options_ctl = OptionsCtl::with_invoker(&invoker).target_session("=not_existing_session:");
+    assert!(matches!(
+        options_ctl.get_local_session_options(),
+        Err(Error::Tmux(_))
+    ));
+    assert!(matches!(
+        options_ctl.set_local_session_options(Default::default()),
+        Err(Error::Tmux(_))
+    ));
+}
+
+#[cfg(feature = "tmux_1_9")]
+#[test]
+fn options_ctl_apply() {
+    use tmux_interface::{NewSession, Options, OptionsCtl, SessionOptions, TestServer};
+
+    const TARGET_SESSION: &str = "options_ctl_apply_test";
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
+
+    let options_ctl =
+        OptionsCtl::with_invoker(&invoker).target_session(format!("={}:", TARGET_SESSION));
+
+    // user tweak, not managed
+    let mut tweak = Options::default();
+    tweak.global_session_options.history_limit = Some(5000);
+    options_ctl.apply(&tweak).unwrap();
+
+    let desired = Options {
+        global_session_options: SessionOptions {
+            status_left: Some("[#{session_name}] ".into()),
+            ..Default::default()
+        },
+        local_session_options: SessionOptions {
+            base_index: Some(1),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let applied = options_ctl.apply(&desired).unwrap();
+    let names: Vec<&str> = applied.iter().map(|change| change.name.as_str()).collect();
+    // default `status-left` is the same
+    assert_eq!(names, vec!["base-index"]);
+
+    assert!(options_ctl.diff(&desired).unwrap().is_empty());
+    let options = options_ctl.get_all().unwrap();
+    assert_eq!(options.global_session_options.history_limit, Some(5000));
+    assert_eq!(options.local_session_options.base_index, Some(1));
+}
+
+#[cfg(feature = "tmux_1_9")]
+#[test]
+fn options_ctl_diff_get_all() {
+    use tmux_interface::{
+        GlobalSessionOptionsCtl, OptionScope, OptionsCtl, OptionsDiff, SessionOptionsCtl,
+        TestServer,
+    };
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    // options returned by the getters are not changed
+    let options_ctl = OptionsCtl::with_invoker(&invoker);
+    assert!(options_ctl
+        .diff(&options_ctl.get_all().unwrap())
+        .unwrap()
+        .is_empty());
+
+    let session_ctl = GlobalSessionOptionsCtl::with_invoker(&invoker);
+    let diff = OptionsDiff::diff_all(
+        OptionScope::GlobalSession,
+        &session_ctl.get_all().unwrap(),
+        &session_ctl.get_all().unwrap(),
+    );
+    assert!(diff.is_empty(), "{}", diff);
+}
+
+#[cfg(feature = "tmux_3_1")]
+#[test]
+fn options_ctl_resolve() {
+    use tmux_interface::{NewSession, OptionKind, OptionScope, OptionsCtl, SetOption, TestServer};
+
+    const TARGET_SESSION: &str = "options_ctl_resolve_test";
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
+
+    let target = format!("={}:", TARGET_SESSION);
+    let set = |cmd: SetOption| invoker(cmd.target_pane(target.clone()).build()).unwrap();
+    set(SetOption::new().option("base-index").value("1"));
+    set(SetOption::new()
+        .window()
+        .option("main-pane-width")
+        .value("70"));
+    set(SetOption::new().pane().option("remain-on-exit").value("on"));
+    set(SetOption::new()
+        .window()
+        .option("@user")
+        .value("window value"));
+
+    let options_ctl = OptionsCtl::with_invoker(&invoker).target_pane(target.clone());
+    let pane = OptionScope::Pane(Some(target.clone().into()));
+    let window = OptionScope::Window(Some(target.clone().into()));
+    let session = OptionScope::Session(Some(target.clone().into()));
+
+    let resolved = options_ctl.resolve("remain-on-exit").unwrap();
+    assert_eq!(resolved.value.as_deref(), Some("on"));
+    assert_eq!(resolved.source, Some(pane));
+
+    let resolved = options_ctl.resolve("main-pane-width").unwrap();
+    assert_eq!(resolved.value.as_deref(), Some("70"));
+    assert_eq!(resolved.source, Some(window.clone()));
+
+    let resolved = options_ctl.resolve("mode-keys").unwrap();
+    assert_eq!(resolved.source, Some(OptionScope::GlobalWindow));
+
+    let resolved = options_ctl.resolve("base-index").unwrap();
+    assert_eq!(resolved.value.as_deref(), Some("1"));
+    assert_eq!(resolved.source, Some(session));
+
+    let resolved = options_ctl.resolve("status-left").unwrap();
+    assert_eq!(resolved.value.as_deref(), Some("[#{session_name}] "));
+    assert_eq!(resolved.source, Some(OptionScope::GlobalSession));
+
+    let resolved = options_ctl.resolve("escape-time").unwrap();
+    assert_eq!(resolved.source, Some(OptionScope::Server));
+
+    let resolved = options_ctl.resolve_kind(OptionKind::Pane, "@user").unwrap();
+    assert_eq!(resolved.value.as_deref(), Some("window value"));
+    assert_eq!(resolved.source, Some(window));
+    assert!(options_ctl.resolve("@user").is_err());
+}
+
+#[cfg(feature = "tmux_2_4")]
+#[test]
+fn options_ctl_array_option() {
+    use tmux_interface::{
+        ArrayOption, ArrayOptionsCtl, GlobalSessionOptionsCtl, ServerOptionsCtl, TestServer,
+    };
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    let server_ctl = ServerOptionsCtl::new(&invoker);
+    let command_alias: ArrayOption<String> = server_ctl.get_array_option("command-alias").unwrap();
+    let len = command_alias.len();
+    assert!(len > 1);
+
+    server_ctl
+        .set_array_item("command-alias", 10, "x=y")
+        .unwrap();
+    server_ctl.unset_array_item("command-alias", 1).unwrap();
+    server_ctl
+        .append_array_item("command-alias", "z=w")
+        .unwrap();
+
+    let command_alias: ArrayOption<String> = server_ctl.get_array_option("command-alias").unwrap();
+    assert_eq!(command_alias.len(), len + 1);
+    assert_eq!(command_alias.get(10).unwrap(), "x=y");
+    assert_eq!(command_alias.get(1).unwrap(), "z=w");
+    let item: Option<String> = server_ctl.get_array_item("command-alias", 9).unwrap();
+    assert_eq!(item, None);
+
+    server_ctl
+        .set_array_option("command-alias", &ArrayOption::from(vec!["a=b"]))
+        .unwrap();
+    let command_alias: ArrayOption<String> = server_ctl.get_array_option("command-alias").unwrap();
+    assert_eq!(command_alias.into_vec(), vec!["a=b"]);
+
+    let session_ctl = GlobalSessionOptionsCtl::new(&invoker);
+    session_ctl
+        .append_array_item("update-environment", "MY_VAR")
+        .unwrap();
+    let update_environment: ArrayOption<String> =
+        session_ctl.get_array_option("update-environment").unwrap();
+    assert_eq!(update_environment.into_vec().last().unwrap(), "MY_VAR");
+}
+
+#[cfg(feature = "tmux_3_1")]
+#[test]
+fn options_ctl_user_options() {
+    use tmux_interface::{OptionScope, TestServer, UserOptionsCtl};
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    let store = UserOptionsCtl::with_invoker(&invoker, OptionScope::Pane(None));
+    let title = "say \"hi\"\n\tthen $HOME #{pane_id} \\ done;";
+    store.set("ticket-id", 1024).unwrap();
+    store.set("ticket-title", title).unwrap();
+    store.set("owner", "alice").unwrap();
+
+    assert_eq!(store.get::<usize>("ticket-id").unwrap(), Some(1024));
+    assert_eq!(store.get::<String>("ticket-title").unwrap().unwrap(), title);
+    assert_eq!(store.get::<String>("ticket").unwrap(), None);
+
+    let ticket = store.list::<String>("ticket-").unwrap();
+    assert_eq!(ticket.len(), 2);
+    assert_eq!(ticket["ticket-title"], title);
+
+    store.delete("owner").unwrap();
+    assert_eq!(store.get::<String>("owner").unwrap(), None);
+    store.delete_all("ticket-").unwrap();
+    assert!(store.list::<String>("").unwrap().is_empty());
+}
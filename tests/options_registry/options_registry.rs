@@ -0,0 +1,30 @@
+// all options known for the installed tmux version are listed by `show-options`
+#[cfg(feature = "tmux_3_1")]
+#[test]
+fn options_registry_available() {
+    use tmux_interface::{OptionKind, OptionsRegistry, ShowOptions, TestServer, Tmux, Version};
+
+    let output = Tmux::new().version().output().unwrap();
+    let version: Version = output.to_string().parse().unwrap();
+
+    let server = TestServer::new().unwrap();
+    let show = |cmd: ShowOptions<'static>, kind| {
+        let output = server.output(cmd).unwrap().to_string();
+        for option in OptionsRegistry::available(kind, &version) {
+            assert!(
+                output.lines().any(|line| {
+                    let name = line.split(' ').next().unwrap_or_default();
+                    name == option.name || name.starts_with(&format!("{}[", option.name))
+                }),
+                "{} option {} not found",
+                kind,
+                option.name
+            );
+        }
+    };
+    show(ShowOptions::new().server(), OptionKind::Server);
+    show(ShowOptions::new().global(), OptionKind::Session);
+    show(ShowOptions::new().global().window(), OptionKind::Window);
+    // global pane options are listed as window options
+    show(ShowOptions::new().global().window(), OptionKind::Pane);
+}
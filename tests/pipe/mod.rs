@@ -0,0 +1 @@
+mod pane_stream;
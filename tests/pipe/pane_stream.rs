@@ -0,0 +1,59 @@
+#[cfg(feature = "tmux_2_9a")]
+#[test]
+fn pane_stream_until_pane_killed() {
+    use std::io::Read;
+    use tmux_interface::{
+        DisplayMessage, Error, KillPane, NewWindow, PaneLog, PaneStream, PipePane, SendKeys,
+        TestServer,
+    };
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+    // shell without user profile
+    invoker(NewWindow::new().detached().shell_command("sh").build()).unwrap();
+
+    let mut stream = PaneStream::with_invoker("%1", &invoker).unwrap();
+    invoker(
+        SendKeys::new()
+            .target_pane("%1")
+            .key("echo streamed")
+            .build(),
+    )
+    .unwrap();
+    invoker(SendKeys::new().target_pane("%1").key("Enter").build()).unwrap();
+
+    let mut output = Vec::new();
+    let mut buf = [0; 64];
+    while !String::from_utf8_lossy(&output).contains("streamed\r\n") {
+        let len = stream.read(&mut buf).unwrap();
+        assert_ne!(len, 0);
+        output.extend_from_slice(&buf[..len]);
+    }
+    assert!(!stream.pane_dead().unwrap());
+
+    // the pipe of the stream or the other pipe is not replaced
+    let result = PaneStream::with_invoker("%1", &invoker);
+    assert!(matches!(result, Err(Error::Tmux(_))));
+    invoker(NewWindow::new().detached().shell_command("sh").build()).unwrap();
+    let pipe_pane = PipePane::new()
+        .target_pane("%2")
+        .shell_command("cat > /dev/null");
+    invoker(pipe_pane.build()).unwrap();
+    let result = PaneStream::with_invoker("%2", &invoker);
+    assert!(matches!(result, Err(Error::Tmux(_))));
+    let pane_pipe = DisplayMessage::new()
+        .print()
+        .target_pane("%2")
+        .message("#{pane_pipe}");
+    assert_eq!(invoker(pane_pipe.build()).unwrap().to_string(), "1\n");
+
+    // stream ends when the pane is closed
+    invoker(KillPane::new().target_pane("%1").build()).unwrap();
+    let dir = std::env::temp_dir().join(server.socket_name());
+    let log = PaneLog::new(&dir, "pane_1");
+    log.record(&mut stream).unwrap();
+    assert!(stream.pane_dead().unwrap());
+    drop(stream);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,152 @@
+#[cfg(feature = "tmux_2_0")]
+#[test]
+fn snapshot_restore() {
+    use tmux_interface::{
+        KillSession, NewSession, NewWindow, SelectLayout, SetOption, Snapshot, SnapshotCtl,
+        SplitWindow, TestServer,
+    };
+
+    const TARGET_SESSION: &str = "snapshot_restore_test";
+
+    // separate servers, the restore target is a fresh server
+    let source_server = TestServer::new().unwrap();
+    let target_server = TestServer::new().unwrap();
+    let source = source_server.invoker();
+    let target = target_server.invoker();
+    // shell without user profile
+    let default_shell = || {
+        SetOption::new()
+            .global()
+            .option("default-shell")
+            .value("/bin/sh")
+            .build()
+    };
+    source(default_shell()).unwrap();
+    target(default_shell()).unwrap();
+
+    source(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .window_name("editor")
+            .start_directory("/tmp")
+            .width(120)
+            .height(40)
+            .build(),
+    )
+    .unwrap();
+    source(SplitWindow::new().detached().start_directory("/").build()).unwrap();
+    source(SplitWindow::new().detached().horizontal().build()).unwrap();
+    source(SelectLayout::new().layout_name("main-vertical").build()).unwrap();
+    source(NewWindow::new().detached().window_name("shell").build()).unwrap();
+    // session started with the server
+    source(KillSession::new().target_session("0").build()).unwrap();
+
+    let snapshot = SnapshotCtl::with_invoker(&source).snapshot(true).unwrap();
+    assert_eq!(snapshot.sessions.len(), 1);
+    let session = &snapshot.sessions[0];
+    assert_eq!(session.name, TARGET_SESSION);
+    assert_eq!(session.windows.len(), 2);
+    assert_eq!(session.windows[0].name, "editor");
+    assert_eq!(session.windows[0].panes.len(), 3);
+    assert!(session.windows[0].active);
+    assert!(session.windows[0].panes[0].contents.is_some());
+
+    // file format round trip
+    let snapshot: Snapshot = snapshot.to_string().parse().unwrap();
+
+    let restored = SnapshotCtl::with_invoker(&target)
+        .restore(&snapshot, &[])
+        .unwrap();
+    assert_eq!(restored, vec![TARGET_SESSION.to_string()]);
+
+    let restored_snapshot = SnapshotCtl::with_invoker(&target).snapshot(false).unwrap();
+    let restored_session = restored_snapshot
+        .sessions
+        .iter()
+        .find(|session| session.name == TARGET_SESSION)
+        .unwrap();
+    for (window, restored_window) in session.windows.iter().zip(&restored_session.windows) {
+        assert_eq!(window.index, restored_window.index);
+        assert_eq!(window.name, restored_window.name);
+        assert_eq!(window.active, restored_window.active);
+        assert_eq!(window.panes.len(), restored_window.panes.len());
+        let layout = window.layout.as_ref().unwrap();
+        let restored_layout = restored_window.layout.as_ref().unwrap();
+        assert!(layout.cell.structural_eq(&restored_layout.cell));
+        assert_eq!(
+            (layout.cell.x, layout.cell.y),
+            (restored_layout.cell.x, restored_layout.cell.y)
+        );
+        for (pane, restored_pane) in window.panes.iter().zip(&restored_window.panes) {
+            assert_eq!(pane.current_path, restored_pane.current_path);
+            assert_eq!(pane.active, restored_pane.active);
+        }
+    }
+
+    // existing sessions are skipped
+    let restored = SnapshotCtl::with_invoker(&target)
+        .restore(&snapshot, &[])
+        .unwrap();
+    assert!(restored.is_empty());
+}
+
+#[cfg(feature = "tmux_2_0")]
+#[test]
+fn snapshot_restore_many_panes() {
+    use tmux_interface::{
+        KillSession, NewSession, SelectLayout, SetOption, SnapshotCtl, SplitWindow, TestServer,
+    };
+
+    const TARGET_SESSION: &str = "snapshot_restore_many_panes_test";
+    const PANES: usize = 16;
+
+    let source_server = TestServer::new().unwrap();
+    let target_server = TestServer::new().unwrap();
+    let source = source_server.invoker();
+    let target = target_server.invoker();
+    // shell without user profile
+    let default_shell = || {
+        SetOption::new()
+            .global()
+            .option("default-shell")
+            .value("/bin/sh")
+            .build()
+    };
+    source(default_shell()).unwrap();
+    target(default_shell()).unwrap();
+
+    source(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
+    source(KillSession::new().target_session("0").build()).unwrap();
+    for _ in 1..PANES {
+        source(SplitWindow::new().detached().build()).unwrap();
+        source(SelectLayout::new().layout_name("tiled").build()).unwrap();
+    }
+
+    // each split of the last pane would halve it, not enough space for all panes
+    let snapshot = SnapshotCtl::with_invoker(&source).snapshot(false).unwrap();
+    assert_eq!(snapshot.sessions[0].windows[0].panes.len(), PANES);
+    let restored = SnapshotCtl::with_invoker(&target)
+        .restore(&snapshot, &[])
+        .unwrap();
+    assert_eq!(restored, vec![TARGET_SESSION.to_string()]);
+
+    let restored_snapshot = SnapshotCtl::with_invoker(&target).snapshot(false).unwrap();
+    let restored_session = restored_snapshot
+        .sessions
+        .iter()
+        .find(|session| session.name == TARGET_SESSION)
+        .unwrap();
+    let window = &snapshot.sessions[0].windows[0];
+    let restored_window = &restored_session.windows[0];
+    assert_eq!(restored_window.panes.len(), PANES);
+    let layout = window.layout.as_ref().unwrap();
+    let restored_layout = restored_window.layout.as_ref().unwrap();
+    assert!(layout.cell.structural_eq(&restored_layout.cell));
+}
@@ -0,0 +1,49 @@
+#[cfg(feature = "tmux_1_8")]
+#[test]
+fn channel_lock_signal_wait() {
+    use std::process::Child;
+    use std::thread;
+    use std::time::Duration;
+    use tmux_interface::{Channel, Error, StdIO, TestServer, TmuxCommand, WaitFor};
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+    let spawner = |cmd: TmuxCommand| -> Result<Child, Error> {
+        server
+            .tmux()
+            .command(cmd)
+            .stdout(Some(StdIO::Null))
+            .stderr(Some(StdIO::Piped))
+            .spawn()
+    };
+
+    // no waiting client or signal is left after the timeout
+    let channel = Channel::with_invokers("channel", &invoker, &spawner);
+    for _ in 0..2 {
+        assert!(matches!(
+            channel.wait(Some(Duration::from_millis(200))),
+            Err(Error::Timeout)
+        ));
+    }
+    channel.signal().unwrap();
+    channel.wait(Some(Duration::from_secs(10))).unwrap();
+
+    // client not waiting yet on timeout
+    assert!(matches!(
+        channel.wait(Some(Duration::from_millis(0))),
+        Err(Error::Timeout)
+    ));
+    assert!(matches!(
+        channel.wait(Some(Duration::from_millis(200))),
+        Err(Error::Timeout)
+    ));
+
+    // other client waits for the lock until the guard is dropped
+    let guard = channel.lock().unwrap();
+    let mut other = spawner(WaitFor::new().locked().channel("channel").build()).unwrap();
+    thread::sleep(Duration::from_millis(200));
+    assert!(other.try_wait().unwrap().is_none());
+    drop(guard);
+    assert!(other.wait().unwrap().success());
+    channel.unlock().unwrap();
+}
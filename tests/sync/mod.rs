@@ -0,0 +1,2 @@
+mod channel;
+mod pane_runner;
@@ -0,0 +1,39 @@
+#[cfg(feature = "tmux_3_0")]
+#[test]
+fn pane_runner_run_respawn() {
+    use std::process::Child;
+    use std::time::Duration;
+    use tmux_interface::{Error, NewWindow, PaneRunner, StdIO, TestServer, TmuxCommand};
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+    let spawner = |cmd: TmuxCommand| -> Result<Child, Error> {
+        server
+            .tmux()
+            .command(cmd)
+            .stdout(Some(StdIO::Null))
+            .stderr(Some(StdIO::Piped))
+            .spawn()
+    };
+    // shell without user profile
+    invoker(NewWindow::new().detached().shell_command("sh").build()).unwrap();
+
+    let runner = PaneRunner::with_invokers(&invoker, &spawner).timeout(Duration::from_secs(10));
+    assert!(runner.run("%1", "true").unwrap().success());
+    assert_eq!(runner.run("%1", "sh -c 'exit 3'").unwrap().code(), Some(3));
+    // trailing comment, background job, multiple lines
+    let status = runner.run("%1", "sh -c 'exit 5' # comment").unwrap();
+    assert_eq!(status.code(), Some(5));
+    assert!(runner.run("%1", "sleep 1 &").unwrap().success());
+    let status = runner.run("%1", "true\nsh -c 'exit 6'").unwrap();
+    assert_eq!(status.code(), Some(6));
+
+    // cancelled, the shell runs the next command
+    let runner = runner.timeout(Duration::from_millis(500));
+    assert!(matches!(runner.run("%1", "sleep 60"), Err(Error::Timeout)));
+    let runner = runner.timeout(Duration::from_secs(10));
+    assert!(runner.run("%1", "true").unwrap().success());
+
+    assert_eq!(runner.respawn("%1", "exit 4").unwrap().code(), Some(4));
+    assert_eq!(runner.respawn("%1", "echo done").unwrap().code(), Some(0));
+}
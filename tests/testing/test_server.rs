@@ -0,0 +1,38 @@
+#[cfg(feature = "tmux_1_6")]
+#[test]
+fn test_server() {
+    use std::time::Duration;
+    use tmux_interface::{Error, HasSession, KillServer, ListWindows, NewWindow, TestServer};
+
+    let server = TestServer::with_size(100, 30).unwrap();
+    let other = TestServer::new().unwrap();
+    assert_ne!(server.socket_name(), other.socket_name());
+    let socket_path = other.socket_path().unwrap().to_path_buf();
+    assert!(socket_path.exists());
+
+    let invoker = server.invoker();
+    invoker(NewWindow::new().detached().build()).unwrap();
+    let output = invoker(
+        ListWindows::new()
+            .format("#{window_width}x#{window_height}")
+            .build(),
+    )
+    .unwrap();
+    #[cfg(feature = "tmux_2_9")]
+    assert_eq!(output.to_string(), "100x30\n100x30\n");
+    #[cfg(not(feature = "tmux_2_9"))]
+    assert!(output.to_string().starts_with("100x30\n"));
+
+    // commands of the other server do not affect the server
+    other.output(KillServer::new()).unwrap();
+    assert!(!other.output(HasSession::new()).unwrap().success());
+    server.wait_ready(Duration::from_secs(1)).unwrap();
+    assert!(matches!(
+        other.wait_ready(Duration::from_millis(50)),
+        Err(Error::Timeout)
+    ));
+
+    // socket file removed
+    drop(other);
+    assert!(!socket_path.exists());
+}
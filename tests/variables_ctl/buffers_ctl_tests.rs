@@ -1,23 +1,34 @@
 #[cfg(feature = "tmux_1_6")]
 #[test]
 fn get_buffers() {
-    use tmux_interface::{BuffersCtl, DeleteBuffer, KillSession, NewSession, SetBuffer, Tmux};
+    use tmux_interface::{BuffersCtl, DeleteBuffer, NewSession, SetBuffer, TestServer};
 
     const TARGET_SESSION: &str = "get_buffers_test";
     const BUFFER_NAME: &str = "test_buffer";
 
-    Tmux::with_command(NewSession::new().detached().session_name(TARGET_SESSION))
-        .output()
-        .unwrap();
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
 
     #[cfg(feature = "tmux_2_3")]
-    Tmux::with_command(SetBuffer::new().buffer_name(BUFFER_NAME).data(BUFFER_NAME))
-        .output()
-        .unwrap();
+    invoker(
+        SetBuffer::new()
+            .buffer_name(BUFFER_NAME)
+            .data(BUFFER_NAME)
+            .build(),
+    )
+    .unwrap();
 
     #[cfg(feature = "tmux_2_3")]
     {
-        let buffers = BuffersCtl::new().get_all().unwrap();
+        let buffers = BuffersCtl::with_invoker(&invoker).get_all().unwrap();
         let mut found = false;
         for buffer in buffers {
             if buffer.name == Some(BUFFER_NAME.to_string()) {
@@ -28,11 +39,33 @@ fn get_buffers() {
     }
 
     #[cfg(feature = "tmux_2_3")]
-    Tmux::with_command(DeleteBuffer::new().buffer_name(BUFFER_NAME))
-        .output()
-        .unwrap();
+    invoker(DeleteBuffer::new().buffer_name(BUFFER_NAME).build()).unwrap();
+}
+
+#[cfg(feature = "tmux_2_3")]
+#[test]
+fn buffers_ctl_binary_data() {
+    use tmux_interface::{BuffersCtl, Error, TestServer, TmuxCommand, TmuxOutput};
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+    let stdin_invoker = |cmd: TmuxCommand, input: &[u8]| -> Result<TmuxOutput, Error> {
+        server.tmux().command(cmd).output_with_stdin(input)
+    };
+    let buffers_ctl = BuffersCtl::with_invokers(&invoker, &stdin_invoker);
+
+    let data: Vec<u8> = (0..=255).chain(b"\n\n\0".iter().copied()).collect();
+    buffers_ctl.write("binary", &data).unwrap();
+    assert_eq!(buffers_ctl.read(Some("binary")).unwrap(), data);
+    assert_eq!(
+        buffers_ctl.get("binary").unwrap().unwrap().size,
+        Some(data.len())
+    );
 
-    Tmux::with_command(KillSession::new().target_session(TARGET_SESSION))
-        .output()
-        .unwrap();
+    buffers_ctl.rename("binary", "renamed").unwrap();
+    assert_eq!(buffers_ctl.get("binary").unwrap(), None);
+    assert_eq!(buffers_ctl.read(Some("renamed")).unwrap(), data);
+    buffers_ctl.delete("renamed").unwrap();
+    assert_eq!(buffers_ctl.get("renamed").unwrap(), None);
+    assert!(buffers_ctl.read(Some("renamed")).is_err());
 }
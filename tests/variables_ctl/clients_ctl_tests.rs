@@ -1,15 +1,22 @@
 #[cfg(feature = "tmux_1_6")]
 #[test]
 fn get_clients() {
-    use tmux_interface::{ClientsCtl, KillSession, NewSession, Tmux};
+    use tmux_interface::{ClientsCtl, NewSession, TestServer};
 
     const TARGET_SESSION: &str = "get_clients_test";
 
-    Tmux::with_command(NewSession::new().detached().session_name(TARGET_SESSION))
-        .output()
-        .unwrap();
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
 
-    let _clients = ClientsCtl::new().get_all().unwrap();
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
+
+    let _clients = ClientsCtl::with_invoker(&invoker).get_all().unwrap();
     // let mut found = false;
     // for client in clients {
     //     if client.session == Some("0".to_string()) {
@@ -17,8 +24,4 @@ fn get_clients() {
     //     }
     // }
     // assert!(found);
-
-    Tmux::with_command(KillSession::new().target_session(TARGET_SESSION))
-        .output()
-        .unwrap();
 }
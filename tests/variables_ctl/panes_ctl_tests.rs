@@ -1,17 +1,24 @@
 #[cfg(feature = "tmux_1_6")]
 #[test]
 fn get_panes() {
-    use tmux_interface::{KillSession, NewSession, PanesCtl, SplitWindow, Tmux};
+    use tmux_interface::{NewSession, PanesCtl, SplitWindow, TestServer};
 
     const TARGET_SESSION: &str = "get_panes_test";
 
-    Tmux::with_command(NewSession::new().detached().session_name(TARGET_SESSION))
-        .output()
-        .unwrap();
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
 
-    Tmux::with_command(SplitWindow::new()).output().unwrap();
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
 
-    let panes = PanesCtl::new().get_all().unwrap();
+    invoker(SplitWindow::new().target_pane(TARGET_SESSION).build()).unwrap();
+
+    let panes = PanesCtl::with_invoker(&invoker).get_all().unwrap();
     let mut found = false;
     for _pane in panes {
         // if pane.current_command == Some(COMMAND.to_string()) {
@@ -19,8 +26,4 @@ fn get_panes() {
         // }
     }
     assert!(found);
-
-    Tmux::with_command(KillSession::new().target_session(TARGET_SESSION))
-        .output()
-        .unwrap();
 }
@@ -1,15 +1,22 @@
 #[cfg(feature = "tmux_1_6")]
 #[test]
 fn get_sessions() {
-    use tmux_interface::{KillSession, NewSession, SessionsCtl, Tmux};
+    use tmux_interface::{NewSession, SessionsCtl, TestServer};
 
     const TARGET_SESSION: &str = "get_sessions_test";
 
-    Tmux::with_command(NewSession::new().detached().session_name(TARGET_SESSION))
-        .output()
-        .unwrap();
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
 
-    let sessions = SessionsCtl::new().get_all().unwrap();
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
+
+    let sessions = SessionsCtl::with_invoker(&invoker).get_all().unwrap();
     let mut found = false;
     for session in sessions {
         if session.name == Some(TARGET_SESSION.to_string()) {
@@ -17,8 +24,4 @@ fn get_sessions() {
         }
     }
     assert!(found);
-
-    Tmux::with_command(KillSession::new().target_session(TARGET_SESSION))
-        .output()
-        .unwrap();
 }
@@ -1,14 +1,17 @@
 #[cfg(feature = "tmux_1_6")]
 #[test]
 fn variables_ctl_tests() {
-    use tmux_interface::{PanesCtl, SessionsCtl, WindowsCtl};
+    use tmux_interface::{PanesCtl, SessionsCtl, TestServer, WindowsCtl};
 
-    let sessions = SessionsCtl::new().get_all().unwrap();
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+
+    let sessions = SessionsCtl::with_invoker(&invoker).get_all().unwrap();
     dbg!(&sessions);
 
-    let windows = WindowsCtl::new().get_all().unwrap();
+    let windows = WindowsCtl::with_invoker(&invoker).get_all().unwrap();
     dbg!(&windows);
 
-    let panes = PanesCtl::new().get_all().unwrap();
+    let panes = PanesCtl::with_invoker(&invoker).get_all().unwrap();
     dbg!(&panes);
 }
@@ -1,19 +1,30 @@
 #[cfg(feature = "tmux_1_6")]
 #[test]
 fn get_windows() {
-    use tmux_interface::{KillSession, NewSession, NewWindow, Tmux, WindowsCtl};
+    use tmux_interface::{NewSession, NewWindow, TestServer, WindowsCtl};
 
     const TARGET_SESSION: &str = "get_windows_test";
 
-    Tmux::with_command(NewSession::new().detached().session_name(TARGET_SESSION))
-        .output()
-        .unwrap();
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
 
-    Tmux::with_command(NewWindow::new().window_name(TARGET_SESSION))
-        .output()
-        .unwrap();
+    invoker(
+        NewSession::new()
+            .detached()
+            .session_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
 
-    let windows = WindowsCtl::new().get_all().unwrap();
+    invoker(
+        NewWindow::new()
+            .target_window(format!("{}:", TARGET_SESSION))
+            .window_name(TARGET_SESSION)
+            .build(),
+    )
+    .unwrap();
+
+    let windows = WindowsCtl::with_invoker(&invoker).get_all().unwrap();
     let mut found = false;
     for window in windows {
         if window.name == Some(TARGET_SESSION.to_string()) {
@@ -21,8 +32,4 @@ fn get_windows() {
         }
     }
     assert!(found);
-
-    Tmux::with_command(KillSession::new().target_session(TARGET_SESSION))
-        .output()
-        .unwrap();
 }
@@ -0,0 +1 @@
+mod workspace_ctl_tests;
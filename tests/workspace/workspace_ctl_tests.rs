@@ -0,0 +1,100 @@
+#[cfg(feature = "tmux_1_9")]
+#[test]
+fn apply() {
+    use std::time::Duration;
+    use tmux_interface::{
+        CapturePane, KillPane, NewWindow, SetOption, TestServer, Workspace, WorkspaceCtl,
+        WorkspaceDrift, WorkspacePane, WorkspaceWindow,
+    };
+
+    const TARGET_SESSION: &str = "workspace_apply_test";
+
+    let workspace = Workspace::new(TARGET_SESSION)
+        .root("/tmp")
+        .window(
+            WorkspaceWindow::new("editor")
+                .layout("even-horizontal")
+                .pane(WorkspacePane::new().command("echo Enter C-c").focus())
+                .pane(WorkspacePane::new()),
+        )
+        .window(WorkspaceWindow::new("shell").focus());
+
+    let server = TestServer::new().unwrap();
+    let invoker = server.invoker();
+    // shell without user profile
+    invoker(
+        SetOption::new()
+            .global()
+            .option("default-shell")
+            .value("/bin/sh")
+            .build(),
+    )
+    .unwrap();
+    let workspace_ctl = WorkspaceCtl::with_invoker(&invoker);
+
+    // nothing exists yet
+    let drift = workspace_ctl.drift(&workspace).unwrap();
+    assert_eq!(
+        drift,
+        vec![
+            WorkspaceDrift::MissingSession(TARGET_SESSION.to_string()),
+            WorkspaceDrift::MissingWindow("editor".to_string()),
+            WorkspaceDrift::MissingWindow("shell".to_string()),
+        ]
+    );
+
+    let report = workspace_ctl.apply(&workspace).unwrap();
+    assert_eq!(
+        report.created,
+        vec![
+            WorkspaceDrift::MissingSession(TARGET_SESSION.to_string()),
+            WorkspaceDrift::MissingWindow("shell".to_string()),
+        ]
+    );
+    assert!(report.drift.is_empty());
+    assert!(workspace_ctl.drift(&workspace).unwrap().is_empty());
+
+    // commands are sent as literal text
+    server
+        .wait_until(Duration::from_secs(5), |server| {
+            let output = server.output(
+                CapturePane::new()
+                    .stdout()
+                    .target_pane(format!("={}:editor.0", TARGET_SESSION)),
+            )?;
+            Ok(output.to_string().lines().any(|line| line == "Enter C-c"))
+        })
+        .unwrap();
+
+    // idempotent
+    assert!(workspace_ctl.apply(&workspace).unwrap().is_clean());
+
+    // missing pane is recreated, extra window is reported only
+    invoker(
+        KillPane::new()
+            .target_pane(format!("={}:editor.1", TARGET_SESSION))
+            .build(),
+    )
+    .unwrap();
+    invoker(
+        NewWindow::new()
+            .detached()
+            .window_name("extra")
+            .target_window(format!("={}:", TARGET_SESSION))
+            .build(),
+    )
+    .unwrap();
+    let report = workspace_ctl.apply(&workspace).unwrap();
+    assert_eq!(
+        report.created,
+        vec![WorkspaceDrift::MissingPanes {
+            window: "editor".to_string(),
+            expected: 2,
+            actual: 1
+        }]
+    );
+    assert_eq!(
+        report.drift,
+        vec![WorkspaceDrift::ExtraWindow("extra".to_string())]
+    );
+}